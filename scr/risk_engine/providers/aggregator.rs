@@ -1,108 +1,152 @@
 use crate::risk_engine::types::*;
 use crate::risk_engine::config::RiskEngineConfig;
-use crate::risk_engine::providers::{TrmClient, ChainalysisClient};
+use crate::risk_engine::providers::{ProviderHealth, RiskDataProvider};
+use crate::risk_engine::scoring::{BehaviorContext, FloorRuleSet, IndicatorReconciler, ScoringStrategy};
 use std::sync::Arc;
 use tokio::time::Instant;
 use std::collections::HashMap;
-use tracing::{info, warn, error};
+use tracing::{info, warn};
 
 #[derive(Debug, Clone)]
 pub struct RiskDataAggregator {
-    trm_client: Option<Arc<TrmClient>>,
-    chainalysis_client: Option<Arc<ChainalysisClient>>,
+    providers: Vec<Arc<dyn RiskDataProvider>>,
+    scoring: Arc<dyn ScoringStrategy>,
+    floor_rules: FloorRuleSet,
+    reconciler: IndicatorReconciler,
     config: Arc<RiskEngineConfig>,
 }
 
 impl RiskDataAggregator {
     pub fn new(
-        trm_client: Option<Arc<TrmClient>>,
-        chainalysis_client: Option<Arc<ChainalysisClient>>,
+        providers: Vec<Arc<dyn RiskDataProvider>>,
+        scoring: Arc<dyn ScoringStrategy>,
+        floor_rules: FloorRuleSet,
         config: Arc<RiskEngineConfig>,
     ) -> Self {
         Self {
-            trm_client,
-            chainalysis_client,
+            providers,
+            scoring,
+            floor_rules,
+            reconciler: IndicatorReconciler::new(),
             config,
         }
     }
     
+    pub fn providers(&self) -> &[Arc<dyn RiskDataProvider>] {
+        &self.providers
+    }
+    
+    pub fn provider_health(&self) -> Vec<ProviderHealth> {
+        self.providers.iter().filter_map(|p| p.health()).collect()
+    }
+    
     pub async fn aggregate_wallet_risk(&self, wallet_address: &str) -> Result<WalletRiskProfile, String> {
+        self.aggregate_wallet_risk_with(wallet_address, Vec::new(), &[], &BehaviorContext::default(), &[]).await
+    }
+    
+    // Scores provider data for the wallet and its linked off-chain addresses,
+    // together with indicators derived elsewhere, such as exposure through a
+    // high-risk entity cluster, and the wallet's observed transaction behaviour.
+    // Categories an analyst suppressed are left out before scoring and floors.
+    pub async fn aggregate_wallet_risk_with(
+        &self,
+        wallet_address: &str,
+        extra_indicators: Vec<RiskIndicator>,
+        linked_addresses: &[ChainAddress],
+        behavior: &BehaviorContext,
+        suppressed_categories: &[RiskCategory],
+    ) -> Result<WalletRiskProfile, String> {
         let start_time = Instant::now();
+        let wallet = ChainAddress::solana(wallet_address)?;
         let mut all_indicators = Vec::new();
         let mut data_sources = Vec::new();
+        let mut provider_outages = Vec::new();
+        let mut metadata = HashMap::new();
+        let mut responding = Vec::new();
+        let mut entity = None;
         
-        // Parallel data fetching from all providers
-        let mut tasks = Vec::new();
-        
-        // TRM Labs
-        if let Some(client) = &self.trm_client {
-            let client = client.clone();
-            let address = wallet_address.to_string();
-            tasks.push(tokio::spawn(async move {
-                client.get_address_risk(&address).await
-            }));
-        }
+        // Every provider screens the wallet, plus each linked address on a chain it supports
+        let targets: Vec<(Arc<dyn RiskDataProvider>, ChainAddress)> = self.providers.iter()
+            .flat_map(|provider| {
+                let chains = provider.chains();
+                std::iter::once(wallet.clone())
+                    .chain(linked_addresses.iter().filter(|a| chains.contains(&a.chain())).cloned())
+                    .map(|address| (provider.clone(), address))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
         
-        // Chainalysis
-        if let Some(client) = &self.chainalysis_client {
-            let client = client.clone();
-            let address = wallet_address.to_string();
-            tasks.push(tokio::spawn(async move {
-                client.get_address_screening(&address).await
-            }));
-        }
+        // Parallel data fetching from all providers
+        let tasks: Vec<_> = targets.iter()
+            .map(|(provider, address)| {
+                let provider = provider.clone();
+                let address = address.clone();
+                tokio::spawn(async move {
+                    provider.fetch(&address).await
+                })
+            })
+            .collect();
         
-        // Wait for all providers
+        // Results line up with targets
         let results = futures::future::join_all(tasks).await;
         
-        // Process TRM results
-        if let Some(result) = results.get(0) {
-            match result {
-                Ok(Ok(trm_response)) => {
-                    let indicators: Vec<RiskIndicator> = trm_response.clone().into();
-                    all_indicators.extend(indicators);
-                    data_sources.push(DataSource::TrmLabs);
-                    info!("TRM data fetched for {}", wallet_address);
+        for ((provider, address), result) in targets.iter().zip(results) {
+            let linked = *address != wallet;
+            let error = match result {
+                Ok(Ok(mut report)) if linked => {
+                    report.apply_response_time();
+                    all_indicators.extend(report.indicators.into_iter().map(|i| Self::tag_linked(i, address)));
+                    info!("{} data fetched for {} linked address {}", provider.name(), wallet_address, address);
+                    continue;
+                }
+                Ok(Ok(mut report)) => {
+                    report.apply_response_time();
+                    all_indicators.extend(report.indicators);
+                    metadata.extend(report.metadata);
+                    if entity.is_none() {
+                        entity = report.entity;
+                    }
+                    if !data_sources.contains(&provider.source()) {
+                        data_sources.push(provider.source());
+                    }
+                    responding.push((provider.source(), provider.coverage()));
+                    info!("{} data fetched for {}", provider.name(), wallet_address);
+                    continue;
                 }
                 Ok(Err(e)) => {
-                    warn!("TRM provider failed for {}: {}", wallet_address, e);
+                    warn!("{} provider failed for {}: {}", provider.name(), address, e);
+                    e.to_string()
                 }
                 Err(e) => {
-                    warn!("TRM task failed for {}: {}", wallet_address, e);
+                    warn!("{} task failed for {}: {}", provider.name(), address, e);
+                    e.to_string()
                 }
-            }
+            };
+            
+            provider_outages.push(ProviderOutage {
+                source: provider.source(),
+                error: if linked { format!("linked address {}: {}", address, error) } else { error },
+                detected_at: chrono::Utc::now(),
+                linked_address: linked.then(|| address.clone()),
+            });
         }
         
-        // Process Chainalysis results
-        if let Some(result) = results.get(1) {
-            match result {
-                Ok(Ok(chainalysis_screening)) => {
-                    // Convert Chainalysis data to our indicators
-                    let indicators = self.convert_chainalysis_indicators(
-                        wallet_address,
-                        chainalysis_screening,
-                    );
-                    all_indicators.extend(indicators);
-                    data_sources.push(DataSource::Chainalysis);
-                    info!("Chainalysis data fetched for {}", wallet_address);
-                }
-                Ok(Err(e)) => {
-                    warn!("Chainalysis provider failed for {}: {}", wallet_address, e);
-                }
-                Err(e) => {
-                    warn!("Chainalysis task failed for {}: {}", wallet_address, e);
-                }
-            }
+        // Weight each exposure once, however many providers reported it. Derived
+        // indicators join afterwards: no provider was asked about them, so their
+        // absence from provider data isn't a disagreement
+        let (mut all_indicators, provider_disagreements) = self.reconciler.reconcile(all_indicators, &responding);
+        for disagreement in &provider_disagreements {
+            warn!("Provider disagreement for {}: {}", wallet_address, disagreement.reason);
         }
+        all_indicators.extend(extra_indicators);
+        all_indicators.retain(|indicator| !suppressed_categories.contains(&indicator.category));
         
         // Calculate overall risk score
-        let overall_score = self.calculate_overall_score(&all_indicators);
+        let overall_score = self.scoring.score(&all_indicators, behavior);
+        metadata.insert("scoring_strategy".to_string(), serde_json::json!(self.scoring.name()));
         let risk_level = self.config.risk_level_for_score(overall_score);
         
-        // Generate recommendations
-        let recommendations = self.generate_recommendations(&risk_level, &all_indicators);
-        
-        let profile = WalletRiskProfile {
+        let mut profile = WalletRiskProfile {
             wallet_address: wallet_address.to_string(),
             overall_risk_score: overall_score,
             risk_level,
@@ -110,128 +154,65 @@ impl RiskDataAggregator {
             attestation_key: None, // Will be populated by caller
             last_updated: chrono::Utc::now(),
             data_sources,
-            recommendations,
-            metadata: HashMap::new(),
+            recommendations: Vec::new(),
+            metadata,
+            provider_outages,
+            fail_mode_applied: None,
+            score_overrides: Vec::new(),
+            provider_disagreements,
+            transaction_risk: None,
+            entity,
+            linked_addresses: linked_addresses.to_vec(),
+            jurisdiction: None,
+            sybil_cluster: None,
         };
         
+        // Hard floors so a confirmed hit can't be averaged away
+        self.floor_rules.apply(&mut profile, &self.config.thresholds);
+        
+        // Generate recommendations
+        profile.recommendations = self.generate_recommendations(&profile.risk_level, &profile.risk_indicators);
+        
         let duration = start_time.elapsed();
         info!("Risk aggregation completed for {} in {:?}", wallet_address, duration);
         
         Ok(profile)
     }
     
-    fn calculate_overall_score(&self, indicators: &[RiskIndicator]) -> f64 {
-        if indicators.is_empty() {
-            return 0.0;
+    // Keeps linked-address findings distinct from the wallet's own in reconciliation
+    fn tag_linked(mut indicator: RiskIndicator, address: &ChainAddress) -> RiskIndicator {
+        let address_str = address.to_string();
+        indicator.id = format!("{}_{}", indicator.id, address_str);
+        indicator.description = format!("{} (linked {} address {})", indicator.description, address.chain().as_str(), address.address());
+        if !indicator.addresses_involved.contains(&address_str) {
+            indicator.addresses_involved.push(address_str.clone());
         }
-        
-        // Weighted average based on category weights and confidence
-        let mut weighted_sum = 0.0;
-        let mut total_weight = 0.0;
-        
-        for indicator in indicators {
-            let category_weight = indicator.category.weight();
-            let confidence_weight = indicator.confidence;
-            let weight = category_weight * confidence_weight;
-            
-            weighted_sum += indicator.score * weight;
-            total_weight += weight;
-        }
-        
-        let average = if total_weight > 0.0 {
-            weighted_sum / total_weight
-        } else {
-            0.0
-        };
-        
-        // Apply non-linear scaling for critical risks
-        self.apply_nonlinear_scaling(average)
+        indicator.metadata.insert("linked_address".to_string(), serde_json::json!(address_str));
+        indicator
     }
     
-    fn apply_nonlinear_scaling(&self, score: f64) -> f64 {
-        // Exponential scaling for higher scores to emphasize critical risks
-        if score > 80.0 {
-            score + (score - 80.0) * 0.5
-        } else if score > 60.0 {
-            score + (score - 60.0) * 0.3
-        } else {
-            score
-        }.min(100.0)
+    // Lets local providers such as the SDN list pick up new data
+    pub fn refresh_providers(&self) {
+        for provider in &self.providers {
+            provider.refresh();
+        }
     }
     
-    fn convert_chainalysis_indicators(
-        &self,
-        wallet_address: &str,
-        screening: &ChainalysisScreening,
-    ) -> Vec<RiskIndicator> {
-        let mut indicators = Vec::new();
-        
-        // Convert category scores to indicators
-        for category_score in &screening.category_scores {
-            let category = match category_score.category.as_str() {
-                "sanctions" => RiskCategory::Sanctions,
-                "illicit_activity" => RiskCategory::IllicitActivity,
-                "high_risk_service" => RiskCategory::HighRiskService,
-                _ => RiskCategory::TechnicalRisk,
-            };
-            
-            let indicator = RiskIndicator {
-                id: format!("chainalysis_{}_{}", wallet_address, category_score.category),
-                category,
-                subcategory: category_score.subcategory.clone().unwrap_or_default(),
-                score: category_score.score,
-                confidence: 0.9, // Chainalysis typically has high confidence
-                description: format!("Chainalysis {} risk detected", category_score.category),
-                evidence: vec![Evidence {
-                    source: DataSource::Chainalysis,
-                    raw_data: serde_json::to_value(category_score).unwrap(),
-                    extracted_info: HashMap::from([
-                        ("category".to_string(), category_score.category.clone()),
-                        ("score".to_string(), category_score.score.to_string()),
-                    ]),
-                    timestamp: chrono::Utc::now(),
-                }],
-                first_seen: chrono::Utc::now(),
-                last_seen: chrono::Utc::now(),
-                transaction_hashes: Vec::new(),
-                addresses_involved: Vec::new(),
-                metadata: HashMap::new(),
-            };
-            
-            indicators.push(indicator);
+    pub fn link_address(&self, address: &ChainAddress, wallet_address: &str) {
+        for provider in &self.providers {
+            provider.link_address(address, wallet_address);
         }
-        
-        // Add sanctions indicator if applicable
-        if screening.is_sanctioned {
-            let sanctions_indicator = RiskIndicator {
-                id: format!("chainalysis_{}_sanctions", wallet_address),
-                category: RiskCategory::Sanctions,
-                subcategory: "OFAC".to_string(),
-                score: 100.0, // Maximum score for sanctions
-                confidence: 1.0,
-                description: "Address appears on sanctions list".to_string(),
-                evidence: vec![Evidence {
-                    source: DataSource::Chainalysis,
-                    raw_data: serde_json::json!({"is_sanctioned": true}),
-                    extracted_info: HashMap::from([
-                        ("sanctioned".to_string(), "true".to_string()),
-                    ]),
-                    timestamp: chrono::Utc::now(),
-                }],
-                first_seen: chrono::Utc::now(),
-                last_seen: chrono::Utc::now(),
-                transaction_hashes: Vec::new(),
-                addresses_involved: Vec::new(),
-                metadata: HashMap::new(),
-            };
-            
-            indicators.push(sanctions_indicator);
+    }
+    
+    pub fn forget_wallet(&self, wallet_address: &str) {
+        for provider in &self.providers {
+            provider.forget_wallet(wallet_address);
         }
-        
-        indicators
     }
     
-    fn generate_recommendations(
+    // Public so callers can regenerate them once the level is final, e.g. after
+    // a fail mode or hysteresis changed it
+    pub fn generate_recommendations(
         &self,
         risk_level: &RiskLevel,
         indicators: &[RiskIndicator],
@@ -279,6 +260,14 @@ impl RiskDataAggregator {
                     deadline_hours: None,
                 });
             }
+            RiskLevel::Unknown => {
+                recommendations.push(RiskRecommendation {
+                    action: RecommendedAction::FlagForReview,
+                    priority: PriorityLevel::High,
+                    reason: "Risk could not be assessed - required data source unavailable".to_string(),
+                    deadline_hours: Some(12),
+                });
+            }
         }
         
         // Add specific recommendations based on indicators
@@ -8,6 +8,76 @@ pub struct AccessRequirements {
     pub accreditation_required: bool,
     pub max_attestation_age_days: Option<u32>, // Max days since issuance
     pub custom_validator: Option<CustomValidator>,
+    // One claim per sybil cluster for each allocation, see AttestationVerifier::claim_allocation
+    #[serde(default)]
+    pub one_allocation_per_cluster: bool,
+}
+ 
+// Operator cluster the risk engine placed a wallet in
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterMembership {
+    pub cluster_id: String,
+    pub confidence: f64,
+}
+ 
+#[async_trait]
+pub trait ClusterDirectory: Send + Sync {
+    async fn cluster_for(&self, wallet: &Pubkey) -> Option<ClusterMembership>;
+}
+ 
+// Cluster assignments exported from the risk engine; memberships below the
+// confidence floor are ignored
+#[derive(Debug, Clone, Default)]
+pub struct StaticClusterDirectory {
+    memberships: HashMap<Pubkey, ClusterMembership>,
+    min_confidence: f64,
+}
+ 
+impl StaticClusterDirectory {
+    pub fn new(min_confidence: f64) -> Self {
+        Self {
+            memberships: HashMap::new(),
+            min_confidence,
+        }
+    }
+    
+    // Directory over the clusters the risk engine writes to SYBIL_CLUSTERS_PATH;
+    // members that aren't valid addresses are skipped
+    pub fn from_clusters(clusters: &[crate::risk_engine::types::SybilCluster], min_confidence: f64) -> Self {
+        let mut directory = Self::new(min_confidence);
+        for cluster in clusters {
+            for member in &cluster.members {
+                if let Ok(wallet) = Pubkey::from_str(member) {
+                    directory.insert(wallet, ClusterMembership {
+                        cluster_id: cluster.cluster_id.clone(),
+                        confidence: cluster.confidence,
+                    });
+                }
+            }
+        }
+        directory
+    }
+    
+    pub fn load(path: &str, min_confidence: f64) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read sybil clusters {}: {}", path, e))?;
+        let clusters: Vec<crate::risk_engine::types::SybilCluster> = serde_json::from_str(&contents)
+            .map_err(|e| format!("Failed to parse sybil clusters {}: {}", path, e))?;
+        Ok(Self::from_clusters(&clusters, min_confidence))
+    }
+    
+    pub fn insert(&mut self, wallet: Pubkey, membership: ClusterMembership) {
+        self.memberships.insert(wallet, membership);
+    }
+}
+ 
+#[async_trait]
+impl ClusterDirectory for StaticClusterDirectory {
+    async fn cluster_for(&self, wallet: &Pubkey) -> Option<ClusterMembership> {
+        self.memberships.get(wallet)
+            .filter(|m| m.confidence >= self.min_confidence)
+            .cloned()
+    }
 }
  
 #[derive(Debug, Clone, Serialize, Deserialize)]
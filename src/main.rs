@@ -1,4 +1,9 @@
 use solana_kyc_compliance_sdk::risk_engine::{RiskEngineConfig, RiskMonitoringService};
+use solana_kyc_compliance_sdk::risk_engine::jurisdiction::JurisdictionRules;
+use solana_kyc_compliance_sdk::risk_engine::policy::{LintSeverity, PolicyLinter, PolicyManager};
+use solana_kyc_compliance_sdk::risk_engine::providers::{refresh_sdn_list, UsageTracker};
+use solana_kyc_compliance_sdk::risk_engine::scoring::{calibrate, CalibrationMetrics, LabelStore};
+use std::sync::Arc;
 use tracing_subscriber::{EnvFilter, fmt};
 use tracing::{info, error};
 use tokio::signal;
@@ -23,6 +28,31 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     info!("Configuration loaded successfully");
     
+    // One-shot subcommands
+    match std::env::args().nth(1).as_deref() {
+        Some("lint-policies") => {
+            lint_policies(config);
+            return Ok(());
+        }
+        Some("refresh-sdn") => {
+            refresh_sdn(config).await;
+            return Ok(());
+        }
+        Some("usage-report") => {
+            usage_report(config);
+            return Ok(());
+        }
+        Some("calibrate-weights") => {
+            calibrate_weights(config);
+            return Ok(());
+        }
+        Some(other) => {
+            error!("Unknown subcommand: {}", other);
+            process::exit(2);
+        }
+        None => {}
+    }
+    
     // Initialize risk monitoring service
     let service = RiskMonitoringService::new(config)
         .await
@@ -48,4 +78,145 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     service.stop().await;
     info!("Risk monitoring service stopped");
     
-    Ok(())
\ No newline at end of file
+    Ok(())
+}
+
+fn lint_policies(config: RiskEngineConfig) {
+    let mut linter = PolicyLinter::new(config.thresholds.clone());
+    if let Some(path) = &config.jurisdiction_rules_path {
+        match JurisdictionRules::load(path) {
+            Ok(rules) => linter = linter.with_jurisdiction_rules(rules),
+            Err(e) => {
+                error!("Failed to load jurisdiction rule packs: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+    let bundles_path = config.policy_bundles_path.clone();
+    let mut policy_manager = PolicyManager::new(Arc::new(config));
+    
+    if let Some(path) = bundles_path {
+        if let Err(e) = policy_manager.load_bundles(&path) {
+            error!("Failed to load policy bundles: {}", e);
+            process::exit(1);
+        }
+    }
+    
+    let reports = linter.lint_manager(&policy_manager);
+    
+    for report in &reports {
+        println!("== bundle '{}' ==", report.bundle);
+        for finding in &report.findings {
+            println!("{}", finding);
+        }
+        
+        println!(
+            "{} policies checked: {} errors, {} warnings, {} info",
+            report.policies_checked,
+            report.count(LintSeverity::Error),
+            report.count(LintSeverity::Warning),
+            report.count(LintSeverity::Info),
+        );
+    }
+    
+    if reports.iter().any(|r| r.has_errors()) {
+        process::exit(1);
+    }
+}
+
+async fn refresh_sdn(config: RiskEngineConfig) {
+    let Some(path) = config.ofac_sdn.list_path.as_deref() else {
+        error!("OFAC_SDN_PATH is not set");
+        process::exit(1);
+    };
+    let url = std::env::args().nth(2).unwrap_or(config.ofac_sdn.download_url.clone());
+    
+    match refresh_sdn_list(&url, path).await {
+        Ok(version) => {
+            println!(
+                "SDN list written to {}: published {}, {} addresses, sha256 {}",
+                path,
+                version.publish_date.as_deref().unwrap_or("unknown"),
+                version.address_count,
+                version.sha256,
+            );
+        }
+        Err(e) => {
+            error!("Failed to refresh OFAC SDN list: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+// Prints month-to-date provider usage from PROVIDER_USAGE_PATH as JSON
+fn usage_report(config: RiskEngineConfig) {
+    let tracker = UsageTracker::load(&config.provider_budgets).unwrap_or_else(|e| {
+        error!("{}", e);
+        process::exit(1);
+    });
+    
+    let report = serde_json::json!({
+        "providers": tracker.provider_usage(),
+        "wallets": tracker.wallet_usage(),
+        "tenants": tracker.tenant_usage(),
+    });
+    println!("{}", serde_json::to_string_pretty(&report).unwrap_or_default());
+}
+
+// Fits scoring weights from ANALYST_LABELS_PATH and writes them to the given
+// path or SCORING_WEIGHTS_PATH. Weights that do worse than the current scoring
+// on the holdout are not written unless --force is given.
+fn calibrate_weights(config: RiskEngineConfig) {
+    let Some(labels_path) = config.labels_path.as_deref() else {
+        error!("ANALYST_LABELS_PATH is not set");
+        process::exit(1);
+    };
+    let args: Vec<String> = std::env::args().skip(2).collect();
+    let force = args.iter().any(|arg| arg == "--force");
+    let output = args.iter().find(|arg| !arg.starts_with("--")).cloned();
+    let Some(output) = output.or(config.scoring.weights_path.clone()) else {
+        error!("No output path given and SCORING_WEIGHTS_PATH is not set");
+        process::exit(1);
+    };
+    
+    let labels = LabelStore::load(labels_path).unwrap_or_else(|e| {
+        error!("{}", e);
+        process::exit(1);
+    });
+    let weights = calibrate(labels.labels(), config.thresholds.high, config.scoring.calibration_holdout)
+        .unwrap_or_else(|e| {
+            error!("Calibration failed: {}", e);
+            process::exit(1);
+        });
+    
+    let regressed = weights.after.precision < weights.before.precision
+        || weights.after.recall < weights.before.recall;
+    
+    let describe = |metrics: &CalibrationMetrics| format!(
+        "precision {:.3}, recall {:.3} ({} TP, {} FP, {} FN)",
+        metrics.precision,
+        metrics.recall,
+        metrics.true_positives,
+        metrics.false_positives,
+        metrics.false_negatives,
+    );
+    println!(
+        "Calibrated on {} training labels, {} holdout at score >= {}",
+        weights.training_labels,
+        weights.after.samples,
+        weights.threshold,
+    );
+    println!("before: {}", describe(&weights.before));
+    println!("after:  {}", describe(&weights.after));
+    
+    if regressed && !force {
+        error!("Calibrated weights lose holdout precision or recall; not writing {} (use --force to override)", output);
+        process::exit(1);
+    }
+    
+    if let Err(e) = weights.save(&output) {
+        error!("{}", e);
+        process::exit(1);
+    }
+    println!("Weights written to {}", output);
+}
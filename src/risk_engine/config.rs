@@ -1,5 +1,8 @@
+use crate::risk_engine::types::{self, DataSource, FailMode};
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::env;
+use std::str::FromStr;
 use std::sync::Arc;
 use thiserror::Error;
  
@@ -17,16 +20,58 @@ pub struct RiskEngineConfig {
     pub trm: TrmConfig,
     pub chainalysis: ChainalysisConfig,
     pub crystal: CrystalConfig,
+    pub ofac_sdn: OfacSdnConfig,
+    // Provider names in fetch order; None enables every configured provider
+    pub providers: Option<Vec<String>>,
+    pub provider_resilience: ProviderResilienceConfig,
+    pub provider_cache: ProviderCacheConfig,
+    pub provider_budgets: ProviderBudgetConfig,
     
     // Scoring thresholds
     pub thresholds: RiskThresholds,
     
+    // How the overall score is computed from indicators
+    pub scoring: ScoringConfig,
+    
     // Monitoring settings
     pub monitoring: MonitoringConfig,
     
+    // Notification delivery
+    pub notifications: NotificationConfig,
+    
+    // Risk level hysteresis
+    pub hysteresis: HysteresisConfig,
+    
+    // Limits on automated suspensions and revocations
+    pub enforcement_guard: EnforcementGuardConfig,
+    
+    // Sources the default bundle needs, and what to do when one is down
+    pub source_requirements: SourceRequirements,
+    
+    // Per-jurisdiction policy bundles (JSON)
+    pub policy_bundles_path: Option<String>,
+    
+    // Post-scoring floor/override rules (JSON); built-in defaults when unset
+    pub floor_rules_path: Option<String>,
+    
+    // Versioned country rating packs (JSON file or directory); no jurisdiction indicator when unset
+    pub jurisdiction_rules_path: Option<String>,
+    
+    // Analyst per-wallet overrides (JSON), rewritten when overrides change
+    pub overrides_path: Option<String>,
+    
+    // Analyst case outcome labels (JSON), rewritten when a case is closed
+    pub labels_path: Option<String>,
+    // Latest assessment per wallet (JSON) that cases are labelled against, saved each cycle
+    pub assessments_path: Option<String>,
+    // Sybil clusters (JSON) for consumer-side cluster directories, rewritten after each sybil poll
+    pub sybil_clusters_path: Option<String>,
+    
     // On-chain settings
     pub rpc_url: String,
     pub program_id: String,
+    // Attestation registry deployment; jurisdiction-based rules are skipped when unset
+    pub attestation_registry_program_id: Option<String>,
     pub risk_engine_wallet: String,
 }
  
@@ -36,24 +81,92 @@ pub struct TrmConfig {
     pub api_url: String,
     pub timeout_secs: u64,
     pub enabled: bool,
+    pub entity_lookup: bool,
 }
  
 #[derive(Debug, Clone, Deserialize)]
 pub struct ChainalysisConfig {
     pub api_key: String,
     pub api_url: String,
+    pub sanctions_api_url: String,
+    pub timeout_secs: u64,
     pub kyt_enabled: bool,
     pub sanctions_enabled: bool,
 }
  
 #[derive(Debug, Clone, Deserialize)]
 pub struct CrystalConfig {
-    pub api_key: String,
+    // Optional provider: disabled when no key is configured
+    pub api_key: Option<String>,
     pub api_url: String,
+    pub timeout_secs: u64,
     pub enable_cross_chain: bool,
 }
  
 #[derive(Debug, Clone, Deserialize)]
+pub struct OfacSdnConfig {
+    // Local sdn.xml or sdn.csv; the provider is disabled when unset
+    pub list_path: Option<String>,
+    pub download_url: String,
+}
+ 
+// Provider response cache; the embedded store is shared by every process using the same file
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProviderCacheConfig {
+    pub backend: CacheBackend,
+    pub path: String,
+    pub default_ttl_secs: u64,
+    // Per-provider TTLs by provider name; 0 disables caching for that provider
+    pub provider_ttl_secs: HashMap<String, u64>,
+    // How long a "no data" answer is reused
+    pub negative_ttl_secs: u64,
+}
+ 
+impl ProviderCacheConfig {
+    pub fn ttl_for(&self, provider: &str) -> u64 {
+        self.provider_ttl_secs.get(provider).copied().unwrap_or(self.default_ttl_secs)
+    }
+}
+ 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheBackend {
+    Disabled,
+    Memory,
+    Embedded,
+}
+ 
+impl FromStr for CacheBackend {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "disabled" | "none" => Ok(CacheBackend::Disabled),
+            "memory" => Ok(CacheBackend::Memory),
+            "embedded" | "sqlite" => Ok(CacheBackend::Embedded),
+            other => Err(format!("Unknown provider cache backend: {}", other)),
+        }
+    }
+}
+ 
+// Per-provider call budgets (JSON) and where the running counters are kept
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProviderBudgetConfig {
+    pub budgets_path: Option<String>,
+    pub usage_path: Option<String>,
+}
+ 
+// Retry and circuit breaker settings applied to every provider
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProviderResilienceConfig {
+    pub max_retries: u32,
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
+    pub failure_threshold: u32,
+    pub cool_down_secs: u64,
+}
+ 
+#[derive(Debug, Clone, Deserialize)]
 pub struct RiskThresholds {
     pub critical: f64,    // >= 90
     pub high: f64,        // >= 75
@@ -61,12 +174,129 @@ pub struct RiskThresholds {
     pub low: f64,         // >= 25
 }
  
+impl RiskThresholds {
+    // Score range of a level; upper bounds sit just under the next threshold
+    pub fn band_for_level(&self, level: &types::RiskLevel) -> (f64, f64) {
+        let below = |x: f64| x - 0.01;
+        match level {
+            types::RiskLevel::Safe | types::RiskLevel::Unknown => (0.0, below(self.low)),
+            types::RiskLevel::Low => (self.low, below(self.medium)),
+            types::RiskLevel::Medium => (self.medium, below(self.high)),
+            types::RiskLevel::High => (self.high, below(self.critical)),
+            types::RiskLevel::Critical => (self.critical, 100.0),
+        }
+    }
+}
+ 
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScoringConfig {
+    // Tree ensemble dump (XGBoost or LightGBM JSON); weighted average when unset
+    pub model_path: Option<String>,
+    // XGBoost dumps carry neither; LightGBM dumps name their own objective
+    pub model_objective: String,
+    pub model_base_score: f64,
+    // Calibrated category weights and confidence multipliers for the weighted average
+    pub weights_path: Option<String>,
+    // Share of labelled cases held out to measure calibrated weights
+    pub calibration_holdout: f64,
+}
+ 
 #[derive(Debug, Clone, Deserialize)]
 pub struct MonitoringConfig {
     pub poll_interval_minutes: u64,
     pub batch_size: usize,
     pub retry_attempts: u32,
     pub cache_ttl_minutes: u64,
+    pub suspension_review_minutes: u64,
+    pub scheduling: SchedulingConfig,
+    pub transaction_screening: TransactionScreeningConfig,
+    pub sybil_detection: SybilDetectionConfig,
+}
+ 
+// Per-level re-check intervals for the monitoring queue
+#[derive(Debug, Clone, Deserialize)]
+pub struct SchedulingConfig {
+    pub tick_seconds: u64,
+    pub critical_minutes: u64,
+    pub high_minutes: u64,
+    pub medium_minutes: u64,
+    pub low_minutes: u64,
+    pub safe_minutes: u64,
+    pub unknown_minutes: u64,
+    pub anomaly_minutes: u64,
+    pub anomaly_window_hours: u64,
+    pub expiry_window_hours: u64,
+    pub retry_minutes: u64,
+}
+ 
+// Polling for new transfers by monitored wallets
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransactionScreeningConfig {
+    pub enabled: bool,
+    pub poll_seconds: u64,
+    // Page size when reading signatures; every page back to the last screened one is read
+    pub max_signatures_per_poll: usize,
+}
+ 
+// Clustering monitored wallets that look like one operator
+#[derive(Debug, Clone, Deserialize)]
+pub struct SybilDetectionConfig {
+    pub enabled: bool,
+    pub poll_minutes: u64,
+    // Recent transactions fetched per wallet on each poll
+    pub signatures_per_wallet: usize,
+    // Wallets whose activity is fetched from the RPC node at once
+    pub max_concurrent_fetches: usize,
+    // Transactions this close together count as synchronized
+    pub sync_window_secs: i64,
+    pub min_synchronized_events: usize,
+    // Weaker links don't join wallets into a cluster
+    pub min_confidence: f64,
+}
+ 
+#[derive(Debug, Clone, Deserialize)]
+pub struct HysteresisConfig {
+    pub band: f64,
+    pub upgrade_cycles: u32,
+    pub downgrade_cycles: u32,
+    pub state_path: Option<String>,
+}
+ 
+#[derive(Debug, Clone, Deserialize)]
+pub struct SourceRequirements {
+    pub required_sources: Vec<DataSource>,
+    pub fail_mode: FailMode,
+}
+ 
+#[derive(Debug, Clone, Deserialize)]
+pub struct EnforcementGuardConfig {
+    pub max_per_cycle: Option<usize>,
+    // Share of monitored wallets that may be enforced within one window; cycles
+    // run as often as wallets fall due, so the cap can't be per cycle
+    pub max_percent_per_window: Option<f64>,
+    pub max_per_hour: Option<usize>,
+    pub window_minutes: u64,
+}
+ 
+#[derive(Debug, Clone, Deserialize)]
+pub struct NotificationConfig {
+    pub webhook_url: Option<String>,
+    pub webhook_secret: Option<String>,
+    pub chat_webhook_url: Option<String>,
+    pub smtp: Option<SmtpConfig>,
+    pub retry_attempts: u32,
+    pub retry_delay_ms: u64,
+    pub timeout_secs: u64,
+}
+ 
+#[derive(Debug, Clone, Deserialize)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+    pub from_address: String,
+    pub compliance_recipients: Vec<String>,
 }
  
 impl RiskEngineConfig {
@@ -87,6 +317,10 @@ impl RiskEngineConfig {
                     .unwrap_or_else(|_| "true".to_string())
                     .parse()
                     .unwrap_or(true),
+                entity_lookup: env::var("TRM_ENTITY_LOOKUP")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .unwrap_or(true),
             },
             
             chainalysis: ChainalysisConfig {
@@ -94,6 +328,12 @@ impl RiskEngineConfig {
                     .map_err(|_| ConfigError::MissingEnvVar("CHAINALYSIS_API_KEY".to_string()))?,
                 api_url: env::var("CHAINALYSIS_API_URL")
                     .unwrap_or_else(|_| "https://api.chainalysis.com".to_string()),
+                sanctions_api_url: env::var("CHAINALYSIS_SANCTIONS_API_URL")
+                    .unwrap_or_else(|_| "https://public.chainalysis.com".to_string()),
+                timeout_secs: env::var("CHAINALYSIS_TIMEOUT")
+                    .unwrap_or_else(|_| "30".to_string())
+                    .parse()
+                    .unwrap_or(30),
                 kyt_enabled: env::var("CHAINALYSIS_KYT_ENABLED")
                     .unwrap_or_else(|_| "true".to_string())
                     .parse()
@@ -105,15 +345,90 @@ impl RiskEngineConfig {
             },
             
             crystal: CrystalConfig {
-                api_key: env::var("CRYSTAL_API_KEY").ok(),
+                api_key: env::var("CRYSTAL_API_KEY").ok().filter(|k| !k.is_empty()),
                 api_url: env::var("CRYSTAL_API_URL")
                     .unwrap_or_else(|_| "https://api.crystalblockchain.com".to_string()),
+                timeout_secs: env::var("CRYSTAL_TIMEOUT")
+                    .unwrap_or_else(|_| "30".to_string())
+                    .parse()
+                    .unwrap_or(30),
                 enable_cross_chain: env::var("CRYSTAL_CROSS_CHAIN")
                     .unwrap_or_else(|_| "false".to_string())
                     .parse()
                     .unwrap_or(false),
             },
             
+            ofac_sdn: OfacSdnConfig {
+                list_path: env::var("OFAC_SDN_PATH").ok(),
+                download_url: env::var("OFAC_SDN_URL")
+                    .unwrap_or_else(|_| "https://www.treasury.gov/ofac/downloads/sdn.xml".to_string()),
+            },
+            
+            providers: env::var("RISK_PROVIDERS").ok().map(|v| {
+                v.split(',')
+                    .map(|s| s.trim().to_lowercase())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            }),
+            
+            provider_resilience: ProviderResilienceConfig {
+                max_retries: env::var("PROVIDER_MAX_RETRIES")
+                    .unwrap_or_else(|_| "2".to_string())
+                    .parse()
+                    .unwrap_or(2),
+                base_delay_ms: env::var("PROVIDER_RETRY_BASE_MS")
+                    .unwrap_or_else(|_| "250".to_string())
+                    .parse()
+                    .unwrap_or(250),
+                max_delay_ms: env::var("PROVIDER_RETRY_MAX_MS")
+                    .unwrap_or_else(|_| "5000".to_string())
+                    .parse()
+                    .unwrap_or(5000),
+                failure_threshold: env::var("PROVIDER_BREAKER_THRESHOLD")
+                    .unwrap_or_else(|_| "5".to_string())
+                    .parse()
+                    .unwrap_or(5),
+                cool_down_secs: env::var("PROVIDER_BREAKER_COOLDOWN_SECS")
+                    .unwrap_or_else(|_| "60".to_string())
+                    .parse()
+                    .unwrap_or(60),
+            },
+            
+            provider_cache: ProviderCacheConfig {
+                backend: env::var("PROVIDER_CACHE_BACKEND")
+                    .unwrap_or_else(|_| "memory".to_string())
+                    .parse()
+                    .map_err(ConfigError::InvalidConfig)?,
+                path: env::var("PROVIDER_CACHE_PATH")
+                    .unwrap_or_else(|_| "provider_cache.db".to_string()),
+                default_ttl_secs: env::var("PROVIDER_CACHE_TTL_SECS")
+                    .unwrap_or_else(|_| "900".to_string())
+                    .parse()
+                    .unwrap_or(900),
+                // e.g. "trm=900,chainalysis=3600"; the local SDN list is never cached
+                provider_ttl_secs: env::var("PROVIDER_CACHE_TTLS")
+                    .unwrap_or_else(|_| "ofac_sdn=0".to_string())
+                    .split(',')
+                    .filter(|s| !s.trim().is_empty())
+                    .map(|entry| {
+                        let (name, ttl) = entry.split_once('=')
+                            .ok_or_else(|| ConfigError::InvalidConfig(format!("Invalid provider TTL: {}", entry)))?;
+                        let ttl = ttl.trim().parse()
+                            .map_err(|_| ConfigError::InvalidConfig(format!("Invalid provider TTL: {}", entry)))?;
+                        Ok((name.trim().to_lowercase(), ttl))
+                    })
+                    .collect::<Result<HashMap<_, _>, ConfigError>>()?,
+                negative_ttl_secs: env::var("PROVIDER_CACHE_NEGATIVE_TTL_SECS")
+                    .unwrap_or_else(|_| "300".to_string())
+                    .parse()
+                    .unwrap_or(300),
+            },
+            
+            provider_budgets: ProviderBudgetConfig {
+                budgets_path: env::var("PROVIDER_BUDGETS_PATH").ok(),
+                usage_path: env::var("PROVIDER_USAGE_PATH").ok(),
+            },
+            
             thresholds: RiskThresholds {
                 critical: env::var("THRESHOLD_CRITICAL")
                     .unwrap_or_else(|_| "90.0".to_string())
@@ -133,6 +448,21 @@ impl RiskEngineConfig {
                     .unwrap_or(25.0),
             },
             
+            scoring: ScoringConfig {
+                model_path: env::var("SCORING_MODEL_PATH").ok(),
+                model_objective: env::var("SCORING_MODEL_OBJECTIVE")
+                    .unwrap_or_else(|_| "binary:logistic".to_string()),
+                model_base_score: env::var("SCORING_MODEL_BASE_SCORE")
+                    .unwrap_or_else(|_| "0.5".to_string())
+                    .parse()
+                    .unwrap_or(0.5),
+                weights_path: env::var("SCORING_WEIGHTS_PATH").ok(),
+                calibration_holdout: env::var("CALIBRATION_HOLDOUT")
+                    .unwrap_or_else(|_| "0.2".to_string())
+                    .parse()
+                    .unwrap_or(0.2),
+            },
+            
             monitoring: MonitoringConfig {
                 poll_interval_minutes: env::var("POLL_INTERVAL_MINUTES")
                     .unwrap_or_else(|_| "60".to_string())
@@ -150,12 +480,207 @@ impl RiskEngineConfig {
                     .unwrap_or_else(|_| "15".to_string())
                     .parse()
                     .unwrap_or(15),
+                suspension_review_minutes: env::var("SUSPENSION_REVIEW_MINUTES")
+                    .unwrap_or_else(|_| "15".to_string())
+                    .parse()
+                    .unwrap_or(15),
+                scheduling: SchedulingConfig {
+                    tick_seconds: env::var("SCHEDULER_TICK_SECONDS")
+                        .unwrap_or_else(|_| "60".to_string())
+                        .parse()
+                        .unwrap_or(60),
+                    critical_minutes: env::var("CHECK_INTERVAL_CRITICAL_MINUTES")
+                        .unwrap_or_else(|_| "15".to_string())
+                        .parse()
+                        .unwrap_or(15),
+                    high_minutes: env::var("CHECK_INTERVAL_HIGH_MINUTES")
+                        .unwrap_or_else(|_| "30".to_string())
+                        .parse()
+                        .unwrap_or(30),
+                    medium_minutes: env::var("CHECK_INTERVAL_MEDIUM_MINUTES")
+                        .ok()
+                        .and_then(|v| v.parse().ok())
+                        .or_else(|| env::var("POLL_INTERVAL_MINUTES").ok().and_then(|v| v.parse().ok()))
+                        .unwrap_or(60),
+                    low_minutes: env::var("CHECK_INTERVAL_LOW_MINUTES")
+                        .unwrap_or_else(|_| "240".to_string())
+                        .parse()
+                        .unwrap_or(240),
+                    safe_minutes: env::var("CHECK_INTERVAL_SAFE_MINUTES")
+                        .unwrap_or_else(|_| "720".to_string())
+                        .parse()
+                        .unwrap_or(720),
+                    unknown_minutes: env::var("CHECK_INTERVAL_UNKNOWN_MINUTES")
+                        .unwrap_or_else(|_| "15".to_string())
+                        .parse()
+                        .unwrap_or(15),
+                    anomaly_minutes: env::var("CHECK_INTERVAL_ANOMALY_MINUTES")
+                        .unwrap_or_else(|_| "10".to_string())
+                        .parse()
+                        .unwrap_or(10),
+                    anomaly_window_hours: env::var("ANOMALY_WINDOW_HOURS")
+                        .unwrap_or_else(|_| "24".to_string())
+                        .parse()
+                        .unwrap_or(24),
+                    expiry_window_hours: env::var("EXPIRY_WINDOW_HOURS")
+                        .unwrap_or_else(|_| "72".to_string())
+                        .parse()
+                        .unwrap_or(72),
+                    retry_minutes: env::var("CHECK_RETRY_MINUTES")
+                        .unwrap_or_else(|_| "5".to_string())
+                        .parse()
+                        .unwrap_or(5),
+                },
+                transaction_screening: TransactionScreeningConfig {
+                    enabled: env::var("TX_SCREENING_ENABLED")
+                        .unwrap_or_else(|_| "true".to_string())
+                        .parse()
+                        .unwrap_or(true),
+                    poll_seconds: env::var("TX_POLL_SECONDS")
+                        .unwrap_or_else(|_| "60".to_string())
+                        .parse()
+                        .unwrap_or(60),
+                    max_signatures_per_poll: env::var("TX_MAX_SIGNATURES")
+                        .unwrap_or_else(|_| "50".to_string())
+                        .parse()
+                        .unwrap_or(50),
+                },
+                sybil_detection: SybilDetectionConfig {
+                    enabled: env::var("SYBIL_DETECTION_ENABLED")
+                        .unwrap_or_else(|_| "true".to_string())
+                        .parse()
+                        .unwrap_or(true),
+                    poll_minutes: env::var("SYBIL_POLL_MINUTES")
+                        .unwrap_or_else(|_| "60".to_string())
+                        .parse()
+                        .unwrap_or(60),
+                    signatures_per_wallet: env::var("SYBIL_SIGNATURES_PER_WALLET")
+                        .unwrap_or_else(|_| "100".to_string())
+                        .parse()
+                        .unwrap_or(100),
+                    max_concurrent_fetches: env::var("SYBIL_MAX_CONCURRENT_FETCHES")
+                        .unwrap_or_else(|_| "8".to_string())
+                        .parse()
+                        .unwrap_or(8),
+                    sync_window_secs: env::var("SYBIL_SYNC_WINDOW_SECS")
+                        .unwrap_or_else(|_| "30".to_string())
+                        .parse()
+                        .unwrap_or(30),
+                    min_synchronized_events: env::var("SYBIL_MIN_SYNCHRONIZED_EVENTS")
+                        .unwrap_or_else(|_| "3".to_string())
+                        .parse()
+                        .unwrap_or(3),
+                    min_confidence: env::var("SYBIL_MIN_CONFIDENCE")
+                        .unwrap_or_else(|_| "0.6".to_string())
+                        .parse()
+                        .unwrap_or(0.6),
+                },
             },
             
+            notifications: NotificationConfig {
+                webhook_url: env::var("NOTIFY_WEBHOOK_URL").ok(),
+                webhook_secret: env::var("NOTIFY_WEBHOOK_SECRET").ok(),
+                chat_webhook_url: env::var("NOTIFY_CHAT_WEBHOOK_URL").ok(),
+                smtp: match env::var("SMTP_HOST") {
+                    Ok(host) => Some(SmtpConfig {
+                        host,
+                        port: env::var("SMTP_PORT")
+                            .unwrap_or_else(|_| "587".to_string())
+                            .parse()
+                            .unwrap_or(587),
+                        username: env::var("SMTP_USERNAME")
+                            .map_err(|_| ConfigError::MissingEnvVar("SMTP_USERNAME".to_string()))?,
+                        password: env::var("SMTP_PASSWORD")
+                            .map_err(|_| ConfigError::MissingEnvVar("SMTP_PASSWORD".to_string()))?,
+                        from_address: env::var("SMTP_FROM")
+                            .map_err(|_| ConfigError::MissingEnvVar("SMTP_FROM".to_string()))?,
+                        compliance_recipients: env::var("COMPLIANCE_TEAM_EMAILS")
+                            .unwrap_or_default()
+                            .split(',')
+                            .map(|s| s.trim().to_string())
+                            .filter(|s| !s.is_empty())
+                            .collect(),
+                    }),
+                    Err(_) => None,
+                },
+                retry_attempts: env::var("NOTIFY_RETRY_ATTEMPTS")
+                    .unwrap_or_else(|_| "3".to_string())
+                    .parse()
+                    .unwrap_or(3),
+                retry_delay_ms: env::var("NOTIFY_RETRY_DELAY_MS")
+                    .unwrap_or_else(|_| "500".to_string())
+                    .parse()
+                    .unwrap_or(500),
+                timeout_secs: env::var("NOTIFY_TIMEOUT")
+                    .unwrap_or_else(|_| "10".to_string())
+                    .parse()
+                    .unwrap_or(10),
+            },
+            
+            hysteresis: HysteresisConfig {
+                band: env::var("HYSTERESIS_BAND")
+                    .unwrap_or_else(|_| "3.0".to_string())
+                    .parse()
+                    .unwrap_or(3.0),
+                upgrade_cycles: env::var("HYSTERESIS_UPGRADE_CYCLES")
+                    .unwrap_or_else(|_| "1".to_string())
+                    .parse()
+                    .unwrap_or(1),
+                downgrade_cycles: env::var("HYSTERESIS_DOWNGRADE_CYCLES")
+                    .unwrap_or_else(|_| "2".to_string())
+                    .parse()
+                    .unwrap_or(2),
+                state_path: env::var("RISK_LEVEL_STATE_PATH").ok(),
+            },
+            
+            enforcement_guard: EnforcementGuardConfig {
+                max_per_cycle: env::var("ENFORCEMENT_MAX_PER_CYCLE")
+                    .ok()
+                    .and_then(|v| v.parse().ok()),
+                max_percent_per_window: Some(
+                    env::var("ENFORCEMENT_MAX_PERCENT_PER_WINDOW")
+                        .unwrap_or_else(|_| "5.0".to_string())
+                        .parse()
+                        .unwrap_or(5.0),
+                ),
+                max_per_hour: Some(
+                    env::var("ENFORCEMENT_MAX_PER_HOUR")
+                        .unwrap_or_else(|_| "50".to_string())
+                        .parse()
+                        .unwrap_or(50),
+                ),
+                window_minutes: env::var("ENFORCEMENT_WINDOW_MINUTES")
+                    .unwrap_or_else(|_| "60".to_string())
+                    .parse()
+                    .unwrap_or(60),
+            },
+            
+            source_requirements: SourceRequirements {
+                required_sources: env::var("REQUIRED_SOURCES")
+                    .unwrap_or_default()
+                    .split(',')
+                    .filter(|s| !s.trim().is_empty())
+                    .map(|s| s.parse().map_err(ConfigError::InvalidConfig))
+                    .collect::<Result<Vec<_>, _>>()?,
+                fail_mode: env::var("SOURCE_FAIL_MODE")
+                    .unwrap_or_else(|_| "keep_previous".to_string())
+                    .parse()
+                    .map_err(ConfigError::InvalidConfig)?,
+            },
+            
+            policy_bundles_path: env::var("POLICY_BUNDLES_PATH").ok(),
+            floor_rules_path: env::var("FLOOR_RULES_PATH").ok(),
+            jurisdiction_rules_path: env::var("JURISDICTION_RULES_PATH").ok(),
+            overrides_path: env::var("ANALYST_OVERRIDES_PATH").ok(),
+            labels_path: env::var("ANALYST_LABELS_PATH").ok(),
+            assessments_path: env::var("ASSESSMENT_SNAPSHOTS_PATH").ok(),
+            sybil_clusters_path: env::var("SYBIL_CLUSTERS_PATH").ok(),
+            
             rpc_url: env::var("SOLANA_RPC_URL")
                 .unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".to_string()),
             program_id: env::var("RISK_PROGRAM_ID")
                 .unwrap_or_else(|_| "RiskAwareAttestation11111111111111111111111".to_string()),
+            attestation_registry_program_id: env::var("ATTESTATION_REGISTRY_PROGRAM_ID").ok(),
             risk_engine_wallet: env::var("RISK_ENGINE_WALLET")
                 .map_err(|_| ConfigError::MissingEnvVar("RISK_ENGINE_WALLET".to_string()))?,
         })
@@ -0,0 +1,150 @@
+use crate::risk_engine::types::*;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone)]
+struct ClusterState {
+    attribution: EntityAttribution,
+    members: HashSet<String>,
+    // Wallet whose provider data rated the cluster high risk
+    flagged_by: Option<String>,
+}
+
+// Wallets grouped by the entity providers attribute them to, so risk found
+// on one wallet is applied to the rest of its cluster.
+#[derive(Debug, Clone)]
+pub struct EntityClusterIndex {
+    high_risk_score: f64,
+    clusters: HashMap<String, ClusterState>,
+    wallet_cluster: HashMap<String, String>,
+    pending_rescore: Vec<String>,
+}
+
+impl EntityClusterIndex {
+    pub fn new(high_risk_score: f64) -> Self {
+        Self {
+            high_risk_score,
+            clusters: HashMap::new(),
+            wallet_cluster: HashMap::new(),
+            pending_rescore: Vec::new(),
+        }
+    }
+
+    // Records the wallet's latest attribution. When this makes the cluster
+    // high risk, the other known members are queued for re-scoring.
+    pub fn observe(&mut self, wallet_address: &str, entity: Option<&EntityAttribution>) {
+        if let Some(previous) = self.wallet_cluster.get(wallet_address).cloned() {
+            if entity.map(|e| &e.entity_id) != Some(&previous) {
+                self.leave(wallet_address, &previous);
+            }
+        }
+
+        let Some(entity) = entity else { return };
+
+        let state = self.clusters.entry(entity.entity_id.clone()).or_insert_with(|| ClusterState {
+            attribution: entity.clone(),
+            members: HashSet::new(),
+            flagged_by: None,
+        });
+
+        // Keep every address any lookup has placed in the entity
+        let mut addresses = std::mem::take(&mut state.attribution.addresses);
+        for address in &entity.addresses {
+            if !addresses.contains(address) {
+                addresses.push(address.clone());
+            }
+        }
+        state.attribution = EntityAttribution { addresses, ..entity.clone() };
+        state.members.insert(wallet_address.to_string());
+        state.members.extend(entity.addresses.iter().cloned());
+
+        let is_high_risk = entity.risk_score >= self.high_risk_score;
+        match state.flagged_by.as_deref() {
+            None if is_high_risk => {
+                state.flagged_by = Some(wallet_address.to_string());
+                self.pending_rescore.extend(
+                    state.members.iter().filter(|m| m.as_str() != wallet_address).cloned()
+                );
+            }
+            Some(flagged_by) if flagged_by == wallet_address && !is_high_risk => {
+                state.flagged_by = None;
+            }
+            _ => {}
+        }
+
+        // Addresses only known from the entity lookup don't override their own attribution
+        for address in &entity.addresses {
+            self.wallet_cluster.entry(address.clone()).or_insert_with(|| entity.entity_id.clone());
+        }
+        self.wallet_cluster.insert(wallet_address.to_string(), entity.entity_id.clone());
+    }
+
+    // Attribution of a high-risk cluster the wallet belongs to, when the risk
+    // was found on another member
+    pub fn exposure_for(&self, wallet_address: &str) -> Option<&EntityAttribution> {
+        let state = self.clusters.get(self.wallet_cluster.get(wallet_address)?)?;
+        match state.flagged_by.as_deref() {
+            Some(flagged_by) if flagged_by != wallet_address => Some(&state.attribution),
+            _ => None,
+        }
+    }
+
+    pub fn take_pending_rescore(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.pending_rescore)
+    }
+
+    pub fn remove(&mut self, wallet_address: &str) {
+        if let Some(cluster_id) = self.wallet_cluster.get(wallet_address).cloned() {
+            self.leave(wallet_address, &cluster_id);
+        }
+        self.pending_rescore.retain(|w| w != wallet_address);
+    }
+
+    fn leave(&mut self, wallet_address: &str, cluster_id: &str) {
+        self.wallet_cluster.remove(wallet_address);
+
+        if let Some(state) = self.clusters.get_mut(cluster_id) {
+            state.members.remove(wallet_address);
+            if state.flagged_by.as_deref() == Some(wallet_address) {
+                state.flagged_by = None;
+            }
+            if state.members.is_empty() {
+                self.clusters.remove(cluster_id);
+            }
+        }
+    }
+}
+
+// Exposure a wallet inherits from a high-risk cluster it was attributed to
+pub fn cluster_exposure_indicator(wallet_address: &str, attribution: &EntityAttribution) -> RiskIndicator {
+    let now = chrono::Utc::now();
+
+    RiskIndicator {
+        id: format!("cluster_{}_{}", attribution.entity_id, wallet_address),
+        category: RiskCategory::CounterpartyRisk,
+        subcategory: "entity_cluster".to_string(),
+        score: attribution.risk_score,
+        confidence: 0.6,
+        description: format!(
+            "Member of high-risk {} cluster {} ({})",
+            attribution.category,
+            attribution.name.as_deref().unwrap_or(&attribution.entity_id),
+            attribution.entity_type
+        ),
+        evidence: vec![Evidence {
+            source: attribution.source.clone(),
+            raw_data: serde_json::to_value(attribution).unwrap_or_default(),
+            extracted_info: HashMap::from([
+                ("entity_id".to_string(), attribution.entity_id.clone()),
+                ("category".to_string(), attribution.category.clone()),
+            ]),
+            timestamp: now,
+        }],
+        first_seen: now,
+        last_seen: now,
+        transaction_hashes: Vec::new(),
+        addresses_involved: Vec::new(),
+        metadata: HashMap::from([
+            ("entity_id".to_string(), serde_json::json!(attribution.entity_id)),
+        ]),
+    }
+}
@@ -0,0 +1,180 @@
+use crate::risk_engine::types::*;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use tracing::info;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JurisdictionRule {
+    // ISO 3166-1 alpha-2 code
+    pub country: String,
+    pub rating: JurisdictionRating,
+    pub due_diligence: DueDiligenceLevel,
+    #[serde(default)]
+    pub lists: Vec<String>,
+    // Indicator score; the rating's default when unset
+    #[serde(default)]
+    pub score: Option<f64>,
+}
+
+// One dated release of the country ratings, e.g. after an FATF plenary.
+// Countries a pack doesn't list get no indicator.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JurisdictionRulePack {
+    pub version: String,
+    pub effective_from: DateTime<Utc>,
+    #[serde(default)]
+    pub source: Option<String>,
+    pub rules: Vec<JurisdictionRule>,
+}
+
+impl JurisdictionRulePack {
+    fn validate(&self) -> Result<(), String> {
+        let mut seen = HashSet::new();
+        for rule in &self.rules {
+            if rule.country.len() != 2 || !rule.country.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(format!(
+                    "Rule pack {} has invalid country code {:?}",
+                    self.version, rule.country
+                ));
+            }
+            if !seen.insert(rule.country.to_ascii_uppercase()) {
+                return Err(format!("Rule pack {} lists {} twice", self.version, rule.country));
+            }
+            if let Some(score) = rule.score {
+                if !(0.0..=100.0).contains(&score) {
+                    return Err(format!(
+                        "Rule pack {} scores {} outside [0, 100]",
+                        self.version, rule.country
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// Every loaded pack version; the newest one already in effect is applied, so
+// the next release can be staged ahead of its effective date.
+#[derive(Debug, Clone, Default)]
+pub struct JurisdictionRules {
+    packs: Vec<JurisdictionRulePack>,
+}
+
+impl JurisdictionRules {
+    pub fn new(mut packs: Vec<JurisdictionRulePack>) -> Result<Self, String> {
+        let mut versions = HashSet::new();
+        for pack in &packs {
+            pack.validate()?;
+            if !versions.insert(pack.version.clone()) {
+                return Err(format!("Duplicate jurisdiction rule pack version {}", pack.version));
+            }
+        }
+
+        packs.sort_by_key(|pack| pack.effective_from);
+        Ok(Self { packs })
+    }
+
+    // A single pack file, or a directory with one pack per .json file
+    pub fn load(path: &str) -> Result<Self, String> {
+        let mut files = Vec::new();
+        if Path::new(path).is_dir() {
+            let entries = std::fs::read_dir(path)
+                .map_err(|e| format!("Failed to read jurisdiction rule packs {}: {}", path, e))?;
+            for entry in entries {
+                let file = entry
+                    .map_err(|e| format!("Failed to read jurisdiction rule packs {}: {}", path, e))?
+                    .path();
+                if file.extension().map(|ext| ext == "json").unwrap_or(false) {
+                    files.push(file);
+                }
+            }
+        } else {
+            files.push(Path::new(path).to_path_buf());
+        }
+
+        let mut packs = Vec::new();
+        for file in files {
+            let contents = std::fs::read_to_string(&file)
+                .map_err(|e| format!("Failed to read jurisdiction rule pack {}: {}", file.display(), e))?;
+            let pack: JurisdictionRulePack = serde_json::from_str(&contents)
+                .map_err(|e| format!("Invalid jurisdiction rule pack {}: {}", file.display(), e))?;
+            packs.push(pack);
+        }
+
+        let rules = Self::new(packs)?;
+        info!(
+            "Loaded {} jurisdiction rule packs from {}, active version {}",
+            rules.packs.len(),
+            path,
+            rules.active_pack(Utc::now()).map(|p| p.version.as_str()).unwrap_or("none")
+        );
+        Ok(rules)
+    }
+
+    pub fn active_pack(&self, at: DateTime<Utc>) -> Option<&JurisdictionRulePack> {
+        self.packs.iter().rev().find(|pack| pack.effective_from <= at)
+    }
+
+    pub fn assess(&self, jurisdiction: &str) -> Option<JurisdictionAssessment> {
+        let pack = self.active_pack(Utc::now())?;
+        let country = jurisdiction.trim();
+        let rule = pack.rules.iter().find(|r| r.country.eq_ignore_ascii_case(country))?;
+
+        Some(JurisdictionAssessment {
+            country: rule.country.to_ascii_uppercase(),
+            rating: rule.rating,
+            due_diligence: rule.due_diligence,
+            score: rule.score.unwrap_or_else(|| rule.rating.default_score()),
+            lists: rule.lists.clone(),
+            pack_version: pack.version.clone(),
+        })
+    }
+}
+
+// Standard-rated jurisdictions carry no indicator
+pub fn jurisdiction_indicator(wallet_address: &str, assessment: &JurisdictionAssessment) -> Option<RiskIndicator> {
+    if assessment.rating == JurisdictionRating::Standard {
+        return None;
+    }
+
+    let now = Utc::now();
+    let lists = if assessment.lists.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", assessment.lists.join(", "))
+    };
+
+    Some(RiskIndicator {
+        id: format!("jurisdiction_{}_{}", assessment.country, wallet_address),
+        category: RiskCategory::GeographicRisk,
+        subcategory: "jurisdiction".to_string(),
+        score: assessment.score,
+        // Taken from the attestation, not inferred
+        confidence: 1.0,
+        description: format!(
+            "Attested jurisdiction {} rated {}{}",
+            assessment.country,
+            assessment.rating.as_str(),
+            lists
+        ),
+        evidence: vec![Evidence {
+            source: DataSource::Internal,
+            raw_data: serde_json::to_value(assessment).unwrap_or_default(),
+            extracted_info: HashMap::from([
+                ("country".to_string(), assessment.country.clone()),
+                ("pack_version".to_string(), assessment.pack_version.clone()),
+            ]),
+            timestamp: now,
+        }],
+        first_seen: now,
+        last_seen: now,
+        transaction_hashes: Vec::new(),
+        addresses_involved: Vec::new(),
+        metadata: HashMap::from([
+            ("due_diligence".to_string(), serde_json::json!(assessment.due_diligence)),
+            ("pack_version".to_string(), serde_json::json!(assessment.pack_version)),
+        ]),
+    })
+}
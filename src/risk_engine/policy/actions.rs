@@ -1,5 +1,6 @@
 use crate::risk_engine::types::*;
 use crate::risk_engine::config::RiskEngineConfig;
+use crate::risk_engine::policy::{Notification, NotificationDispatcher};
 use solana_sdk::{
     pubkey::Pubkey,
     signature::{Keypair, Signature},
@@ -20,6 +21,8 @@ pub enum ActionError {
     SignerError(String),
     #[error("Configuration error: {0}")]
     ConfigError(String),
+    #[error("Notification error: {0}")]
+    NotificationError(String),
 }
 
 #[derive(Debug, Clone)]
@@ -28,6 +31,7 @@ pub struct ActionExecutor {
     config: Arc<RiskEngineConfig>,
     risk_engine_keypair: Keypair,
     program_id: Pubkey,
+    notifier: Arc<NotificationDispatcher>,
 }
 
 impl ActionExecutor {
@@ -39,11 +43,15 @@ impl ActionExecutor {
         let program_id = Pubkey::from_str(&config.program_id)
             .map_err(|e| ActionError::ConfigError(format!("Invalid program ID: {}", e)))?;
         
+        let notifier = NotificationDispatcher::from_config(&config.notifications)
+            .map_err(|e| ActionError::NotificationError(e.to_string()))?;
+        
         Ok(Self {
             rpc_client,
             config,
             risk_engine_keypair,
             program_id,
+            notifier: Arc::new(notifier),
         })
     }
     
@@ -79,6 +87,10 @@ impl ActionExecutor {
                 self.revoke_attestation(wallet_address, attestation_key, &action.parameters)
                     .await
             }
+            ActionType::ReinstateAttestation => {
+                self.reinstate_attestation(wallet_address, attestation_key)
+                    .await
+            }
             ActionType::NoAction => {
                 Ok(ActionExecutionResult {
                     action_type: ActionType::NoAction,
@@ -88,20 +100,41 @@ impl ActionExecutor {
                     timestamp: chrono::Utc::now(),
                 })
             }
-            _ => {
-                // For notification actions, just log them
-                info!("Notification action: {:?} for wallet {}", action.action_type, wallet_address);
-                Ok(ActionExecutionResult {
-                    action_type: action.action_type.clone(),
-                    success: true,
-                    transaction_signature: None,
-                    message: "Notification sent".to_string(),
-                    timestamp: chrono::Utc::now(),
-                })
+            ActionType::NotifyUser
+            | ActionType::NotifyComplianceTeam
+            | ActionType::RequestAdditionalKyc
+            | ActionType::EscalateToCompliance => {
+                self.send_notification(&action.action_type, wallet_address, &action.parameters, risk_profile)
+                    .await
             }
         }
     }
     
+    async fn send_notification(
+        &self,
+        action_type: &ActionType,
+        wallet_address: &str,
+        parameters: &ActionParameters,
+        risk_profile: &WalletRiskProfile,
+    ) -> Result<ActionExecutionResult, ActionError> {
+        let notification = Notification::for_action(action_type, parameters, wallet_address, risk_profile);
+        let report = self.notifier.dispatch(&notification).await;
+        
+        if report.delivered() {
+            info!("Notification {:?} for wallet {}: {}", action_type, wallet_address, report.summary());
+        } else {
+            error!("Notification {:?} for wallet {} not delivered: {}", action_type, wallet_address, report.summary());
+        }
+        
+        Ok(ActionExecutionResult {
+            action_type: action_type.clone(),
+            success: report.delivered(),
+            transaction_signature: None,
+            message: report.summary(),
+            timestamp: chrono::Utc::now(),
+        })
+    }
+    
     async fn flag_attestation(
         &self,
         wallet_address: &str,
@@ -229,6 +262,39 @@ impl ActionExecutor {
         }
     }
     
+    pub async fn reinstate_attestation(
+        &self,
+        wallet_address: &str,
+        attestation_key: &Pubkey,
+    ) -> Result<ActionExecutionResult, ActionError> {
+        // Build reinstate instruction
+        let instruction = risk_aware_attestation::instruction::reinstate_attestation(
+            &self.program_id,
+            attestation_key,
+            &self.risk_engine_keypair.pubkey(),
+        ).map_err(|e| ActionError::TransactionError(e.to_string()))?;
+        
+        let transaction = self.build_transaction(vec![instruction]).await?;
+        
+        match self.send_transaction(transaction).await {
+            Ok(signature) => {
+                info!("Reinstated attestation {} for wallet {}", attestation_key, wallet_address);
+                
+                Ok(ActionExecutionResult {
+                    action_type: ActionType::ReinstateAttestation,
+                    success: true,
+                    transaction_signature: Some(signature.to_string()),
+                    message: "Reinstated".to_string(),
+                    timestamp: chrono::Utc::now(),
+                })
+            }
+            Err(e) => {
+                error!("Failed to reinstate attestation {}: {}", attestation_key, e);
+                Err(ActionError::TransactionError(format!("Reinstate failed: {}", e)))
+            }
+        }
+    }
+    
     async fn build_transaction(
         &self,
         instructions: Vec<solana_sdk::instruction::Instruction>,
@@ -392,6 +458,39 @@ pub mod risk_aware_attestation {
             
             Ok(())
         }
+        
+        // Permissionless once the suspension has ended; the risk engine may reinstate early
+        pub fn reinstate_attestation(ctx: Context<ReinstateAttestation>) -> Result<()> {
+            let attestation = &mut ctx.accounts.attestation;
+            let now = Clock::get()?.unix_timestamp;
+            
+            require!(
+                attestation.risk_status == RiskStatus::Suspended,
+                ErrorCode::NotSuspended
+            );
+            
+            let suspension_ended = attestation.suspension_end
+                .map(|end| now >= end)
+                .unwrap_or(true);
+            let is_risk_engine = attestation.issuer == ctx.accounts.caller.key();
+            
+            require!(
+                suspension_ended || is_risk_engine,
+                ErrorCode::SuspensionActive
+            );
+            
+            attestation.risk_status = RiskStatus::Active;
+            attestation.suspension_end = None;
+            
+            emit!(AttestationReinstated {
+                attestation: attestation.key(),
+                reinstated_by: ctx.accounts.caller.key(),
+                early: !suspension_ended,
+                timestamp: now,
+            });
+            
+            Ok(())
+        }
     }
     
     #[derive(Accounts)]
@@ -415,6 +514,13 @@ pub mod risk_aware_attestation {
         pub risk_engine: Signer<'info>,
     }
     
+    #[derive(Accounts)]
+    pub struct ReinstateAttestation<'info> {
+        #[account(mut)]
+        pub attestation: Account<'info, RiskAwareAttestation>,
+        pub caller: Signer<'info>,
+    }
+    
     #[account]
     pub struct RiskAwareAttestation {
         pub subject: Pubkey,              // Wallet being attested
@@ -456,6 +562,14 @@ pub mod risk_aware_attestation {
         pub timestamp: i64,
     }
     
+    #[event]
+    pub struct AttestationReinstated {
+        pub attestation: Pubkey,
+        pub reinstated_by: Pubkey,
+        pub early: bool,
+        pub timestamp: i64,
+    }
+    
     #[error_code]
     pub enum ErrorCode {
         #[msg("Unauthorized action")]
@@ -464,5 +578,9 @@ pub mod risk_aware_attestation {
         AttestationNotFound,
         #[msg("Invalid risk score")]
         InvalidRiskScore,
+        #[msg("Attestation is not suspended")]
+        NotSuspended,
+        #[msg("Suspension has not ended")]
+        SuspensionActive,
     }
 }
\ No newline at end of file
@@ -0,0 +1,133 @@
+use crate::risk_engine::config::EnforcementGuardConfig;
+use crate::risk_engine::policy::ActionType;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::VecDeque;
+use tracing::error;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GuardDecision {
+    Allowed,
+    Deferred(String),
+}
+
+// Caps how many suspensions and revocations automated enforcement may issue,
+// so a provider returning garbage cannot revoke every attestation at once.
+// Limits are counted over rolling windows, since cycles run whenever wallets
+// fall due. Once a limit is hit the guard latches until an operator resets it.
+#[derive(Debug, Clone)]
+pub struct EnforcementGuard {
+    config: EnforcementGuardConfig,
+    monitored_wallets: usize,
+    cycle_count: usize,
+    recent: VecDeque<DateTime<Utc>>,
+    tripped: Option<String>,
+}
+
+impl EnforcementGuard {
+    pub fn new(config: EnforcementGuardConfig) -> Self {
+        Self {
+            config,
+            monitored_wallets: 0,
+            cycle_count: 0,
+            recent: VecDeque::new(),
+            tripped: None,
+        }
+    }
+
+    pub fn begin_cycle(&mut self, monitored_wallets: usize) {
+        self.monitored_wallets = monitored_wallets;
+        self.cycle_count = 0;
+    }
+
+    pub fn is_tripped(&self) -> bool {
+        self.tripped.is_some()
+    }
+
+    pub fn trip_reason(&self) -> Option<&str> {
+        self.tripped.as_deref()
+    }
+
+    pub fn reset(&mut self) {
+        self.tripped = None;
+        self.cycle_count = 0;
+        self.recent.clear();
+    }
+
+    // Returns Some(reason) on the call that trips the guard, so the caller
+    // can raise a single alert.
+    pub fn check(&mut self, action_type: &ActionType) -> (GuardDecision, Option<String>) {
+        if let Some(reason) = &self.tripped {
+            if Self::is_automatic(action_type) {
+                return (GuardDecision::Deferred(reason.clone()), None);
+            }
+            return (GuardDecision::Allowed, None);
+        }
+
+        if !Self::is_limited(action_type) {
+            return (GuardDecision::Allowed, None);
+        }
+
+        let now = Utc::now();
+        let retention = Duration::hours(1).max(Duration::minutes(self.config.window_minutes as i64));
+        while let Some(oldest) = self.recent.front() {
+            if now - *oldest > retention {
+                self.recent.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        if let Some(reason) = self.limit_exceeded() {
+            error!("Enforcement guard tripped: {}", reason);
+            self.tripped = Some(reason.clone());
+            return (GuardDecision::Deferred(reason.clone()), Some(reason));
+        }
+
+        self.cycle_count += 1;
+        self.recent.push_back(now);
+        (GuardDecision::Allowed, None)
+    }
+
+    fn limit_exceeded(&self) -> Option<String> {
+        if let Some(max) = self.config.max_per_cycle {
+            if self.cycle_count >= max {
+                return Some(format!("{} enforcement actions this cycle (limit {})", self.cycle_count, max));
+            }
+        }
+
+        if let Some(max_percent) = self.config.max_percent_per_window {
+            let allowed = ((self.monitored_wallets as f64 * max_percent / 100.0).ceil() as usize).max(1);
+            let in_window = self.count_since(Duration::minutes(self.config.window_minutes as i64));
+            if in_window >= allowed {
+                return Some(format!(
+                    "{} of {} wallets enforced in the last {} minutes (limit {:.1}%)",
+                    in_window, self.monitored_wallets, self.config.window_minutes, max_percent
+                ));
+            }
+        }
+
+        if let Some(max) = self.config.max_per_hour {
+            let in_hour = self.count_since(Duration::hours(1));
+            if in_hour >= max {
+                return Some(format!("{} enforcement actions in the last hour (limit {})", in_hour, max));
+            }
+        }
+
+        None
+    }
+
+    fn count_since(&self, window: Duration) -> usize {
+        let cutoff = Utc::now() - window;
+        self.recent.iter().filter(|at| **at > cutoff).count()
+    }
+
+    fn is_limited(action_type: &ActionType) -> bool {
+        matches!(action_type, ActionType::SuspendAttestation | ActionType::RevokeAttestation)
+    }
+
+    // Everything that changes attestation state or contacts users is held back
+    // while tripped; compliance notifications still go out.
+    fn is_automatic(action_type: &ActionType) -> bool {
+        !matches!(action_type, ActionType::NotifyComplianceTeam | ActionType::EscalateToCompliance | ActionType::NoAction)
+    }
+}
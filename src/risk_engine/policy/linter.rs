@@ -0,0 +1,400 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::config::RiskThresholds;
+use crate::risk_engine::jurisdiction::JurisdictionRules;
+use crate::risk_engine::policy::{ActionType, PolicyConditions, PolicyManager, RiskPolicy, DEFAULT_BUNDLE};
+use chrono::Utc;
+use std::collections::HashSet;
+use std::fmt;
+
+// Scores are clamped to this range by the scoring model
+const SCORE_FLOOR: f64 = 0.0;
+const SCORE_CEILING: f64 = 100.0;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LintSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub enum LintFinding {
+    UnreachablePolicy {
+        policy_id: String,
+        reason: String,
+    },
+    ConflictingActions {
+        policy_a: String,
+        policy_b: String,
+        overlap: ScoreRange,
+        actions_a: Vec<ActionType>,
+        actions_b: Vec<ActionType>,
+    },
+    UncoveredRange {
+        range: ScoreRange,
+    },
+}
+
+impl LintFinding {
+    pub fn severity(&self) -> LintSeverity {
+        match self {
+            LintFinding::UnreachablePolicy { .. } => LintSeverity::Error,
+            LintFinding::ConflictingActions { .. } => LintSeverity::Warning,
+            LintFinding::UncoveredRange { .. } => LintSeverity::Info,
+        }
+    }
+}
+
+impl fmt::Display for LintFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LintFinding::UnreachablePolicy { policy_id, reason } => {
+                write!(f, "[error] policy '{}' can never match: {}", policy_id, reason)
+            }
+            LintFinding::ConflictingActions { policy_a, policy_b, overlap, actions_a, actions_b } => {
+                write!(
+                    f,
+                    "[warning] policies '{}' and '{}' both match scores {} with contradictory actions {:?} vs {:?}",
+                    policy_a, policy_b, overlap, actions_a, actions_b
+                )
+            }
+            LintFinding::UncoveredRange { range } => {
+                write!(f, "[info] no active policy covers scores {}", range)
+            }
+        }
+    }
+}
+
+// Half-open score interval [min, max)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreRange {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl ScoreRange {
+    fn is_empty(&self) -> bool {
+        self.min >= self.max
+    }
+
+    fn intersect(&self, other: &ScoreRange) -> ScoreRange {
+        ScoreRange {
+            min: self.min.max(other.min),
+            max: self.max.min(other.max),
+        }
+    }
+}
+
+impl fmt::Display for ScoreRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.max > SCORE_CEILING {
+            write!(f, "[{:.1}, {:.1}]", self.min, SCORE_CEILING)
+        } else {
+            write!(f, "[{:.1}, {:.1})", self.min, self.max)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PolicyLintReport {
+    pub bundle: String,
+    pub policies_checked: usize,
+    pub findings: Vec<LintFinding>,
+}
+
+impl PolicyLintReport {
+    pub fn has_errors(&self) -> bool {
+        self.findings.iter().any(|f| f.severity() == LintSeverity::Error)
+    }
+
+    pub fn count(&self, severity: LintSeverity) -> usize {
+        self.findings.iter().filter(|f| f.severity() == severity).count()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PolicyLinter {
+    thresholds: RiskThresholds,
+    // Resolves due_diligence_min to the countries the active pack rates at that level
+    jurisdiction_rules: Option<JurisdictionRules>,
+}
+
+impl PolicyLinter {
+    pub fn new(thresholds: RiskThresholds) -> Self {
+        Self { thresholds, jurisdiction_rules: None }
+    }
+
+    pub fn with_jurisdiction_rules(mut self, rules: JurisdictionRules) -> Self {
+        self.jurisdiction_rules = Some(rules);
+        self
+    }
+
+    // Bundles are evaluated independently, so each one is linted on its own
+    pub fn lint_manager(&self, manager: &PolicyManager) -> Vec<PolicyLintReport> {
+        let mut reports = vec![self.lint(DEFAULT_BUNDLE, manager.policies())];
+        for bundle in manager.bundles() {
+            reports.push(self.lint(&bundle.name, &bundle.policies));
+        }
+        reports
+    }
+
+    pub fn lint(&self, bundle: &str, policies: &[RiskPolicy]) -> PolicyLintReport {
+        let mut findings = Vec::new();
+        let active: Vec<&RiskPolicy> = policies.iter().filter(|p| p.active).collect();
+
+        // Effective score region for every reachable policy
+        let mut regions: Vec<(&RiskPolicy, ScoreRange)> = Vec::new();
+        for policy in &active {
+            match self.effective_range(policy) {
+                Ok(range) => regions.push((policy, range)),
+                Err(reason) => findings.push(LintFinding::UnreachablePolicy {
+                    policy_id: policy.id.clone(),
+                    reason,
+                }),
+            }
+        }
+
+        // Pairwise overlaps with contradictory enforcement
+        for (i, (policy_a, range_a)) in regions.iter().enumerate() {
+            for (policy_b, range_b) in regions.iter().skip(i + 1) {
+                let overlap = range_a.intersect(range_b);
+                if overlap.is_empty() {
+                    continue;
+                }
+
+                if !self.can_co_match(policy_a, policy_b) {
+                    continue;
+                }
+
+                if Self::actions_conflict(policy_a, policy_b) {
+                    findings.push(LintFinding::ConflictingActions {
+                        policy_a: policy_a.id.clone(),
+                        policy_b: policy_b.id.clone(),
+                        overlap,
+                        actions_a: Self::enforcement_actions(policy_a),
+                        actions_b: Self::enforcement_actions(policy_b),
+                    });
+                }
+            }
+        }
+
+        // Gaps in [0, 100] not covered by any reachable policy; only policies
+        // that match every wallet at their scores count towards coverage
+        let mut ranges: Vec<ScoreRange> = regions.iter()
+            .filter(|(p, _)| Self::is_unconditional(p))
+            .map(|(_, r)| *r)
+            .collect();
+        ranges.sort_by(|a, b| a.min.partial_cmp(&b.min).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut cursor = SCORE_FLOOR;
+        for range in &ranges {
+            if range.min > cursor {
+                findings.push(LintFinding::UncoveredRange {
+                    range: ScoreRange { min: cursor, max: range.min },
+                });
+            }
+            cursor = cursor.max(range.max);
+        }
+        if cursor <= SCORE_CEILING {
+            findings.push(LintFinding::UncoveredRange {
+                range: ScoreRange { min: cursor, max: f64::INFINITY },
+            });
+        }
+
+        findings.sort_by(|a, b| b.severity().cmp(&a.severity()));
+
+        PolicyLintReport {
+            bundle: bundle.to_string(),
+            policies_checked: active.len(),
+            findings,
+        }
+    }
+
+    fn effective_range(&self, policy: &RiskPolicy) -> Result<ScoreRange, String> {
+        let conditions = &policy.conditions;
+
+        // A max at the ceiling still matches the top score
+        let declared = ScoreRange {
+            min: conditions.risk_score_min.unwrap_or(SCORE_FLOOR),
+            max: conditions.risk_score_max
+                .filter(|max| *max < SCORE_CEILING)
+                .unwrap_or(f64::INFINITY),
+        };
+
+        if declared.is_empty() {
+            return Err(format!(
+                "risk_score_min {:.1} is not below risk_score_max {:.1}",
+                declared.min, declared.max
+            ));
+        }
+
+        if declared.min > SCORE_CEILING {
+            return Err(format!("risk_score_min {:.1} exceeds the maximum score", declared.min));
+        }
+
+        let has_score_condition = conditions.risk_score_min.is_some() || conditions.risk_score_max.is_some();
+        if conditions.risk_level == Some(RiskLevel::Unknown) && has_score_condition {
+            return Err("risk_level unknown never matches a score condition".to_string());
+        }
+
+        let range = match &conditions.risk_level {
+            Some(level) => {
+                let band = self.level_band(level);
+                let range = declared.intersect(&band);
+                if range.is_empty() {
+                    return Err(format!(
+                        "risk_level {} only covers scores {}, which does not intersect {}",
+                        level.as_str(), band, declared
+                    ));
+                }
+                range
+            }
+            None => declared,
+        };
+
+        if let (Some(min_level), Some(countries)) = (conditions.due_diligence_min, self.countries(policy)) {
+            if countries.is_empty() {
+                return Err(format!(
+                    "no listed jurisdiction in the active rule pack requires {} due diligence or more",
+                    min_level.as_str()
+                ));
+            }
+        }
+
+        if policy.actions.is_empty() {
+            return Err("policy has no actions".to_string());
+        }
+
+        Ok(range)
+    }
+
+    // Jurisdictions a policy can match; None when any jurisdiction can
+    fn countries(&self, policy: &RiskPolicy) -> Option<HashSet<String>> {
+        let conditions = &policy.conditions;
+        let listed: HashSet<String> = conditions.jurisdictions.iter()
+            .map(|c| c.trim().to_ascii_uppercase())
+            .collect();
+
+        let rated = conditions.due_diligence_min.and_then(|min_level| {
+            let pack = self.jurisdiction_rules.as_ref()?.active_pack(Utc::now())?;
+            Some(pack.rules.iter()
+                .filter(|rule| rule.due_diligence >= min_level)
+                .map(|rule| rule.country.to_ascii_uppercase())
+                .collect::<HashSet<String>>())
+        });
+
+        match rated {
+            Some(rated) if listed.is_empty() => Some(rated),
+            Some(rated) => Some(listed.intersection(&rated).cloned().collect()),
+            None if listed.is_empty() => None,
+            None => Some(listed),
+        }
+    }
+
+    // Conditions that no single profile can satisfy for both policies at once
+    fn can_co_match(&self, a: &RiskPolicy, b: &RiskPolicy) -> bool {
+        let (ca, cb) = (&a.conditions, &b.conditions);
+
+        // Transaction policies are evaluated separately from wallet policies
+        if ca.transaction_score_min.is_some() != cb.transaction_score_min.is_some() {
+            return false;
+        }
+
+        // A profile has exactly one level, so differing levels never co-match
+        if let (Some(level_a), Some(level_b)) = (&ca.risk_level, &cb.risk_level) {
+            if level_a != level_b {
+                return false;
+            }
+        }
+
+        // Unknown profiles never satisfy score conditions
+        let scored = |c: &PolicyConditions| c.risk_score_min.is_some() || c.risk_score_max.is_some();
+        if (ca.risk_level == Some(RiskLevel::Unknown) && scored(cb))
+            || (cb.risk_level == Some(RiskLevel::Unknown) && scored(ca))
+        {
+            return false;
+        }
+
+        // Providers either disagreed or they didn't
+        if let (Some(disagree_a), Some(disagree_b)) = (ca.provider_disagreement, cb.provider_disagreement) {
+            if disagree_a != disagree_b {
+                return false;
+            }
+        }
+
+        // Categories and required sources only narrow a match; a profile can
+        // carry several of each, so they never rule out the other policy
+
+        // A wallet has one attested jurisdiction
+        if let (Some(countries_a), Some(countries_b)) = (self.countries(a), self.countries(b)) {
+            if countries_a.is_disjoint(&countries_b) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    // Policies narrowed by anything but score and level only match some wallets
+    fn is_unconditional(policy: &RiskPolicy) -> bool {
+        let c = &policy.conditions;
+        c.risk_level != Some(RiskLevel::Unknown)
+            && c.transaction_score_min.is_none()
+            && c.categories.is_empty()
+            && c.required_sources.is_empty()
+            && c.fail_mode.is_none()
+            && c.provider_disagreement.is_none()
+            && c.jurisdictions.is_empty()
+            && c.due_diligence_min.is_none()
+            && c.sybil_cluster_confidence_min.is_none()
+            && c.age_of_attestation_days.is_none()
+            && c.transaction_volume_threshold.is_none()
+    }
+
+    fn level_band(&self, level: &RiskLevel) -> ScoreRange {
+        let t = &self.thresholds;
+        let (min, max) = match level {
+            RiskLevel::Safe => (SCORE_FLOOR, t.low),
+            RiskLevel::Low => (t.low, t.medium),
+            RiskLevel::Medium => (t.medium, t.high),
+            RiskLevel::High => (t.high, t.critical),
+            RiskLevel::Critical => (t.critical, f64::INFINITY),
+            // Unknown profiles carry no usable score
+            RiskLevel::Unknown => (SCORE_FLOOR, f64::INFINITY),
+        };
+        ScoreRange { min, max }
+    }
+
+    fn is_enforcement(action_type: &ActionType) -> bool {
+        matches!(
+            action_type,
+            ActionType::FlagAttestation | ActionType::SuspendAttestation | ActionType::RevokeAttestation
+        )
+    }
+
+    fn enforcement_actions(policy: &RiskPolicy) -> Vec<ActionType> {
+        policy.actions.iter()
+            .map(|a| a.action_type.clone())
+            .filter(Self::is_enforcement)
+            .collect()
+    }
+
+    // Two policies conflict when they would leave the attestation in different
+    // states, or apply the same enforcement with different approval requirements.
+    fn actions_conflict(a: &RiskPolicy, b: &RiskPolicy) -> bool {
+        for action_a in &a.actions {
+            for action_b in &b.actions {
+                if !Self::is_enforcement(&action_a.action_type) || !Self::is_enforcement(&action_b.action_type) {
+                    continue;
+                }
+
+                if action_a.action_type != action_b.action_type
+                    || action_a.requires_approval != action_b.requires_approval
+                {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
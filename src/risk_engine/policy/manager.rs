@@ -1,16 +1,54 @@
 use crate::risk_engine::types::*;
-use crate::risk_engine::config::RiskEngineConfig;
-use std::sync::Arc;
+use crate::risk_engine::config::{RiskEngineConfig, SourceRequirements};
+use crate::risk_engine::policy::{AnalystOverride, OverrideStore};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
 use chrono::{Duration, Utc};
+use thiserror::Error;
+use tracing::info;
+
+pub const DEFAULT_BUNDLE: &str = "default";
+
+#[derive(Error, Debug)]
+pub enum PolicyError {
+    #[error("Failed to read policy bundles: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Invalid policy bundle file: {0}")]
+    ParseError(String),
+    #[error("Duplicate policy bundle: {0}")]
+    DuplicateBundle(String),
+}
 
 #[derive(Debug, Clone)]
 pub struct PolicyManager {
     config: Arc<RiskEngineConfig>,
     policies: Vec<RiskPolicy>,
     escalation_path: EscalationPath,
+    bundles: Vec<PolicyBundle>,
+    overrides: Arc<RwLock<OverrideStore>>,
 }
 
-#[derive(Debug, Clone)]
+// Named policy set selected by attestation jurisdiction; wallets whose
+// jurisdiction matches no bundle fall back to the default policies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyBundle {
+    pub name: String,
+    pub jurisdictions: Vec<String>,
+    pub policies: Vec<RiskPolicy>,
+    pub escalation_path: Option<EscalationPath>,
+    #[serde(default)]
+    pub required_sources: Vec<DataSource>,
+    #[serde(default)]
+    pub fail_mode: Option<FailMode>,
+}
+
+impl PolicyBundle {
+    pub fn covers(&self, jurisdiction: &str) -> bool {
+        self.jurisdictions.iter().any(|j| j.eq_ignore_ascii_case(jurisdiction.trim()))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RiskPolicy {
     pub id: String,
     pub name: String,
@@ -20,7 +58,7 @@ pub struct RiskPolicy {
     pub active: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PolicyConditions {
     pub risk_level: Option<RiskLevel>,
     pub risk_score_min: Option<f64>,
@@ -29,9 +67,29 @@ pub struct PolicyConditions {
     pub age_of_attestation_days: Option<u32>,
     pub transaction_volume_threshold: Option<f64>,
     pub required_sources: Vec<DataSource>,
+    // TreatAsHigh lets the policy fire when a required source is missing
+    // because of an outage; any other mode leaves the policy unmatched.
+    #[serde(default)]
+    pub fail_mode: Option<FailMode>,
+    // Some(true) matches only when providers disagreed, Some(false) only when they agreed
+    #[serde(default)]
+    pub provider_disagreement: Option<bool>,
+    // Transaction policies only match a screened transaction scoring at least this;
+    // all other policies are skipped while a transaction is evaluated
+    #[serde(default)]
+    pub transaction_score_min: Option<f64>,
+    // Attested jurisdictions (country codes) the policy is limited to; empty matches any
+    #[serde(default)]
+    pub jurisdictions: Vec<String>,
+    // Matches when the jurisdiction rule pack requires at least this level
+    #[serde(default)]
+    pub due_diligence_min: Option<DueDiligenceLevel>,
+    // Matches wallets in a sybil cluster detected with at least this confidence
+    #[serde(default)]
+    pub sybil_cluster_confidence_min: Option<f64>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PolicyAction {
     pub action_type: ActionType,
     pub parameters: ActionParameters,
@@ -39,28 +97,32 @@ pub struct PolicyAction {
     pub requires_approval: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ActionType {
     NoAction,
     FlagAttestation,
     SuspendAttestation,
     RevokeAttestation,
+    ReinstateAttestation,
     RequestAdditionalKyc,
     EscalateToCompliance,
     NotifyUser,
     NotifyComplianceTeam,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ActionParameters {
     pub flag_reason: Option<String>,
     pub suspension_duration_days: Option<u32>,
     pub revocation_reason: Option<String>,
     pub notification_message: Option<String>,
     pub escalation_level: Option<u32>,
+    // Level of additional KYC requested; standard when unset
+    pub due_diligence: Option<DueDiligenceLevel>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PolicyPriority {
     Low,
     Medium,
@@ -68,13 +130,13 @@ pub enum PolicyPriority {
     Critical,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EscalationPath {
     pub levels: Vec<EscalationLevel>,
     pub auto_escalate_hours: u32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EscalationLevel {
     pub level: u32,
     pub risk_threshold: f64,
@@ -98,6 +160,12 @@ impl PolicyManager {
                 age_of_attestation_days: None,
                 transaction_volume_threshold: None,
                 required_sources: vec![DataSource::TrmLabs, DataSource::Chainalysis],
+                fail_mode: None,
+                provider_disagreement: None,
+                transaction_score_min: None,
+                jurisdictions: vec![],
+                due_diligence_min: None,
+                sybil_cluster_confidence_min: None,
             },
             actions: vec![
                 PolicyAction {
@@ -135,6 +203,12 @@ impl PolicyManager {
                 age_of_attestation_days: None,
                 transaction_volume_threshold: None,
                 required_sources: vec![],
+                fail_mode: None,
+                provider_disagreement: None,
+                transaction_score_min: None,
+                jurisdictions: vec![],
+                due_diligence_min: None,
+                sybil_cluster_confidence_min: None,
             },
             actions: vec![
                 PolicyAction {
@@ -172,6 +246,12 @@ impl PolicyManager {
                 age_of_attestation_days: None,
                 transaction_volume_threshold: None,
                 required_sources: vec![],
+                fail_mode: None,
+                provider_disagreement: None,
+                transaction_score_min: None,
+                jurisdictions: vec![],
+                due_diligence_min: None,
+                sybil_cluster_confidence_min: None,
             },
             actions: vec![
                 PolicyAction {
@@ -209,6 +289,12 @@ impl PolicyManager {
                 age_of_attestation_days: None,
                 transaction_volume_threshold: None,
                 required_sources: vec![DataSource::TrmLabs, DataSource::Chainalysis],
+                fail_mode: None,
+                provider_disagreement: None,
+                transaction_score_min: None,
+                jurisdictions: vec![],
+                due_diligence_min: None,
+                sybil_cluster_confidence_min: None,
             },
             actions: vec![
                 PolicyAction {
@@ -246,6 +332,12 @@ impl PolicyManager {
                 age_of_attestation_days: Some(30),
                 transaction_volume_threshold: Some(10000.0),
                 required_sources: vec![],
+                fail_mode: None,
+                provider_disagreement: None,
+                transaction_score_min: None,
+                jurisdictions: vec![],
+                due_diligence_min: None,
+                sybil_cluster_confidence_min: None,
             },
             actions: vec![
                 PolicyAction {
@@ -271,6 +363,127 @@ impl PolicyManager {
             active: true,
         });
         
+        // High Risk Transaction Policy
+        policies.push(RiskPolicy {
+            id: "high_risk_transaction".to_string(),
+            name: "High Risk Transaction Review".to_string(),
+            conditions: PolicyConditions {
+                risk_level: None,
+                risk_score_min: None,
+                risk_score_max: None,
+                categories: vec![],
+                age_of_attestation_days: None,
+                transaction_volume_threshold: None,
+                required_sources: vec![],
+                fail_mode: None,
+                provider_disagreement: None,
+                transaction_score_min: Some(config.thresholds.high),
+                jurisdictions: vec![],
+                due_diligence_min: None,
+                sybil_cluster_confidence_min: None,
+            },
+            actions: vec![
+                PolicyAction {
+                    action_type: ActionType::FlagAttestation,
+                    parameters: ActionParameters {
+                        flag_reason: Some("High risk transaction detected".to_string()),
+                        ..Default::default()
+                    },
+                    delay_minutes: Some(0),
+                    requires_approval: false,
+                },
+                PolicyAction {
+                    action_type: ActionType::NotifyComplianceTeam,
+                    parameters: ActionParameters {
+                        notification_message: Some("High risk transaction by {wallet} (score {risk_score})".to_string()),
+                        ..Default::default()
+                    },
+                    delay_minutes: Some(0),
+                    requires_approval: false,
+                },
+            ],
+            priority: PolicyPriority::High,
+            active: true,
+        });
+        
+        // Jurisdiction Enhanced Due Diligence Policy
+        policies.push(RiskPolicy {
+            id: "jurisdiction_edd".to_string(),
+            name: "Jurisdiction Enhanced Due Diligence".to_string(),
+            conditions: PolicyConditions {
+                risk_level: None,
+                risk_score_min: None,
+                risk_score_max: None,
+                categories: vec![],
+                age_of_attestation_days: None,
+                transaction_volume_threshold: None,
+                required_sources: vec![],
+                fail_mode: None,
+                provider_disagreement: None,
+                transaction_score_min: None,
+                jurisdictions: vec![],
+                due_diligence_min: Some(DueDiligenceLevel::Enhanced),
+                sybil_cluster_confidence_min: None,
+            },
+            actions: vec![
+                PolicyAction {
+                    action_type: ActionType::RequestAdditionalKyc,
+                    parameters: ActionParameters {
+                        notification_message: Some("Enhanced due diligence required for your jurisdiction".to_string()),
+                        due_diligence: Some(DueDiligenceLevel::Enhanced),
+                        ..Default::default()
+                    },
+                    delay_minutes: Some(0),
+                    requires_approval: false,
+                },
+            ],
+            priority: PolicyPriority::Medium,
+            active: true,
+        });
+        
+        // Sybil Cluster Policy
+        policies.push(RiskPolicy {
+            id: "sybil_cluster_flag".to_string(),
+            name: "Sybil Cluster Review".to_string(),
+            conditions: PolicyConditions {
+                risk_level: None,
+                risk_score_min: None,
+                risk_score_max: None,
+                categories: vec![],
+                age_of_attestation_days: None,
+                transaction_volume_threshold: None,
+                required_sources: vec![],
+                fail_mode: None,
+                provider_disagreement: None,
+                transaction_score_min: None,
+                jurisdictions: vec![],
+                due_diligence_min: None,
+                sybil_cluster_confidence_min: Some(0.8),
+            },
+            actions: vec![
+                PolicyAction {
+                    action_type: ActionType::FlagAttestation,
+                    parameters: ActionParameters {
+                        flag_reason: Some("Wallet clustered with other attested wallets".to_string()),
+                        ..Default::default()
+                    },
+                    delay_minutes: Some(0),
+                    requires_approval: true,
+                },
+                PolicyAction {
+                    action_type: ActionType::NotifyComplianceTeam,
+                    parameters: ActionParameters {
+                        notification_message: Some("{wallet} is in a sybil cluster".to_string()),
+                        ..Default::default()
+                    },
+                    delay_minutes: Some(0),
+                    requires_approval: true,
+                },
+            ],
+            priority: PolicyPriority::Medium,
+            active: true,
+        });
+        
         let escalation_path = EscalationPath {
             levels: vec![
                 EscalationLevel {
@@ -299,6 +512,91 @@ impl PolicyManager {
             config,
             policies,
             escalation_path,
+            bundles: Vec::new(),
+            overrides: Arc::new(RwLock::new(OverrideStore::default())),
+        }
+    }
+    
+    pub fn load_overrides(&self, path: &str) -> Result<usize, String> {
+        let store = OverrideStore::load(path)?;
+        let mut overrides = self.overrides.write().map_err(|e| e.to_string())?;
+        *overrides = store;
+        Ok(overrides.purge_expired())
+    }
+    
+    pub fn save_overrides(&self, path: &str) -> Result<(), String> {
+        self.overrides.read().map_err(|e| e.to_string())?.save(path)
+    }
+    
+    pub fn add_override(&self, analyst_override: AnalystOverride) -> Result<(), String> {
+        if analyst_override.justification.trim().is_empty() || analyst_override.author.trim().is_empty() {
+            return Err("Overrides require an author and a justification".to_string());
+        }
+        if !analyst_override.is_active() {
+            return Err("Override expiry must be in the future".to_string());
+        }
+        
+        info!("Override {} added for {} by {}", analyst_override.id,
+            analyst_override.wallet_address, analyst_override.author);
+        self.overrides.write().map_err(|e| e.to_string())?.add(analyst_override);
+        Ok(())
+    }
+    
+    pub fn remove_override(&self, override_id: &str) -> Result<Option<AnalystOverride>, String> {
+        Ok(self.overrides.write().map_err(|e| e.to_string())?.remove(override_id))
+    }
+    
+    pub fn active_overrides(&self, wallet_address: &str) -> Vec<AnalystOverride> {
+        self.overrides.read()
+            .map(|store| store.active_for(wallet_address))
+            .unwrap_or_default()
+    }
+    
+    pub fn load_bundles(&mut self, path: &str) -> Result<usize, PolicyError> {
+        let contents = std::fs::read_to_string(path)?;
+        let bundles: Vec<PolicyBundle> = serde_json::from_str(&contents)
+            .map_err(|e| PolicyError::ParseError(e.to_string()))?;
+        
+        let count = bundles.len();
+        for bundle in bundles {
+            self.add_bundle(bundle)?;
+        }
+        
+        info!("Loaded {} policy bundles from {}", count, path);
+        Ok(count)
+    }
+    
+    pub fn add_bundle(&mut self, bundle: PolicyBundle) -> Result<(), PolicyError> {
+        if bundle.name == DEFAULT_BUNDLE || self.bundles.iter().any(|b| b.name == bundle.name) {
+            return Err(PolicyError::DuplicateBundle(bundle.name));
+        }
+        
+        self.bundles.push(bundle);
+        Ok(())
+    }
+    
+    pub fn bundles(&self) -> &[PolicyBundle] {
+        &self.bundles
+    }
+    
+    pub fn select_bundle(&self, jurisdiction: Option<&str>) -> Option<&PolicyBundle> {
+        jurisdiction.and_then(|j| self.bundles.iter().find(|b| b.covers(j)))
+    }
+    
+    // Bundles inherit the default requirements for anything they leave unset
+    pub fn source_requirements(&self, jurisdiction: Option<&str>) -> SourceRequirements {
+        let default = &self.config.source_requirements;
+        
+        match self.select_bundle(jurisdiction) {
+            Some(bundle) => SourceRequirements {
+                required_sources: if bundle.required_sources.is_empty() {
+                    default.required_sources.clone()
+                } else {
+                    bundle.required_sources.clone()
+                },
+                fail_mode: bundle.fail_mode.clone().unwrap_or_else(|| default.fail_mode.clone()),
+            },
+            None => default.clone(),
         }
     }
     
@@ -308,20 +606,54 @@ impl PolicyManager {
         attestation_age_days: Option<u32>,
         recent_volume: Option<f64>,
     ) -> PolicyEvaluationResult {
+        self.evaluate_policy_for_jurisdiction(risk_profile, None, attestation_age_days, recent_volume)
+    }
+    
+    pub fn evaluate_policy_for_jurisdiction(
+        &self,
+        risk_profile: &WalletRiskProfile,
+        jurisdiction: Option<&str>,
+        attestation_age_days: Option<u32>,
+        recent_volume: Option<f64>,
+    ) -> PolicyEvaluationResult {
+        let applied_overrides = self.active_overrides(&risk_profile.wallet_address);
+        let adjusted_profile;
+        let risk_profile = if applied_overrides.is_empty() {
+            risk_profile
+        } else {
+            adjusted_profile = self.apply_overrides(risk_profile, &applied_overrides);
+            &adjusted_profile
+        };
+        
+        let bundle = self.select_bundle(jurisdiction);
+        let (bundle_name, policies, escalation_path) = match bundle {
+            Some(b) => (
+                b.name.clone(),
+                &b.policies,
+                b.escalation_path.as_ref().unwrap_or(&self.escalation_path),
+            ),
+            None => (DEFAULT_BUNDLE.to_string(), &self.policies, &self.escalation_path),
+        };
+        
         let mut matched_policies = Vec::new();
         let mut recommended_actions = Vec::new();
         
-        for policy in &self.policies {
+        for policy in policies {
             if !policy.active {
                 continue;
             }
             
-            if self.matches_policy(policy, risk_profile, attestation_age_days, recent_volume) {
+            if self.matches_policy(policy, risk_profile, jurisdiction, attestation_age_days, recent_volume) {
                 matched_policies.push(policy.clone());
                 recommended_actions.extend(policy.actions.clone());
             }
         }
         
+        // Drop actions an analyst has exempted this wallet from
+        recommended_actions.retain(|action| {
+            !applied_overrides.iter().any(|o| o.exempt_actions.contains(&action.action_type))
+        });
+        
         // Sort actions by priority and delay
         recommended_actions.sort_by(|a, b| {
             let priority_a = self.action_priority(&a.action_type);
@@ -337,7 +669,59 @@ impl PolicyManager {
             matched_policies,
             recommended_actions,
             evaluation_timestamp: Utc::now(),
-            escalation_level: self.determine_escalation_level(risk_profile.overall_risk_score),
+            escalation_level: Self::determine_escalation_level(escalation_path, risk_profile.overall_risk_score),
+            policy_bundle: bundle_name,
+            jurisdiction: jurisdiction.map(|j| j.to_string()),
+            applied_overrides,
+        }
+    }
+    
+    fn apply_overrides(
+        &self,
+        risk_profile: &WalletRiskProfile,
+        overrides: &[AnalystOverride],
+    ) -> WalletRiskProfile {
+        let mut profile = risk_profile.clone();
+        
+        // The service scores fresh profiles without suppressed categories, so
+        // overall_risk_score already reflects the suppression here
+        profile.risk_indicators.retain(|indicator| {
+            !overrides.iter().any(|o| o.suppressed_categories.contains(&indicator.category))
+        });
+        
+        // The tightest cap wins
+        let cap = overrides.iter()
+            .filter_map(|o| o.level_cap.clone())
+            .min_by_key(|level| level.rank());
+        
+        if let Some(cap) = cap {
+            if profile.risk_level.rank() > cap.rank() {
+                let t = &self.config.thresholds;
+                let max_score = match cap {
+                    RiskLevel::Safe | RiskLevel::Unknown => t.low - 0.01,
+                    RiskLevel::Low => t.medium - 0.01,
+                    RiskLevel::Medium => t.high - 0.01,
+                    RiskLevel::High => t.critical - 0.01,
+                    RiskLevel::Critical => 100.0,
+                };
+                profile.overall_risk_score = profile.overall_risk_score.min(max_score);
+                profile.risk_level = cap;
+            }
+        }
+        
+        profile
+    }
+    
+    // While hysteresis holds a level the score may sit outside its band; score
+    // ranges are matched as the held level so level and score policies agree
+    fn score_for_matching(&self, risk_profile: &WalletRiskProfile) -> f64 {
+        let raw_level = risk_profile.metadata.get("raw_risk_level").and_then(|v| v.as_str());
+        match raw_level {
+            Some(raw) if raw != risk_profile.risk_level.as_str() => {
+                let (min, max) = self.config.thresholds.band_for_level(&risk_profile.risk_level);
+                risk_profile.overall_risk_score.clamp(min, max)
+            }
+            _ => risk_profile.overall_risk_score,
         }
     }
     
@@ -345,6 +729,7 @@ impl PolicyManager {
         &self,
         policy: &RiskPolicy,
         risk_profile: &WalletRiskProfile,
+        jurisdiction: Option<&str>,
         attestation_age_days: Option<u32>,
         recent_volume: Option<f64>,
     ) -> bool {
@@ -355,15 +740,24 @@ impl PolicyManager {
             }
         }
         
+        // An Unknown profile's score is partial, so score conditions can't hold
+        let has_score_condition = policy.conditions.risk_score_min.is_some()
+            || policy.conditions.risk_score_max.is_some();
+        if has_score_condition && risk_profile.risk_level == RiskLevel::Unknown {
+            return false;
+        }
+        
         // Check risk score range
+        let score = self.score_for_matching(risk_profile);
         if let Some(min_score) = policy.conditions.risk_score_min {
-            if risk_profile.overall_risk_score < min_score {
+            if score < min_score {
                 return false;
             }
         }
         
+        // Scores are clamped to 100, so a max at the ceiling still includes it
         if let Some(max_score) = policy.conditions.risk_score_max {
-            if risk_profile.overall_risk_score >= max_score {
+            if max_score < 100.0 && score >= max_score {
                 return false;
             }
         }
@@ -426,10 +820,56 @@ impl PolicyManager {
                 .collect();
             
             if !required_sources.is_subset(&profile_sources) {
+                let missing_due_to_outage = required_sources
+                    .difference(&profile_sources)
+                    .all(|source| risk_profile.provider_outages.iter().any(|o| &o.source == source));
+                
+                if !(missing_due_to_outage && policy.conditions.fail_mode == Some(FailMode::TreatAsHigh)) {
+                    return false;
+                }
+            }
+        }
+        
+        // Check single-transaction risk
+        match (policy.conditions.transaction_score_min, &risk_profile.transaction_risk) {
+            (Some(min_score), Some(tx)) if tx.combined_score >= min_score => {}
+            (None, None) => {}
+            _ => return false,
+        }
+        
+        // Check provider agreement
+        if let Some(required) = policy.conditions.provider_disagreement {
+            if risk_profile.provider_disagreements.is_empty() == required {
+                return false;
+            }
+        }
+        
+        // Check attested jurisdiction
+        if !policy.conditions.jurisdictions.is_empty() {
+            let listed = jurisdiction
+                .map(|j| policy.conditions.jurisdictions.iter().any(|c| c.eq_ignore_ascii_case(j.trim())))
+                .unwrap_or(false);
+            if !listed {
                 return false;
             }
         }
         
+        // Check due diligence required by the jurisdiction rule pack
+        if let Some(min_level) = policy.conditions.due_diligence_min {
+            match &risk_profile.jurisdiction {
+                Some(assessment) if assessment.due_diligence >= min_level => {}
+                _ => return false,
+            }
+        }
+        
+        // Check sybil cluster membership
+        if let Some(min_confidence) = policy.conditions.sybil_cluster_confidence_min {
+            match &risk_profile.sybil_cluster {
+                Some(cluster) if cluster.confidence >= min_confidence => {}
+                _ => return false,
+            }
+        }
+        
         true
     }
     
@@ -442,12 +882,13 @@ impl PolicyManager {
             ActionType::RequestAdditionalKyc => 2,
             ActionType::NotifyComplianceTeam => 1,
             ActionType::NotifyUser => 1,
+            ActionType::ReinstateAttestation => 0,
             ActionType::NoAction => 0,
         }
     }
     
-    fn determine_escalation_level(&self, risk_score: f64) -> Option<u32> {
-        for level in &self.escalation_path.levels {
+    fn determine_escalation_level(escalation_path: &EscalationPath, risk_score: f64) -> Option<u32> {
+        for level in &escalation_path.levels {
             if risk_score >= level.risk_threshold {
                 return Some(level.level);
             }
@@ -459,6 +900,10 @@ impl PolicyManager {
         self.escalation_path.levels.iter().find(|l| l.level == level)
     }
     
+    pub fn policies(&self) -> &[RiskPolicy] {
+        &self.policies
+    }
+    
     pub fn add_policy(&mut self, policy: RiskPolicy) {
         self.policies.push(policy);
     }
@@ -491,6 +936,9 @@ pub struct PolicyEvaluationResult {
     pub recommended_actions: Vec<PolicyAction>,
     pub evaluation_timestamp: chrono::DateTime<Utc>,
     pub escalation_level: Option<u32>,
+    pub policy_bundle: String,
+    pub jurisdiction: Option<String>,
+    pub applied_overrides: Vec<AnalystOverride>,
 }
 
 impl Default for ActionParameters {
@@ -501,6 +949,7 @@ impl Default for ActionParameters {
             revocation_reason: None,
             notification_message: None,
             escalation_level: None,
+            due_diligence: None,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,441 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::config::{NotificationConfig, SmtpConfig};
+use crate::risk_engine::policy::{ActionParameters, ActionType};
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use lettre::message::{header::ContentType, Mailbox};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use reqwest::Client;
+use serde::Serialize;
+use sha2::Sha256;
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::time::Duration;
+use tracing::{info, warn};
+
+#[derive(Error, Debug)]
+pub enum NotificationError {
+    #[error("Network error: {0}")]
+    NetworkError(#[from] reqwest::Error),
+    #[error("Delivery rejected: {0}")]
+    Rejected(String),
+    #[error("SMTP error: {0}")]
+    SmtpError(String),
+    #[error("Configuration error: {0}")]
+    ConfigError(String),
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationRecipient {
+    User,
+    ComplianceTeam,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Notification {
+    pub id: String,
+    pub action_type: String,
+    pub recipient: NotificationRecipient,
+    pub wallet_address: String,
+    pub subject: String,
+    pub message: String,
+    pub risk_score: f64,
+    pub risk_level: String,
+    pub categories: Vec<String>,
+    // Level of additional KYC requested, for RequestAdditionalKyc
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_diligence: Option<DueDiligenceLevel>,
+    pub created_at: chrono::DateTime<Utc>,
+}
+
+impl Notification {
+    pub fn for_action(
+        action_type: &ActionType,
+        parameters: &ActionParameters,
+        wallet_address: &str,
+        risk_profile: &WalletRiskProfile,
+    ) -> Self {
+        let recipient = match action_type {
+            ActionType::NotifyUser | ActionType::RequestAdditionalKyc => NotificationRecipient::User,
+            _ => NotificationRecipient::ComplianceTeam,
+        };
+
+        let mut categories: Vec<String> = Vec::new();
+        for indicator in &risk_profile.risk_indicators {
+            let name = indicator.category.description().to_string();
+            if !categories.contains(&name) {
+                categories.push(name);
+            }
+        }
+
+        let template = parameters.notification_message.clone()
+            .unwrap_or_else(|| Self::default_template(action_type).to_string());
+
+        let message = template
+            .replace("{wallet}", wallet_address)
+            .replace("{risk_score}", &format!("{:.1}", risk_profile.overall_risk_score))
+            .replace("{risk_level}", risk_profile.risk_level.as_str())
+            .replace("{categories}", &categories.join(", "))
+            .replace(
+                "{due_diligence}",
+                parameters.due_diligence.unwrap_or(DueDiligenceLevel::Standard).as_str(),
+            );
+
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            action_type: format!("{:?}", action_type),
+            recipient,
+            wallet_address: wallet_address.to_string(),
+            subject: format!(
+                "[{}] {:?} for wallet {}",
+                risk_profile.risk_level.as_str().to_uppercase(),
+                action_type,
+                wallet_address
+            ),
+            message,
+            risk_score: risk_profile.overall_risk_score,
+            risk_level: risk_profile.risk_level.as_str().to_string(),
+            categories,
+            due_diligence: parameters.due_diligence,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn default_template(action_type: &ActionType) -> &'static str {
+        match action_type {
+            ActionType::NotifyUser => "Your attestation for {wallet} is under review (risk level: {risk_level})",
+            ActionType::RequestAdditionalKyc => "Additional KYC is required for {wallet}",
+            ActionType::EscalateToCompliance => "Wallet {wallet} escalated: score {risk_score}, categories: {categories}",
+            _ => "Wallet {wallet} scored {risk_score} ({risk_level}): {categories}",
+        }
+    }
+
+    fn as_text(&self) -> String {
+        let mut text = format!(
+            "{}\n\nWallet: {}\nRisk score: {:.1}\nRisk level: {}\nCategories: {}",
+            self.message,
+            self.wallet_address,
+            self.risk_score,
+            self.risk_level,
+            if self.categories.is_empty() { "none".to_string() } else { self.categories.join(", ") },
+        );
+        if let Some(level) = self.due_diligence {
+            text.push_str(&format!("\nDue diligence: {}", level.as_str()));
+        }
+        text
+    }
+}
+
+#[async_trait]
+pub trait Notifier: Send + Sync + std::fmt::Debug {
+    fn channel(&self) -> &'static str;
+
+    fn accepts(&self, recipient: &NotificationRecipient) -> bool;
+
+    async fn send(&self, notification: &Notification) -> Result<(), NotificationError>;
+}
+
+// Generic JSON webhook, signed with HMAC-SHA256 over "<timestamp>.<body>"
+#[derive(Debug, Clone)]
+pub struct WebhookNotifier {
+    client: Client,
+    url: String,
+    secret: String,
+}
+
+impl WebhookNotifier {
+    pub fn new(client: Client, url: String, secret: String) -> Self {
+        Self { client, url, secret }
+    }
+
+    fn sign(&self, timestamp: i64, body: &[u8]) -> Result<String, NotificationError> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.secret.as_bytes())
+            .map_err(|e| NotificationError::ConfigError(e.to_string()))?;
+        mac.update(timestamp.to_string().as_bytes());
+        mac.update(b".");
+        mac.update(body);
+        Ok(hex::encode(mac.finalize().into_bytes()))
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    fn channel(&self) -> &'static str {
+        "webhook"
+    }
+
+    fn accepts(&self, _recipient: &NotificationRecipient) -> bool {
+        true
+    }
+
+    async fn send(&self, notification: &Notification) -> Result<(), NotificationError> {
+        let body = serde_json::to_vec(notification)
+            .map_err(|e| NotificationError::ConfigError(e.to_string()))?;
+        let timestamp = Utc::now().timestamp();
+        let signature = self.sign(timestamp, &body)?;
+
+        let response = self.client
+            .post(&self.url)
+            .header("Content-Type", "application/json")
+            .header("X-Risk-Engine-Timestamp", timestamp.to_string())
+            .header("X-Risk-Engine-Signature", format!("sha256={}", signature))
+            .body(body)
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            Err(NotificationError::Rejected(format!("Status: {}, Error: {}", status, error_text)))
+        }
+    }
+}
+
+// Chat webhook (Slack / Mattermost / Teams incoming-webhook compatible payload)
+#[derive(Debug, Clone)]
+pub struct ChatNotifier {
+    client: Client,
+    url: String,
+}
+
+impl ChatNotifier {
+    pub fn new(client: Client, url: String) -> Self {
+        Self { client, url }
+    }
+}
+
+#[async_trait]
+impl Notifier for ChatNotifier {
+    fn channel(&self) -> &'static str {
+        "chat"
+    }
+
+    fn accepts(&self, recipient: &NotificationRecipient) -> bool {
+        recipient == &NotificationRecipient::ComplianceTeam
+    }
+
+    async fn send(&self, notification: &Notification) -> Result<(), NotificationError> {
+        let payload = serde_json::json!({
+            "text": format!("*{}*\n{}", notification.subject, notification.as_text()),
+        });
+
+        let response = self.client
+            .post(&self.url)
+            .json(&payload)
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            Err(NotificationError::Rejected(format!("Status: {}, Error: {}", status, error_text)))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EmailNotifier {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    recipients: Vec<Mailbox>,
+}
+
+impl EmailNotifier {
+    pub fn new(config: &SmtpConfig) -> Result<Self, NotificationError> {
+        let transport = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)
+            .map_err(|e| NotificationError::SmtpError(e.to_string()))?
+            .port(config.port)
+            .credentials(Credentials::new(config.username.clone(), config.password.clone()))
+            .build();
+
+        let from = config.from_address.parse()
+            .map_err(|e| NotificationError::ConfigError(format!("Invalid from address: {}", e)))?;
+
+        let recipients = config.compliance_recipients.iter()
+            .map(|r| r.parse()
+                .map_err(|e| NotificationError::ConfigError(format!("Invalid recipient {}: {}", r, e))))
+            .collect::<Result<Vec<Mailbox>, _>>()?;
+
+        if recipients.is_empty() {
+            return Err(NotificationError::ConfigError("No compliance recipients configured".to_string()));
+        }
+
+        Ok(Self { transport, from, recipients })
+    }
+}
+
+#[async_trait]
+impl Notifier for EmailNotifier {
+    fn channel(&self) -> &'static str {
+        "email"
+    }
+
+    // We only hold compliance team addresses; users are reached via webhook
+    fn accepts(&self, recipient: &NotificationRecipient) -> bool {
+        recipient == &NotificationRecipient::ComplianceTeam
+    }
+
+    async fn send(&self, notification: &Notification) -> Result<(), NotificationError> {
+        let mut builder = Message::builder()
+            .from(self.from.clone())
+            .subject(notification.subject.clone())
+            .header(ContentType::TEXT_PLAIN);
+
+        for recipient in &self.recipients {
+            builder = builder.to(recipient.clone());
+        }
+
+        let email = builder
+            .body(notification.as_text())
+            .map_err(|e| NotificationError::SmtpError(e.to_string()))?;
+
+        self.transport.send(email).await
+            .map(|_| ())
+            .map_err(|e| NotificationError::SmtpError(e.to_string()))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeliveryOutcome {
+    pub channel: &'static str,
+    pub delivered: bool,
+    pub attempts: u32,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationReport {
+    pub notification_id: String,
+    pub outcomes: Vec<DeliveryOutcome>,
+}
+
+impl NotificationReport {
+    pub fn delivered(&self) -> bool {
+        self.outcomes.iter().any(|o| o.delivered)
+    }
+
+    pub fn summary(&self) -> String {
+        if self.outcomes.is_empty() {
+            return "No notification channel configured for recipient".to_string();
+        }
+
+        self.outcomes.iter()
+            .map(|o| match &o.error {
+                None => format!("{}: delivered", o.channel),
+                Some(e) => format!("{}: failed after {} attempts ({})", o.channel, o.attempts, e),
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationDispatcher {
+    notifiers: Vec<Arc<dyn Notifier>>,
+    retry_attempts: u32,
+    retry_delay: Duration,
+}
+
+impl NotificationDispatcher {
+    pub fn new(notifiers: Vec<Arc<dyn Notifier>>, retry_attempts: u32, retry_delay: Duration) -> Self {
+        Self {
+            notifiers,
+            retry_attempts: retry_attempts.max(1),
+            retry_delay,
+        }
+    }
+
+    pub fn from_config(config: &NotificationConfig) -> Result<Self, NotificationError> {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build()
+            .map_err(|e| NotificationError::ConfigError(e.to_string()))?;
+
+        let mut notifiers: Vec<Arc<dyn Notifier>> = Vec::new();
+
+        if let Some(url) = &config.webhook_url {
+            let secret = config.webhook_secret.clone()
+                .ok_or_else(|| NotificationError::ConfigError("NOTIFY_WEBHOOK_SECRET is required for webhook delivery".to_string()))?;
+            notifiers.push(Arc::new(WebhookNotifier::new(client.clone(), url.clone(), secret)));
+        }
+
+        if let Some(url) = &config.chat_webhook_url {
+            notifiers.push(Arc::new(ChatNotifier::new(client.clone(), url.clone())));
+        }
+
+        if let Some(smtp) = &config.smtp {
+            notifiers.push(Arc::new(EmailNotifier::new(smtp)?));
+        }
+
+        if notifiers.is_empty() {
+            warn!("No notification channels configured; notification actions will not be delivered");
+        }
+
+        Ok(Self::new(
+            notifiers,
+            config.retry_attempts,
+            Duration::from_millis(config.retry_delay_ms),
+        ))
+    }
+
+    pub async fn dispatch(&self, notification: &Notification) -> NotificationReport {
+        let mut outcomes = Vec::new();
+
+        for notifier in self.notifiers.iter().filter(|n| n.accepts(&notification.recipient)) {
+            outcomes.push(self.deliver_with_retry(notifier.as_ref(), notification).await);
+        }
+
+        NotificationReport {
+            notification_id: notification.id.clone(),
+            outcomes,
+        }
+    }
+
+    async fn deliver_with_retry(
+        &self,
+        notifier: &dyn Notifier,
+        notification: &Notification,
+    ) -> DeliveryOutcome {
+        let mut delay = self.retry_delay;
+        let mut last_error = None;
+
+        for attempt in 1..=self.retry_attempts {
+            match notifier.send(notification).await {
+                Ok(()) => {
+                    info!("Notification {} delivered via {} (attempt {})",
+                        notification.id, notifier.channel(), attempt);
+                    return DeliveryOutcome {
+                        channel: notifier.channel(),
+                        delivered: true,
+                        attempts: attempt,
+                        error: None,
+                    };
+                }
+                Err(e) => {
+                    warn!("Notification {} via {} failed (attempt {}/{}): {}",
+                        notification.id, notifier.channel(), attempt, self.retry_attempts, e);
+                    last_error = Some(e.to_string());
+
+                    if attempt < self.retry_attempts {
+                        tokio::time::sleep(delay).await;
+                        delay *= 2;
+                    }
+                }
+            }
+        }
+
+        DeliveryOutcome {
+            channel: notifier.channel(),
+            delivered: false,
+            attempts: self.retry_attempts,
+            error: last_error,
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::policy::ActionType;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// Analyst decision that a wallet's exposure has been reviewed. Suppressed
+// categories are hidden from policy matching, the level cap bounds the level
+// and score policies see, and exempt actions are dropped from the result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalystOverride {
+    pub id: String,
+    pub wallet_address: String,
+    pub author: String,
+    pub justification: String,
+    pub created_at: chrono::DateTime<Utc>,
+    pub expires_at: chrono::DateTime<Utc>,
+    #[serde(default)]
+    pub suppressed_categories: Vec<RiskCategory>,
+    #[serde(default)]
+    pub level_cap: Option<RiskLevel>,
+    #[serde(default)]
+    pub exempt_actions: Vec<ActionType>,
+}
+
+impl AnalystOverride {
+    pub fn is_active(&self) -> bool {
+        self.expires_at > Utc::now()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct OverrideStore {
+    overrides: HashMap<String, Vec<AnalystOverride>>,
+}
+
+impl OverrideStore {
+    pub fn load(path: &str) -> Result<Self, String> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(format!("Failed to read overrides {}: {}", path, e)),
+        };
+
+        let overrides: Vec<AnalystOverride> = serde_json::from_str(&contents)
+            .map_err(|e| format!("Invalid overrides file {}: {}", path, e))?;
+
+        let mut store = Self::default();
+        for o in overrides {
+            store.add(o);
+        }
+        Ok(store)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let all: Vec<&AnalystOverride> = self.overrides.values().flatten().collect();
+        let contents = serde_json::to_string_pretty(&all)
+            .map_err(|e| format!("Failed to serialize overrides: {}", e))?;
+        std::fs::write(path, contents)
+            .map_err(|e| format!("Failed to write overrides {}: {}", path, e))
+    }
+
+    pub fn add(&mut self, o: AnalystOverride) {
+        self.overrides.entry(o.wallet_address.clone()).or_default().push(o);
+    }
+
+    pub fn remove(&mut self, override_id: &str) -> Option<AnalystOverride> {
+        for list in self.overrides.values_mut() {
+            if let Some(index) = list.iter().position(|o| o.id == override_id) {
+                return Some(list.remove(index));
+            }
+        }
+        None
+    }
+
+    pub fn active_for(&self, wallet_address: &str) -> Vec<AnalystOverride> {
+        self.overrides.get(wallet_address)
+            .map(|list| list.iter().filter(|o| o.is_active()).cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn purge_expired(&mut self) -> usize {
+        let before: usize = self.overrides.values().map(|l| l.len()).sum();
+        for list in self.overrides.values_mut() {
+            list.retain(|o| o.is_active());
+        }
+        self.overrides.retain(|_, list| !list.is_empty());
+        before - self.overrides.values().map(|l| l.len()).sum::<usize>()
+    }
+}
@@ -0,0 +1,275 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::config::ProviderBudgetConfig;
+use crate::risk_engine::providers::ProviderError;
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use tracing::{info, warn};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderBudget {
+    pub daily_calls: Option<u64>,
+    pub monthly_calls: Option<u64>,
+    #[serde(default)]
+    pub cost_per_call: f64,
+    #[serde(default)]
+    pub degraded_mode: DegradedMode,
+}
+
+// What a provider may still do once its budget is spent
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum DegradedMode {
+    // No live calls; cached responses are still served
+    #[default]
+    CacheOnly,
+    // Live calls continue only for wallets last rated at or above `min_level`
+    ReducedSubset { min_level: RiskLevel },
+}
+
+// Call counters, persisted so a restart doesn't reset the budget
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct UsageCounters {
+    day: Option<NaiveDate>,
+    month: Option<String>,
+    daily: HashMap<String, u64>,
+    monthly: HashMap<String, u64>,
+    // Month-to-date calls per wallet / tenant, then per provider
+    wallets: HashMap<String, HashMap<String, u64>>,
+    tenants: HashMap<String, HashMap<String, u64>>,
+}
+
+#[derive(Debug, Default)]
+struct TrackerState {
+    counters: UsageCounters,
+    wallet_tenants: HashMap<String, String>,
+    wallet_levels: HashMap<String, RiskLevel>,
+    // Linked off-chain address -> monitored wallet it is screened for
+    address_owners: HashMap<String, String>,
+    degraded: HashSet<String>,
+}
+
+impl TrackerState {
+    fn owner<'a>(&'a self, address: &'a str) -> &'a str {
+        self.address_owners.get(address).map(String::as_str).unwrap_or(address)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProviderUsage {
+    pub provider: String,
+    pub calls_today: u64,
+    pub calls_this_month: u64,
+    pub daily_budget: Option<u64>,
+    pub monthly_budget: Option<u64>,
+    pub estimated_cost: f64,
+    pub degraded: bool,
+}
+
+// Month-to-date usage for one wallet or tenant
+#[derive(Debug, Clone, Serialize)]
+pub struct UsageReport {
+    pub key: String,
+    pub month: String,
+    pub calls: HashMap<String, u64>,
+    pub total_calls: u64,
+    pub estimated_cost: f64,
+}
+
+// Counts billable provider calls against daily and monthly budgets
+#[derive(Debug)]
+pub struct UsageTracker {
+    budgets: HashMap<String, ProviderBudget>,
+    usage_path: Option<String>,
+    state: Mutex<TrackerState>,
+}
+
+impl UsageTracker {
+    pub fn load(config: &ProviderBudgetConfig) -> Result<Self, String> {
+        let budgets = match &config.budgets_path {
+            Some(path) => {
+                let contents = std::fs::read_to_string(path)
+                    .map_err(|e| format!("Failed to read provider budgets {}: {}", path, e))?;
+                serde_json::from_str(&contents)
+                    .map_err(|e| format!("Invalid provider budgets {}: {}", path, e))?
+            }
+            None => HashMap::new(),
+        };
+
+        let counters = match &config.usage_path {
+            Some(path) => match std::fs::read_to_string(path) {
+                Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+                    warn!("Ignoring unreadable provider usage {}: {}", path, e);
+                    UsageCounters::default()
+                }),
+                Err(_) => UsageCounters::default(),
+            },
+            None => UsageCounters::default(),
+        };
+
+        Ok(Self {
+            budgets,
+            usage_path: config.usage_path.clone(),
+            state: Mutex::new(TrackerState { counters, ..Default::default() }),
+        })
+    }
+
+    // Reserves `calls` live requests to `provider` for the address, or refuses
+    // them once the budget is spent. Checking and counting happen under one
+    // lock, so concurrent fetches can't overrun the budget. Linked addresses
+    // are judged and billed as their wallet.
+    pub fn reserve(&self, provider: &str, address: &str, calls: u64) -> Result<(), ProviderError> {
+        let mut state = self.state.lock().unwrap();
+        Self::roll_over(&mut state.counters);
+
+        if let Some(budget) = self.budgets.get(provider) {
+            let daily = state.counters.daily.get(provider).copied().unwrap_or(0);
+            let monthly = state.counters.monthly.get(provider).copied().unwrap_or(0);
+            let exhausted = budget.daily_calls.map(|limit| daily + calls > limit).unwrap_or(false)
+                || budget.monthly_calls.map(|limit| monthly + calls > limit).unwrap_or(false);
+
+            if !exhausted {
+                if state.degraded.remove(provider) {
+                    info!("Budget for provider {} available again, leaving degraded mode", provider);
+                }
+            } else {
+                if state.degraded.insert(provider.to_string()) {
+                    warn!(
+                        "Budget for provider {} exhausted ({} today, {} this month), degraded mode {:?}",
+                        provider, daily, monthly, budget.degraded_mode
+                    );
+                }
+
+                let allowed = match &budget.degraded_mode {
+                    DegradedMode::ReducedSubset { min_level } => state.wallet_levels.get(state.owner(address))
+                        .map(|level| level.rank() >= min_level.rank())
+                        .unwrap_or(false),
+                    DegradedMode::CacheOnly => false,
+                };
+                if !allowed {
+                    return Err(ProviderError::BudgetExhausted(provider.to_string()));
+                }
+            }
+        }
+
+        let wallet_address = state.owner(address).to_string();
+        let tenant = state.wallet_tenants.get(&wallet_address).cloned();
+        let counters = &mut state.counters;
+        *counters.daily.entry(provider.to_string()).or_insert(0) += calls;
+        *counters.monthly.entry(provider.to_string()).or_insert(0) += calls;
+        *counters.wallets.entry(wallet_address).or_default()
+            .entry(provider.to_string()).or_insert(0) += calls;
+        if let Some(tenant) = tenant {
+            *counters.tenants.entry(tenant).or_default()
+                .entry(provider.to_string()).or_insert(0) += calls;
+        }
+        Ok(())
+    }
+
+    pub fn set_tenant(&self, wallet_address: &str, tenant: &str) {
+        self.state.lock().unwrap().wallet_tenants.insert(wallet_address.to_string(), tenant.to_string());
+    }
+
+    pub fn link_address(&self, address: &str, wallet_address: &str) {
+        self.state.lock().unwrap().address_owners.insert(address.to_string(), wallet_address.to_string());
+    }
+
+    // Latest level decides membership of the reduced subset
+    pub fn record_level(&self, wallet_address: &str, level: &RiskLevel) {
+        self.state.lock().unwrap().wallet_levels.insert(wallet_address.to_string(), level.clone());
+    }
+
+    // Usage already counted stays in the reports
+    pub fn forget(&self, wallet_address: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.wallet_tenants.remove(wallet_address);
+        state.wallet_levels.remove(wallet_address);
+        state.address_owners.retain(|_, owner| owner != wallet_address);
+    }
+
+    pub fn provider_usage(&self) -> Vec<ProviderUsage> {
+        let mut state = self.state.lock().unwrap();
+        Self::roll_over(&mut state.counters);
+
+        let mut providers: Vec<&String> = state.counters.monthly.keys()
+            .chain(self.budgets.keys())
+            .collect();
+        providers.sort();
+        providers.dedup();
+
+        providers.into_iter()
+            .map(|provider| {
+                let budget = self.budgets.get(provider);
+                let calls_this_month = state.counters.monthly.get(provider).copied().unwrap_or(0);
+                ProviderUsage {
+                    provider: provider.clone(),
+                    calls_today: state.counters.daily.get(provider).copied().unwrap_or(0),
+                    calls_this_month,
+                    daily_budget: budget.and_then(|b| b.daily_calls),
+                    monthly_budget: budget.and_then(|b| b.monthly_calls),
+                    estimated_cost: calls_this_month as f64 * budget.map(|b| b.cost_per_call).unwrap_or(0.0),
+                    degraded: state.degraded.contains(provider),
+                }
+            })
+            .collect()
+    }
+
+    pub fn wallet_usage(&self) -> Vec<UsageReport> {
+        let mut state = self.state.lock().unwrap();
+        Self::roll_over(&mut state.counters);
+        self.reports(&state.counters, &state.counters.wallets)
+    }
+
+    pub fn tenant_usage(&self) -> Vec<UsageReport> {
+        let mut state = self.state.lock().unwrap();
+        Self::roll_over(&mut state.counters);
+        self.reports(&state.counters, &state.counters.tenants)
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let Some(path) = &self.usage_path else {
+            return Ok(());
+        };
+
+        let contents = serde_json::to_string(&self.state.lock().unwrap().counters)
+            .map_err(|e| format!("Failed to serialize provider usage: {}", e))?;
+        std::fs::write(path, contents)
+            .map_err(|e| format!("Failed to write provider usage {}: {}", path, e))
+    }
+
+    fn reports(&self, counters: &UsageCounters, by_key: &HashMap<String, HashMap<String, u64>>) -> Vec<UsageReport> {
+        let month = counters.month.clone().unwrap_or_default();
+        let mut reports: Vec<UsageReport> = by_key.iter()
+            .map(|(key, calls)| UsageReport {
+                key: key.clone(),
+                month: month.clone(),
+                total_calls: calls.values().sum(),
+                estimated_cost: calls.iter()
+                    .map(|(provider, n)| *n as f64 * self.budgets.get(provider).map(|b| b.cost_per_call).unwrap_or(0.0))
+                    .sum(),
+                calls: calls.clone(),
+            })
+            .collect();
+        reports.sort_by(|a, b| b.total_calls.cmp(&a.total_calls).then_with(|| a.key.cmp(&b.key)));
+        reports
+    }
+
+    // Counters reset at UTC day and month boundaries
+    fn roll_over(counters: &mut UsageCounters) {
+        let now = Utc::now();
+        let today = now.date_naive();
+        let month = now.format("%Y-%m").to_string();
+
+        if counters.day != Some(today) {
+            counters.day = Some(today);
+            counters.daily.clear();
+        }
+        if counters.month.as_deref() != Some(month.as_str()) {
+            counters.month = Some(month);
+            counters.monthly.clear();
+            counters.wallets.clear();
+            counters.tenants.clear();
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::config::{CacheBackend, ProviderCacheConfig};
+use crate::risk_engine::providers::{ProviderError, ProviderHealth, ProviderReport, RiskDataProvider};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tracing::{debug, warn};
+
+#[derive(Error, Debug)]
+pub enum CacheError {
+    #[error("Cache storage error: {0}")]
+    Storage(String),
+    #[error("Cache entry could not be encoded: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl From<rusqlite::Error> for CacheError {
+    fn from(e: rusqlite::Error) -> Self {
+        CacheError::Storage(e.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedResponse {
+    pub provider: String,
+    pub wallet_address: String,
+    // When the provider produced the data; used as evidence time
+    pub responded_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    // The provider had nothing on this wallet
+    pub negative: bool,
+    pub report: ProviderReport,
+}
+
+#[async_trait]
+pub trait ProviderCacheStore: Send + Sync + Debug {
+    // Unexpired entry for the provider and wallet
+    async fn get(&self, provider: &str, wallet_address: &str) -> Result<Option<CachedResponse>, CacheError>;
+
+    async fn put(&self, entry: &CachedResponse) -> Result<(), CacheError>;
+
+    // Drops every provider's entry for the wallet
+    async fn invalidate(&self, wallet_address: &str) -> Result<(), CacheError>;
+
+    async fn purge_expired(&self) -> Result<usize, CacheError>;
+}
+
+// Per-process store; entries are lost on restart
+#[derive(Debug, Default)]
+pub struct MemoryCacheStore {
+    entries: RwLock<HashMap<(String, String), CachedResponse>>,
+}
+
+impl MemoryCacheStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ProviderCacheStore for MemoryCacheStore {
+    async fn get(&self, provider: &str, wallet_address: &str) -> Result<Option<CachedResponse>, CacheError> {
+        let entries = self.entries.read().await;
+        Ok(entries.get(&(provider.to_string(), wallet_address.to_string()))
+            .filter(|entry| entry.expires_at > Utc::now())
+            .cloned())
+    }
+
+    async fn put(&self, entry: &CachedResponse) -> Result<(), CacheError> {
+        self.entries.write().await.insert(
+            (entry.provider.clone(), entry.wallet_address.clone()),
+            entry.clone(),
+        );
+        Ok(())
+    }
+
+    async fn invalidate(&self, wallet_address: &str) -> Result<(), CacheError> {
+        self.entries.write().await.retain(|(_, wallet), _| wallet != wallet_address);
+        Ok(())
+    }
+
+    async fn purge_expired(&self) -> Result<usize, CacheError> {
+        let now = Utc::now();
+        let mut entries = self.entries.write().await;
+        let before = entries.len();
+        entries.retain(|_, entry| entry.expires_at > now);
+        Ok(before - entries.len())
+    }
+}
+
+// SQLite file shared by every process on the host (engine, API servers)
+#[derive(Debug)]
+pub struct EmbeddedCacheStore {
+    conn: Mutex<Connection>,
+}
+
+impl EmbeddedCacheStore {
+    pub fn open(path: &str) -> Result<Self, CacheError> {
+        let conn = Connection::open(path)?;
+
+        // WAL lets other processes read while one writes
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        conn.busy_timeout(std::time::Duration::from_secs(5))?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS provider_responses (
+                provider TEXT NOT NULL,
+                wallet_address TEXT NOT NULL,
+                expires_at INTEGER NOT NULL,
+                entry TEXT NOT NULL,
+                PRIMARY KEY (provider, wallet_address)
+            );
+            CREATE INDEX IF NOT EXISTS provider_responses_wallet
+                ON provider_responses (wallet_address);"
+        )?;
+
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+}
+
+#[async_trait]
+impl ProviderCacheStore for EmbeddedCacheStore {
+    async fn get(&self, provider: &str, wallet_address: &str) -> Result<Option<CachedResponse>, CacheError> {
+        let entry: Option<String> = self.conn.lock().unwrap()
+            .query_row(
+                "SELECT entry FROM provider_responses
+                 WHERE provider = ?1 AND wallet_address = ?2 AND expires_at > ?3",
+                params![provider, wallet_address, Utc::now().timestamp()],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        entry.map(|json| serde_json::from_str(&json).map_err(CacheError::from)).transpose()
+    }
+
+    async fn put(&self, entry: &CachedResponse) -> Result<(), CacheError> {
+        let json = serde_json::to_string(entry)?;
+        self.conn.lock().unwrap().execute(
+            "INSERT OR REPLACE INTO provider_responses (provider, wallet_address, expires_at, entry)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![entry.provider, entry.wallet_address, entry.expires_at.timestamp(), json],
+        )?;
+        Ok(())
+    }
+
+    async fn invalidate(&self, wallet_address: &str) -> Result<(), CacheError> {
+        self.conn.lock().unwrap().execute(
+            "DELETE FROM provider_responses WHERE wallet_address = ?1",
+            params![wallet_address],
+        )?;
+        Ok(())
+    }
+
+    async fn purge_expired(&self) -> Result<usize, CacheError> {
+        let purged = self.conn.lock().unwrap().execute(
+            "DELETE FROM provider_responses WHERE expires_at <= ?1",
+            params![Utc::now().timestamp()],
+        )?;
+        Ok(purged)
+    }
+}
+
+// Opens the configured backend; None when caching is disabled
+pub fn open_cache_store(config: &ProviderCacheConfig) -> Result<Option<Arc<dyn ProviderCacheStore>>, CacheError> {
+    let store: Arc<dyn ProviderCacheStore> = match config.backend {
+        CacheBackend::Disabled => return Ok(None),
+        CacheBackend::Memory => Arc::new(MemoryCacheStore::new()),
+        CacheBackend::Embedded => Arc::new(EmbeddedCacheStore::open(&config.path)?),
+    };
+    Ok(Some(store))
+}
+
+// Serves a provider's recent answer for a wallet instead of paying for the call again
+#[derive(Debug)]
+pub struct CachedProvider {
+    inner: Arc<dyn RiskDataProvider>,
+    store: Arc<dyn ProviderCacheStore>,
+    ttl: Duration,
+    negative_ttl: Duration,
+}
+
+impl CachedProvider {
+    pub fn new(
+        inner: Arc<dyn RiskDataProvider>,
+        store: Arc<dyn ProviderCacheStore>,
+        ttl_secs: u64,
+        negative_ttl_secs: u64,
+    ) -> Self {
+        Self {
+            inner,
+            store,
+            ttl: Duration::seconds(ttl_secs as i64),
+            negative_ttl: Duration::seconds(negative_ttl_secs as i64),
+        }
+    }
+}
+
+#[async_trait]
+impl RiskDataProvider for CachedProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn source(&self) -> DataSource {
+        self.inner.source()
+    }
+
+    fn coverage(&self) -> Vec<RiskCategory> {
+        self.inner.coverage()
+    }
+
+    fn billable(&self) -> bool {
+        self.inner.billable()
+    }
+
+    fn chains(&self) -> Vec<Chain> {
+        self.inner.chains()
+    }
+
+    fn requests_per_fetch(&self) -> u64 {
+        self.inner.requests_per_fetch()
+    }
+
+    async fn fetch(&self, address: &ChainAddress) -> Result<ProviderReport, ProviderError> {
+        match self.store.get(self.inner.name(), address.address()).await {
+            Ok(Some(entry)) => {
+                debug!(
+                    "Using cached {} response for {} from {}",
+                    self.inner.name(),
+                    address,
+                    entry.responded_at
+                );
+                return Ok(entry.report);
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Provider cache read failed for {}: {}", self.inner.name(), e),
+        }
+
+        let report = self.inner.fetch(address).await?;
+
+        // Age is counted from the provider's response, not from when it was stored
+        let negative = report.is_empty();
+        let responded_at = report.responded_at.min(Utc::now());
+        let entry = CachedResponse {
+            provider: self.inner.name().to_string(),
+            wallet_address: address.address().to_string(),
+            responded_at,
+            expires_at: responded_at + if negative { self.negative_ttl } else { self.ttl },
+            negative,
+            report: report.clone(),
+        };
+
+        if let Err(e) = self.store.put(&entry).await {
+            warn!("Provider cache write failed for {}: {}", self.inner.name(), e);
+        }
+
+        Ok(report)
+    }
+
+    fn refresh(&self) {
+        self.inner.refresh()
+    }
+
+    fn link_address(&self, address: &ChainAddress, wallet_address: &str) {
+        self.inner.link_address(address, wallet_address)
+    }
+
+    fn forget_wallet(&self, wallet_address: &str) {
+        self.inner.forget_wallet(wallet_address)
+    }
+
+    fn health(&self) -> Option<ProviderHealth> {
+        self.inner.health()
+    }
+}
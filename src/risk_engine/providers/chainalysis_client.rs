@@ -0,0 +1,474 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::config::ChainalysisConfig;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use thiserror::Error;
+use tokio::time::{timeout, Duration};
+
+const SOLANA_NETWORK: &str = "Solana";
+const SOLANA_ASSET: &str = "SOL";
+
+// KYT network and native asset for each chain
+fn kyt_network_asset(chain: Chain) -> (&'static str, &'static str) {
+    match chain {
+        Chain::Solana => (SOLANA_NETWORK, SOLANA_ASSET),
+        Chain::Ethereum => ("Ethereum", "ETH"),
+        Chain::Tron => ("Tron", "TRX"),
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ChainalysisError {
+    #[error("API request failed: {0}")]
+    ApiError(String),
+    #[error("API returned status {0}: {1}")]
+    StatusError(u16, String),
+    #[error("Network error: {0}")]
+    NetworkError(#[from] reqwest::Error),
+    #[error("Timeout error")]
+    TimeoutError,
+    #[error("Invalid response format: {0}")]
+    ParseError(String),
+    #[error("Invalid configuration: {0}")]
+    ConfigError(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct ChainalysisClient {
+    client: Client,
+    config: Arc<ChainalysisConfig>,
+    // Linked address -> monitored wallet whose KYT user it is registered under
+    kyt_users: Arc<RwLock<HashMap<String, String>>>,
+}
+
+impl ChainalysisClient {
+    pub fn new(config: Arc<ChainalysisConfig>) -> Result<Self, ChainalysisError> {
+        if config.api_key.is_empty() {
+            return Err(ChainalysisError::ConfigError("CHAINALYSIS_API_KEY is empty".to_string()));
+        }
+
+        let client = Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build()
+            .map_err(|e| ChainalysisError::ConfigError(e.to_string()))?;
+
+        Ok(Self { client, config, kyt_users: Arc::new(RwLock::new(HashMap::new())) })
+    }
+
+    pub fn set_kyt_user(&self, address: &str, wallet_address: &str) {
+        self.kyt_users.write().unwrap().insert(address.to_string(), wallet_address.to_string());
+    }
+
+    pub fn forget_kyt_user(&self, wallet_address: &str) {
+        self.kyt_users.write().unwrap().retain(|_, owner| owner != wallet_address);
+    }
+
+    // Monitored wallets are their own KYT user
+    fn kyt_user(&self, address: &str) -> String {
+        self.kyt_users.read().unwrap().get(address).cloned().unwrap_or_else(|| address.to_string())
+    }
+
+    pub fn sanctions_enabled(&self) -> bool {
+        self.config.sanctions_enabled
+    }
+
+    pub fn kyt_enabled(&self) -> bool {
+        self.config.kyt_enabled
+    }
+
+    // Combined view used by the aggregator: sanctions identifications plus the
+    // KYT rating of the address, each only when enabled in config
+    pub async fn get_address_screening(&self, address: &ChainAddress) -> Result<ChainalysisScreening, ChainalysisError> {
+        if !self.config.sanctions_enabled && !self.config.kyt_enabled {
+            return Err(ChainalysisError::ApiError("Chainalysis provider is disabled".to_string()));
+        }
+
+        let mut screening = ChainalysisScreening {
+            address: address.address().to_string(),
+            is_sanctioned: false,
+            sanctions_identifications: Vec::new(),
+            category_scores: Vec::new(),
+        };
+
+        if self.config.sanctions_enabled {
+            let identifications = self.check_sanctions(address.address()).await?;
+            screening.is_sanctioned = !identifications.is_empty();
+            screening.sanctions_identifications = identifications;
+        }
+
+        if self.config.kyt_enabled {
+            // KYT tracks exposure per user; each monitored wallet is its own
+            // user and its linked addresses are registered under it
+            let user_id = self.kyt_user(address.address());
+            let registrations = self.register_address(&user_id, address).await?;
+            screening.category_scores = registrations.iter()
+                .filter_map(ChainalysisCategoryScore::from_registration)
+                .collect();
+        }
+
+        Ok(screening)
+    }
+
+    pub async fn check_sanctions(&self, address: &str) -> Result<Vec<ChainalysisIdentification>, ChainalysisError> {
+        if !self.config.sanctions_enabled {
+            return Err(ChainalysisError::ApiError("Chainalysis sanctions screening is disabled".to_string()));
+        }
+
+        let url = format!("{}/api/v1/address/{}", self.config.sanctions_api_url, address);
+
+        let request = self.client
+            .get(&url)
+            .header("X-API-Key", &self.config.api_key)
+            .header("Accept", "application/json");
+
+        let response = timeout(
+            Duration::from_secs(self.config.timeout_secs),
+            request.send()
+        ).await
+        .map_err(|_| ChainalysisError::TimeoutError)?
+        .map_err(ChainalysisError::NetworkError)?;
+
+        match response.status() {
+            reqwest::StatusCode::OK => {
+                let sanctions: ChainalysisSanctionsResponse = response.json().await
+                    .map_err(|e| ChainalysisError::ParseError(e.to_string()))?;
+                Ok(sanctions.identifications)
+            }
+            status => {
+                let error_text = response.text().await.unwrap_or_default();
+                Err(ChainalysisError::StatusError(status.as_u16(), error_text))
+            }
+        }
+    }
+
+    pub async fn register_address(
+        &self,
+        user_id: &str,
+        address: &ChainAddress,
+    ) -> Result<Vec<ChainalysisAddressRegistration>, ChainalysisError> {
+        if !self.config.kyt_enabled {
+            return Err(ChainalysisError::ApiError("Chainalysis KYT is disabled".to_string()));
+        }
+
+        let url = format!("{}/api/kyt/v1/users/{}/withdrawaladdresses", self.config.api_url, user_id);
+
+        let request = self.client
+            .post(&url)
+            .header("Token", &self.config.api_key)
+            .header("Content-Type", "application/json")
+            .json(&vec![ChainalysisAddressRequest {
+                network: kyt_network_asset(address.chain()).0.to_string(),
+                asset: kyt_network_asset(address.chain()).1.to_string(),
+                address: address.address().to_string(),
+            }]);
+
+        let response = timeout(
+            Duration::from_secs(self.config.timeout_secs),
+            request.send()
+        ).await
+        .map_err(|_| ChainalysisError::TimeoutError)?
+        .map_err(ChainalysisError::NetworkError)?;
+
+        if response.status().is_success() {
+            let registrations: Vec<ChainalysisAddressRegistration> = response.json().await
+                .map_err(|e| ChainalysisError::ParseError(e.to_string()))?;
+            Ok(registrations)
+        } else {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            Err(ChainalysisError::StatusError(status.as_u16(), format!("Address registration failed: {}", error_text)))
+        }
+    }
+
+    pub async fn register_transfer(
+        &self,
+        user_id: &str,
+        transfer: &ChainalysisTransfer,
+    ) -> Result<ChainalysisTransferRegistration, ChainalysisError> {
+        if !self.config.kyt_enabled {
+            return Err(ChainalysisError::ApiError("Chainalysis KYT is disabled".to_string()));
+        }
+
+        let url = format!("{}/api/kyt/v2/users/{}/transfers", self.config.api_url, user_id);
+
+        let request = self.client
+            .post(&url)
+            .header("Token", &self.config.api_key)
+            .header("Content-Type", "application/json")
+            .json(transfer);
+
+        let response = timeout(
+            Duration::from_secs(self.config.timeout_secs),
+            request.send()
+        ).await
+        .map_err(|_| ChainalysisError::TimeoutError)?
+        .map_err(ChainalysisError::NetworkError)?;
+
+        if response.status().is_success() {
+            let registration: ChainalysisTransferRegistration = response.json().await
+                .map_err(|e| ChainalysisError::ParseError(e.to_string()))?;
+            Ok(registration)
+        } else {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            Err(ChainalysisError::StatusError(status.as_u16(), format!("Transfer registration failed: {}", error_text)))
+        }
+    }
+
+    // Alerts that need review; low-risk alerts are informational and dropped
+    pub async fn get_transfer_alerts(&self, external_id: &str) -> Result<Vec<ChainalysisAlert>, ChainalysisError> {
+        if !self.config.kyt_enabled {
+            return Err(ChainalysisError::ApiError("Chainalysis KYT is disabled".to_string()));
+        }
+
+        let url = format!("{}/api/kyt/v2/transfers/{}/alerts", self.config.api_url, external_id);
+
+        let request = self.client
+            .get(&url)
+            .header("Token", &self.config.api_key);
+
+        let response = timeout(
+            Duration::from_secs(self.config.timeout_secs),
+            request.send()
+        ).await
+        .map_err(|_| ChainalysisError::TimeoutError)?
+        .map_err(ChainalysisError::NetworkError)?;
+
+        match response.status() {
+            reqwest::StatusCode::OK => {
+                let alerts: ChainalysisAlertsResponse = response.json().await
+                    .map_err(|e| ChainalysisError::ParseError(e.to_string()))?;
+                Ok(alerts.alerts.into_iter().filter(|alert| !alert.is_low_risk()).collect())
+            }
+            // Transfers are processed asynchronously; no alerts yet
+            reqwest::StatusCode::NOT_FOUND => Ok(Vec::new()),
+            status => {
+                let error_text = response.text().await.unwrap_or_default();
+                Err(ChainalysisError::StatusError(status.as_u16(), error_text))
+            }
+        }
+    }
+}
+
+// Chainalysis API Types
+#[derive(Debug, Deserialize)]
+struct ChainalysisSanctionsResponse {
+    #[serde(default)]
+    identifications: Vec<ChainalysisIdentification>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainalysisIdentification {
+    pub category: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ChainalysisAddressRequest {
+    network: String,
+    asset: String,
+    address: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainalysisAddressRegistration {
+    pub asset: String,
+    pub address: String,
+    pub cluster: Option<ChainalysisCluster>,
+    pub rating: String,
+    pub custom_address: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainalysisCluster {
+    pub name: Option<String>,
+    pub category: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransferDirection {
+    Received,
+    Sent,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainalysisTransfer {
+    pub network: String,
+    pub asset: String,
+    pub transfer_reference: String,
+    pub direction: TransferDirection,
+}
+
+impl ChainalysisTransfer {
+    // Transfer reference for account-based chains is "<tx hash>:<counterparty address>"
+    pub fn solana(tx_signature: &str, counterparty: &str, direction: TransferDirection) -> Self {
+        Self {
+            network: SOLANA_NETWORK.to_string(),
+            asset: SOLANA_ASSET.to_string(),
+            transfer_reference: format!("{}:{}", tx_signature, counterparty),
+            direction,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainalysisTransferRegistration {
+    pub updated_at: Option<String>,
+    pub asset: String,
+    pub network: String,
+    pub transfer_reference: String,
+    pub direction: TransferDirection,
+    pub external_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChainalysisAlertsResponse {
+    #[serde(default)]
+    alerts: Vec<ChainalysisAlert>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainalysisAlert {
+    pub alert_level: String,
+    pub category: Option<String>,
+    pub service: Option<String>,
+    pub external_id: String,
+    pub alert_amount: Option<f64>,
+    pub exposure_type: Option<String>,
+}
+
+impl ChainalysisAlert {
+    fn is_low_risk(&self) -> bool {
+        matches!(self.alert_level.to_ascii_lowercase().as_str(), "low" | "lowrisk")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainalysisScreening {
+    pub address: String,
+    pub is_sanctioned: bool,
+    pub sanctions_identifications: Vec<ChainalysisIdentification>,
+    pub category_scores: Vec<ChainalysisCategoryScore>,
+}
+
+// Conversion from Chainalysis types to our internal types
+impl ChainalysisScreening {
+    pub fn to_indicators(&self, wallet_address: &str) -> Vec<RiskIndicator> {
+        let mut indicators = Vec::new();
+        
+        // Convert category scores to indicators
+        for category_score in &self.category_scores {
+            let category = match category_score.category.as_str() {
+                "sanctions" => RiskCategory::Sanctions,
+                "illicit_activity" => RiskCategory::IllicitActivity,
+                "high_risk_service" => RiskCategory::HighRiskService,
+                _ => RiskCategory::TechnicalRisk,
+            };
+            
+            let indicator = RiskIndicator {
+                id: format!("chainalysis_{}_{}_{}", wallet_address, category_score.address, category_score.category),
+                category,
+                subcategory: category_score.subcategory.clone().unwrap_or_default(),
+                score: category_score.score,
+                confidence: 0.9, // Chainalysis typically has high confidence
+                description: format!("Chainalysis {} risk detected", category_score.category),
+                evidence: vec![Evidence {
+                    source: DataSource::Chainalysis,
+                    raw_data: serde_json::to_value(category_score).unwrap(),
+                    extracted_info: HashMap::from([
+                        ("category".to_string(), category_score.category.clone()),
+                        ("score".to_string(), category_score.score.to_string()),
+                    ]),
+                    timestamp: chrono::Utc::now(),
+                }],
+                first_seen: chrono::Utc::now(),
+                last_seen: chrono::Utc::now(),
+                transaction_hashes: Vec::new(),
+                addresses_involved: Vec::new(),
+                metadata: HashMap::new(),
+            };
+            
+            indicators.push(indicator);
+        }
+        
+        // Add sanctions indicator if applicable
+        if self.is_sanctioned {
+            let sanctions_indicator = RiskIndicator {
+                id: format!("chainalysis_{}_sanctions", wallet_address),
+                category: RiskCategory::Sanctions,
+                subcategory: "OFAC".to_string(),
+                score: 100.0, // Maximum score for sanctions
+                confidence: 1.0,
+                description: "Address appears on sanctions list".to_string(),
+                evidence: vec![Evidence {
+                    source: DataSource::Chainalysis,
+                    raw_data: serde_json::json!({"is_sanctioned": true}),
+                    extracted_info: HashMap::from([
+                        ("sanctioned".to_string(), "true".to_string()),
+                    ]),
+                    timestamp: chrono::Utc::now(),
+                }],
+                first_seen: chrono::Utc::now(),
+                last_seen: chrono::Utc::now(),
+                transaction_hashes: Vec::new(),
+                addresses_involved: Vec::new(),
+                metadata: HashMap::new(),
+            };
+            
+            indicators.push(sanctions_indicator);
+        }
+        
+        indicators
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainalysisCategoryScore {
+    // Registered address the rating is for
+    #[serde(default)]
+    pub address: String,
+    pub category: String,
+    pub subcategory: Option<String>,
+    pub score: f64,
+}
+
+impl ChainalysisCategoryScore {
+    // Only a high-risk rating is exposure; low-risk and unrated addresses
+    // carry no signal and must not add indicators to clean wallets
+    fn from_registration(registration: &ChainalysisAddressRegistration) -> Option<Self> {
+        if registration.rating != "highRisk" {
+            return None;
+        }
+
+        let cluster_category = registration.cluster.as_ref()
+            .map(|c| c.category.to_lowercase())
+            .unwrap_or_else(|| "unidentified".to_string());
+
+        Some(Self {
+            address: registration.address.clone(),
+            category: normalize_category(&cluster_category).to_string(),
+            subcategory: Some(cluster_category),
+            score: 80.0,
+        })
+    }
+}
+
+// Maps Chainalysis cluster categories onto the category names the aggregator understands
+fn normalize_category(category: &str) -> &'static str {
+    match category {
+        "sanctions" | "sanctioned entity" | "sanctioned jurisdiction" => "sanctions",
+        "darknet market" | "ransomware" | "stolen funds" | "scam" | "terrorist financing"
+        | "child abuse material" | "fraud shop" | "illicit actor-org" => "illicit_activity",
+        "mixing" | "gambling" | "high risk exchange" | "p2p exchange" | "no kyc exchange" => "high_risk_service",
+        _ => "other",
+    }
+}
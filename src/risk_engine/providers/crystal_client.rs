@@ -0,0 +1,284 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::config::CrystalConfig;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::time::{timeout, Duration};
+
+#[derive(Error, Debug)]
+pub enum CrystalError {
+    #[error("API request failed: {0}")]
+    ApiError(String),
+    #[error("API returned status {0}: {1}")]
+    StatusError(u16, String),
+    #[error("Network error: {0}")]
+    NetworkError(#[from] reqwest::Error),
+    #[error("Timeout error")]
+    TimeoutError,
+    #[error("Invalid response format: {0}")]
+    ParseError(String),
+    #[error("Invalid configuration: {0}")]
+    ConfigError(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct CrystalClient {
+    client: Client,
+    config: Arc<CrystalConfig>,
+    api_key: String,
+}
+
+impl CrystalClient {
+    pub fn new(config: Arc<CrystalConfig>) -> Result<Self, CrystalError> {
+        let api_key = config.api_key.clone()
+            .filter(|k| !k.is_empty())
+            .ok_or_else(|| CrystalError::ConfigError("CRYSTAL_API_KEY is not set".to_string()))?;
+
+        let client = Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build()
+            .map_err(|e| CrystalError::ConfigError(e.to_string()))?;
+
+        Ok(Self { client, config, api_key })
+    }
+
+    pub fn cross_chain_enabled(&self) -> bool {
+        self.config.enable_cross_chain
+    }
+
+    // Address risk, plus exposure through bridged addresses when cross-chain is enabled
+    pub async fn get_wallet_risk(&self, address: &ChainAddress) -> Result<CrystalWalletRisk, CrystalError> {
+        let address_risk = self.get_address_risk(address).await?;
+
+        let cross_chain = if self.config.enable_cross_chain {
+            self.get_cross_chain_exposure(address).await?
+        } else {
+            Vec::new()
+        };
+
+        Ok(CrystalWalletRisk { address_risk, cross_chain })
+    }
+
+    pub async fn get_address_risk(&self, address: &ChainAddress) -> Result<CrystalAddressRisk, CrystalError> {
+        let url = format!("{}/monitor/one", self.config.api_url);
+
+        let request = self.client
+            .post(&url)
+            .header("X-Auth-Apikey", &self.api_key)
+            .json(&CrystalMonitorRequest {
+                address: address.address().to_string(),
+                currency: crystal_currency(address.chain()).to_string(),
+                direction: "withdrawal".to_string(),
+            });
+
+        let response = timeout(
+            Duration::from_secs(self.config.timeout_secs),
+            request.send()
+        ).await
+        .map_err(|_| CrystalError::TimeoutError)?
+        .map_err(CrystalError::NetworkError)?;
+
+        match response.status() {
+            reqwest::StatusCode::OK => {
+                let body: CrystalResponse<CrystalAddressRisk> = response.json().await
+                    .map_err(|e| CrystalError::ParseError(e.to_string()))?;
+                Ok(body.data)
+            }
+            status => {
+                let error_text = response.text().await.unwrap_or_default();
+                Err(CrystalError::StatusError(status.as_u16(), error_text))
+            }
+        }
+    }
+
+    pub async fn get_cross_chain_exposure(&self, address: &ChainAddress) -> Result<Vec<CrystalCrossChainExposure>, CrystalError> {
+        if !self.config.enable_cross_chain {
+            return Err(CrystalError::ApiError("Crystal cross-chain exposure is disabled".to_string()));
+        }
+
+        let url = format!("{}/cross-chain/exposure", self.config.api_url);
+
+        let request = self.client
+            .get(&url)
+            .header("X-Auth-Apikey", &self.api_key)
+            .query(&[("address", address.address()), ("currency", crystal_currency(address.chain()))]);
+
+        let response = timeout(
+            Duration::from_secs(self.config.timeout_secs),
+            request.send()
+        ).await
+        .map_err(|_| CrystalError::TimeoutError)?
+        .map_err(CrystalError::NetworkError)?;
+
+        match response.status() {
+            reqwest::StatusCode::OK => {
+                let body: CrystalResponse<CrystalCrossChainResponse> = response.json().await
+                    .map_err(|e| CrystalError::ParseError(e.to_string()))?;
+                Ok(body.data.exposures)
+            }
+            reqwest::StatusCode::NOT_FOUND => Ok(Vec::new()),
+            status => {
+                let error_text = response.text().await.unwrap_or_default();
+                Err(CrystalError::StatusError(status.as_u16(), error_text))
+            }
+        }
+    }
+}
+
+fn crystal_currency(chain: Chain) -> &'static str {
+    match chain {
+        Chain::Solana => "sol",
+        Chain::Ethereum => "eth",
+        Chain::Tron => "trx",
+    }
+}
+
+// Crystal API Types
+#[derive(Debug, Serialize)]
+struct CrystalMonitorRequest {
+    address: String,
+    currency: String,
+    direction: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrystalResponse<T> {
+    data: T,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrystalAddressRisk {
+    pub address: String,
+    pub currency: String,
+    // 0.0 - 1.0
+    pub riskscore: Option<f64>,
+    // Share of funds per Crystal signal category, 0.0 - 1.0
+    #[serde(default)]
+    pub signals: HashMap<String, f64>,
+    pub name: Option<String>,
+    pub entity_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrystalCrossChainResponse {
+    #[serde(default)]
+    exposures: Vec<CrystalCrossChainExposure>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrystalCrossChainExposure {
+    pub currency: String,
+    pub address: String,
+    pub riskscore: Option<f64>,
+    #[serde(default)]
+    pub signals: HashMap<String, f64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CrystalWalletRisk {
+    pub address_risk: CrystalAddressRisk,
+    pub cross_chain: Vec<CrystalCrossChainExposure>,
+}
+
+// Exposure below this share of funds is noise rather than a signal
+const MIN_SIGNAL_SHARE: f64 = 0.01;
+
+impl CrystalWalletRisk {
+    pub fn into_indicators(self, wallet_address: &str) -> Vec<RiskIndicator> {
+        let mut indicators = signal_indicators(
+            wallet_address,
+            &self.address_risk.currency,
+            &self.address_risk.address,
+            &self.address_risk.signals,
+            false,
+        );
+
+        for exposure in &self.cross_chain {
+            indicators.extend(signal_indicators(
+                wallet_address,
+                &exposure.currency,
+                &exposure.address,
+                &exposure.signals,
+                true,
+            ));
+        }
+
+        indicators
+    }
+}
+
+fn signal_indicators(
+    wallet_address: &str,
+    currency: &str,
+    address: &str,
+    signals: &HashMap<String, f64>,
+    cross_chain: bool,
+) -> Vec<RiskIndicator> {
+    let now = chrono::Utc::now();
+
+    signals.iter()
+        .filter(|(_, share)| **share >= MIN_SIGNAL_SHARE)
+        .filter_map(|(signal, share)| {
+            let (category, base_score) = map_signal(signal)?;
+
+            // Severity of the category, scaled up with the share of funds exposed
+            let score = (base_score * (0.5 + 0.5 * share.min(1.0))).min(100.0);
+            let subcategory = if cross_chain {
+                format!("cross_chain:{}:{}", currency, signal)
+            } else {
+                signal.clone()
+            };
+
+            Some(RiskIndicator {
+                id: format!("crystal_{}_{}_{}", wallet_address, currency, signal),
+                category,
+                subcategory,
+                score,
+                confidence: 0.8,
+                description: format!(
+                    "Crystal {} exposure of {:.1}% on {} address {}",
+                    signal,
+                    share * 100.0,
+                    currency,
+                    address
+                ),
+                evidence: vec![Evidence {
+                    source: DataSource::Crystal,
+                    raw_data: serde_json::json!({
+                        "currency": currency,
+                        "address": address,
+                        "signal": signal,
+                        "share": share,
+                    }),
+                    extracted_info: HashMap::from([
+                        ("signal".to_string(), signal.clone()),
+                        ("share".to_string(), share.to_string()),
+                        ("currency".to_string(), currency.to_string()),
+                    ]),
+                    timestamp: now,
+                }],
+                first_seen: now,
+                last_seen: now,
+                transaction_hashes: Vec::new(),
+                addresses_involved: if cross_chain { vec![address.to_string()] } else { Vec::new() },
+                metadata: HashMap::new(),
+            })
+        })
+        .collect()
+}
+
+// Crystal signal categories that carry risk; licensed exchanges, miners and similar are ignored
+fn map_signal(signal: &str) -> Option<(RiskCategory, f64)> {
+    let mapped = match signal {
+        "sanctions" | "enforcement_action" | "seized_assets" => (RiskCategory::Sanctions, 95.0),
+        "terrorism_financing" | "child_exploitation" | "ransom" | "stolen_coins"
+        | "dark_market" | "dark_service" | "illegal_service" | "scam" => (RiskCategory::IllicitActivity, 85.0),
+        "mixer" | "gambling" | "exchange_fraudulent" | "exchange_unlicensed"
+        | "p2p_exchange_unlicensed" | "exchange_high_risk" => (RiskCategory::HighRiskService, 60.0),
+        "high_risk" => (RiskCategory::CounterpartyRisk, 50.0),
+        _ => return None,
+    };
+    Some(mapped)
+}
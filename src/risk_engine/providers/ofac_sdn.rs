@@ -0,0 +1,384 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::config::OfacSdnConfig;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::RwLock;
+use std::time::SystemTime;
+use thiserror::Error;
+use tracing::info;
+
+const DIGITAL_CURRENCY_PREFIX: &str = "Digital Currency Address - ";
+
+#[derive(Error, Debug)]
+pub enum SdnError {
+    #[error("Failed to read SDN list {0}: {1}")]
+    IoError(String, std::io::Error),
+    #[error("Invalid SDN list: {0}")]
+    ParseError(String),
+    #[error("Download failed: {0}")]
+    DownloadError(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SdnEntry {
+    pub uid: String,
+    pub name: String,
+    pub sdn_type: String,
+    pub programs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SdnAddress {
+    pub address: String,
+    // OFAC currency code, e.g. XBT, ETH, SOL
+    pub currency: String,
+    pub entry: SdnEntry,
+}
+
+// Identifies the exact list a decision was made against
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SdnListVersion {
+    pub publish_date: Option<String>,
+    pub record_count: Option<u64>,
+    pub address_count: usize,
+    pub sha256: String,
+    pub loaded_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SdnIndex {
+    addresses: HashMap<String, SdnAddress>,
+    version: SdnListVersion,
+}
+
+impl SdnIndex {
+    pub fn load(path: &str) -> Result<Self, SdnError> {
+        let contents = std::fs::read(path).map_err(|e| SdnError::IoError(path.to_string(), e))?;
+        Self::parse(&contents, path.to_lowercase().ends_with(".csv"))
+    }
+
+    pub fn parse(contents: &[u8], is_csv: bool) -> Result<Self, SdnError> {
+        let text = std::str::from_utf8(contents)
+            .map_err(|e| SdnError::ParseError(e.to_string()))?;
+
+        let (addresses, publish_date, record_count) = if is_csv {
+            (parse_csv(text)?, None, None)
+        } else {
+            parse_xml(text)?
+        };
+
+        let mut index = HashMap::new();
+        for address in addresses {
+            index.insert(normalize_address(&address.address), address);
+        }
+
+        let version = SdnListVersion {
+            publish_date,
+            record_count,
+            address_count: index.len(),
+            sha256: hex::encode(Sha256::digest(contents)),
+            loaded_at: chrono::Utc::now(),
+        };
+
+        Ok(Self { addresses: index, version })
+    }
+
+    pub fn lookup(&self, address: &str) -> Option<&SdnAddress> {
+        self.addresses.get(&normalize_address(address))
+    }
+
+    pub fn version(&self) -> &SdnListVersion {
+        &self.version
+    }
+
+    pub fn len(&self) -> usize {
+        self.addresses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty()
+    }
+}
+
+// Hex addresses (EVM) are case-insensitive; base58 and bech32 addresses are kept as-is
+fn normalize_address(address: &str) -> String {
+    let address = address.trim();
+    if address.starts_with("0x") || address.starts_with("0X") {
+        address.to_lowercase()
+    } else {
+        address.to_string()
+    }
+}
+
+// Parses an "XBT 1A1zP1..." id value into (currency, address)
+fn parse_digital_currency(id_type: &str, id_number: &str) -> Option<(String, String)> {
+    let currency = id_type.strip_prefix(DIGITAL_CURRENCY_PREFIX)?.trim();
+    let address = id_number.trim().trim_end_matches('.');
+    if currency.is_empty() || address.is_empty() {
+        return None;
+    }
+    Some((currency.to_string(), address.to_string()))
+}
+
+#[derive(Default)]
+struct XmlEntryState {
+    uid: String,
+    first_name: String,
+    last_name: String,
+    sdn_type: String,
+    programs: Vec<String>,
+    id_type: String,
+    id_number: String,
+    ids: Vec<(String, String)>,
+}
+
+fn parse_xml(text: &str) -> Result<(Vec<SdnAddress>, Option<String>, Option<u64>), SdnError> {
+    let mut reader = Reader::from_str(text);
+    reader.trim_text(true);
+
+    let mut addresses = Vec::new();
+    let mut publish_date = None;
+    let mut record_count = None;
+    let mut path: Vec<String> = Vec::new();
+    let mut entry: Option<XmlEntryState> = None;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+                if name == "sdnEntry" {
+                    entry = Some(XmlEntryState::default());
+                }
+                path.push(name);
+            }
+            Ok(Event::End(e)) => {
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+                path.pop();
+
+                match (name.as_str(), entry.as_mut()) {
+                    ("id", Some(state)) => {
+                        if let Some(id) = parse_digital_currency(&state.id_type, &state.id_number) {
+                            state.ids.push(id);
+                        }
+                        state.id_type.clear();
+                        state.id_number.clear();
+                    }
+                    ("sdnEntry", Some(_)) => {
+                        let state = entry.take().expect("entry in progress");
+                        let name = if state.first_name.is_empty() {
+                            state.last_name
+                        } else {
+                            format!("{} {}", state.first_name, state.last_name)
+                        };
+                        let sdn_entry = SdnEntry {
+                            uid: state.uid,
+                            name,
+                            sdn_type: state.sdn_type,
+                            programs: state.programs,
+                        };
+                        for (currency, address) in state.ids {
+                            addresses.push(SdnAddress { address, currency, entry: sdn_entry.clone() });
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Ok(Event::Text(t)) => {
+                let value = t.unescape()
+                    .map_err(|e| SdnError::ParseError(e.to_string()))?
+                    .to_string();
+                let current = path.last().map(String::as_str).unwrap_or_default();
+                let parent = path.len().checked_sub(2).and_then(|i| path.get(i)).map(String::as_str);
+
+                match (entry.as_mut(), current, parent) {
+                    (None, "Publish_Date", _) => publish_date = Some(value),
+                    (None, "Record_Count", _) => record_count = value.parse().ok(),
+                    (Some(state), "uid", Some("sdnEntry")) => state.uid = value,
+                    (Some(state), "firstName", Some("sdnEntry")) => state.first_name = value,
+                    (Some(state), "lastName", Some("sdnEntry")) => state.last_name = value,
+                    (Some(state), "sdnType", _) => state.sdn_type = value,
+                    (Some(state), "program", _) => state.programs.push(value),
+                    (Some(state), "idType", Some("id")) => state.id_type = value,
+                    (Some(state), "idNumber", Some("id")) => state.id_number = value,
+                    _ => {}
+                }
+            }
+            Ok(Event::Eof) => break,
+            Ok(_) => {}
+            Err(e) => {
+                return Err(SdnError::ParseError(format!(
+                    "at position {}: {}",
+                    reader.buffer_position(),
+                    e
+                )));
+            }
+        }
+    }
+
+    Ok((addresses, publish_date, record_count))
+}
+
+// sdn.csv has no header: ent_num, name, type, program, ..., remarks (column 12).
+// Addresses live in remarks as "Digital Currency Address - XBT 1abc...;"
+fn parse_csv(text: &str) -> Result<Vec<SdnAddress>, SdnError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(text.as_bytes());
+
+    let mut addresses = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(|e| SdnError::ParseError(e.to_string()))?;
+        let field = |i: usize| {
+            record.get(i)
+                .map(str::trim)
+                .filter(|v| *v != "-0-")
+                .unwrap_or_default()
+                .to_string()
+        };
+
+        let remarks = field(11);
+        if !remarks.contains(DIGITAL_CURRENCY_PREFIX) {
+            continue;
+        }
+
+        let entry = SdnEntry {
+            uid: field(0),
+            name: field(1),
+            sdn_type: field(2),
+            programs: field(3).split(']').map(|p| p.trim_matches(|c| c == '[' || c == ' ').to_string())
+                .filter(|p| !p.is_empty())
+                .collect(),
+        };
+
+        for remark in remarks.split(';') {
+            let remark = remark.trim();
+            let Some(rest) = remark.strip_prefix(DIGITAL_CURRENCY_PREFIX) else { continue };
+            let Some((currency, address)) = rest.split_once(' ') else { continue };
+            let id_type = format!("{}{}", DIGITAL_CURRENCY_PREFIX, currency);
+            if let Some((currency, address)) = parse_digital_currency(&id_type, address) {
+                addresses.push(SdnAddress { address, currency, entry: entry.clone() });
+            }
+        }
+    }
+
+    Ok(addresses)
+}
+
+// Local-file sanctions screening that keeps working when paid providers are down
+#[derive(Debug)]
+pub struct OfacSdnProvider {
+    path: String,
+    index: RwLock<SdnIndex>,
+    loaded_mtime: RwLock<Option<SystemTime>>,
+}
+
+impl OfacSdnProvider {
+    pub fn new(config: &OfacSdnConfig) -> Result<Option<Self>, SdnError> {
+        let Some(path) = &config.list_path else {
+            return Ok(None);
+        };
+
+        let index = SdnIndex::load(path)?;
+        info!(
+            "Loaded OFAC SDN list {} ({} addresses, published {})",
+            path,
+            index.len(),
+            index.version().publish_date.as_deref().unwrap_or("unknown")
+        );
+
+        Ok(Some(Self {
+            path: path.clone(),
+            index: RwLock::new(index),
+            loaded_mtime: RwLock::new(file_mtime(path)),
+        }))
+    }
+
+    pub fn version(&self) -> SdnListVersion {
+        self.index.read().unwrap().version().clone()
+    }
+
+    // Picks up a list written by `refresh-sdn` without restarting the engine
+    pub fn reload_if_changed(&self) -> Result<bool, SdnError> {
+        let mtime = file_mtime(&self.path);
+        if mtime.is_some() && mtime == *self.loaded_mtime.read().unwrap() {
+            return Ok(false);
+        }
+
+        let index = SdnIndex::load(&self.path)?;
+        info!("Reloaded OFAC SDN list {} ({} addresses)", self.path, index.len());
+        *self.index.write().unwrap() = index;
+        *self.loaded_mtime.write().unwrap() = mtime;
+        Ok(true)
+    }
+
+    pub fn screen(&self, address: &str) -> (Option<RiskIndicator>, SdnListVersion) {
+        let index = self.index.read().unwrap();
+        let version = index.version().clone();
+
+        let indicator = index.lookup(address).map(|hit| {
+            let now = chrono::Utc::now();
+            RiskIndicator {
+                id: format!("ofac_sdn_{}_{}", hit.entry.uid, address),
+                category: RiskCategory::Sanctions,
+                subcategory: "OFAC SDN".to_string(),
+                score: 100.0,
+                confidence: 1.0,
+                description: format!(
+                    "Address listed on OFAC SDN list under {} ({})",
+                    hit.entry.name,
+                    hit.entry.programs.join(", ")
+                ),
+                evidence: vec![Evidence {
+                    source: DataSource::Internal,
+                    raw_data: serde_json::to_value(hit).unwrap_or_default(),
+                    extracted_info: HashMap::from([
+                        ("sdn_uid".to_string(), hit.entry.uid.clone()),
+                        ("sdn_name".to_string(), hit.entry.name.clone()),
+                        ("currency".to_string(), hit.currency.clone()),
+                        ("list_sha256".to_string(), version.sha256.clone()),
+                        ("list_publish_date".to_string(), version.publish_date.clone().unwrap_or_default()),
+                    ]),
+                    timestamp: now,
+                }],
+                first_seen: now,
+                last_seen: now,
+                transaction_hashes: Vec::new(),
+                addresses_involved: vec![hit.address.clone()],
+                metadata: HashMap::new(),
+            }
+        });
+
+        (indicator, version)
+    }
+}
+
+fn file_mtime(path: &str) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+// Downloads the list, validates that it parses, then atomically replaces `path`
+pub async fn refresh_sdn_list(url: &str, path: &str) -> Result<SdnListVersion, SdnError> {
+    let response = reqwest::get(url).await
+        .map_err(|e| SdnError::DownloadError(e.to_string()))?;
+    if !response.status().is_success() {
+        return Err(SdnError::DownloadError(format!("Status: {}", response.status())));
+    }
+    let body = response.bytes().await
+        .map_err(|e| SdnError::DownloadError(e.to_string()))?;
+
+    let index = SdnIndex::parse(&body, path.to_lowercase().ends_with(".csv"))?;
+    if index.is_empty() {
+        return Err(SdnError::ParseError("downloaded list contains no digital currency addresses".to_string()));
+    }
+
+    let tmp_path = format!("{}.tmp", path);
+    std::fs::write(&tmp_path, &body).map_err(|e| SdnError::IoError(tmp_path.clone(), e))?;
+    std::fs::rename(&tmp_path, Path::new(path)).map_err(|e| SdnError::IoError(path.to_string(), e))?;
+
+    Ok(index.version().clone())
+}
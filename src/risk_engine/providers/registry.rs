@@ -0,0 +1,382 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::config::RiskEngineConfig;
+use crate::risk_engine::providers::{
+    TrmClient, TrmError, ChainalysisClient, ChainalysisError, CrystalClient, CrystalError, OfacSdnProvider,
+    ProviderHealth, ResilientProvider, CachedProvider, ProviderCacheStore, UsageTracker,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+use thiserror::Error;
+use tracing::{info, warn, error};
+
+#[derive(Error, Debug)]
+pub enum ProviderError {
+    #[error("{0}")]
+    Request(String),
+    #[error("Provider returned status {0}: {1}")]
+    Status(u16, String),
+    #[error("Network error: {0}")]
+    Network(String),
+    #[error("Timeout error")]
+    Timeout,
+    #[error("Invalid response format: {0}")]
+    Parse(String),
+    #[error("Circuit open until {0}")]
+    CircuitOpen(chrono::DateTime<chrono::Utc>),
+    #[error("Call budget for {0} exhausted")]
+    BudgetExhausted(String),
+}
+
+impl ProviderError {
+    // Worth retrying: the same request may succeed a moment later
+    pub fn is_transient(&self) -> bool {
+        match self {
+            ProviderError::Timeout | ProviderError::Network(_) => true,
+            ProviderError::Status(code, _) => *code == 429 || *code >= 500,
+            _ => false,
+        }
+    }
+}
+
+impl From<TrmError> for ProviderError {
+    fn from(e: TrmError) -> Self {
+        match e {
+            TrmError::TimeoutError => ProviderError::Timeout,
+            TrmError::ParseError(msg) => ProviderError::Parse(msg),
+            TrmError::StatusError(code, msg) => ProviderError::Status(code, msg),
+            TrmError::NetworkError(e) if e.is_timeout() => ProviderError::Timeout,
+            TrmError::NetworkError(e) => ProviderError::Network(e.to_string()),
+            other => ProviderError::Request(other.to_string()),
+        }
+    }
+}
+
+impl From<ChainalysisError> for ProviderError {
+    fn from(e: ChainalysisError) -> Self {
+        match e {
+            ChainalysisError::TimeoutError => ProviderError::Timeout,
+            ChainalysisError::ParseError(msg) => ProviderError::Parse(msg),
+            ChainalysisError::StatusError(code, msg) => ProviderError::Status(code, msg),
+            ChainalysisError::NetworkError(e) if e.is_timeout() => ProviderError::Timeout,
+            ChainalysisError::NetworkError(e) => ProviderError::Network(e.to_string()),
+            other => ProviderError::Request(other.to_string()),
+        }
+    }
+}
+
+impl From<CrystalError> for ProviderError {
+    fn from(e: CrystalError) -> Self {
+        match e {
+            CrystalError::TimeoutError => ProviderError::Timeout,
+            CrystalError::ParseError(msg) => ProviderError::Parse(msg),
+            CrystalError::StatusError(code, msg) => ProviderError::Status(code, msg),
+            CrystalError::NetworkError(e) if e.is_timeout() => ProviderError::Timeout,
+            CrystalError::NetworkError(e) => ProviderError::Network(e.to_string()),
+            other => ProviderError::Request(other.to_string()),
+        }
+    }
+}
+
+// Normalized output of a single provider for one wallet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderReport {
+    pub indicators: Vec<RiskIndicator>,
+    // Merged into the profile metadata, e.g. the sanctions list version used
+    pub metadata: HashMap<String, serde_json::Value>,
+    pub entity: Option<EntityAttribution>,
+    // Provider's own response time when it reports one
+    pub responded_at: DateTime<Utc>,
+}
+
+impl ProviderReport {
+    // Nothing known about the wallet; cached with the negative TTL
+    pub fn is_empty(&self) -> bool {
+        self.indicators.is_empty() && self.entity.is_none()
+    }
+
+    // Evidence is only as recent as the response it came from, which matters
+    // for time decay when the response was served from cache
+    pub fn apply_response_time(&mut self) {
+        for indicator in &mut self.indicators {
+            for evidence in &mut indicator.evidence {
+                evidence.timestamp = evidence.timestamp.min(self.responded_at);
+            }
+            indicator.last_seen = indicator.last_seen.min(self.responded_at);
+            indicator.first_seen = indicator.first_seen.min(indicator.last_seen);
+        }
+    }
+}
+
+impl From<Vec<RiskIndicator>> for ProviderReport {
+    fn from(indicators: Vec<RiskIndicator>) -> Self {
+        Self { indicators, metadata: HashMap::new(), entity: None, responded_at: Utc::now() }
+    }
+}
+
+#[async_trait]
+pub trait RiskDataProvider: Send + Sync + Debug {
+    // Unique, config-facing name ("trm", "chainalysis", ...)
+    fn name(&self) -> &str;
+
+    fn source(&self) -> DataSource;
+
+    // Categories this provider can report on; empty means all of them
+    fn coverage(&self) -> Vec<RiskCategory> {
+        Vec::new()
+    }
+
+    // Whether calls cost money and count against a budget
+    fn billable(&self) -> bool {
+        true
+    }
+
+    // Chains whose addresses the provider can screen
+    fn chains(&self) -> Vec<Chain> {
+        vec![Chain::Solana]
+    }
+
+    // Outbound requests one fetch makes, each metered against the budget
+    fn requests_per_fetch(&self) -> u64 {
+        1
+    }
+
+    async fn fetch(&self, address: &ChainAddress) -> Result<ProviderReport, ProviderError>;
+
+    // Called once per monitoring cycle; local providers use it to pick up new data
+    fn refresh(&self) {}
+
+    // An off-chain address is now screened on behalf of a monitored wallet;
+    // providers that track exposure per customer attribute it to that wallet
+    fn link_address(&self, _address: &ChainAddress, _wallet_address: &str) {}
+
+    fn forget_wallet(&self, _wallet_address: &str) {}
+
+    fn health(&self) -> Option<ProviderHealth> {
+        None
+    }
+}
+
+#[async_trait]
+impl RiskDataProvider for TrmClient {
+    fn name(&self) -> &str {
+        "trm"
+    }
+
+    fn source(&self) -> DataSource {
+        DataSource::TrmLabs
+    }
+
+    fn chains(&self) -> Vec<Chain> {
+        Chain::all()
+    }
+
+    // Address risk, plus the entity lookup when enabled
+    fn requests_per_fetch(&self) -> u64 {
+        1 + self.entity_lookup_enabled() as u64
+    }
+
+    async fn fetch(&self, address: &ChainAddress) -> Result<ProviderReport, ProviderError> {
+        let mut response = self.get_address_risk(address).await?;
+        let cluster = response.entity_cluster.take();
+        let responded_at = DateTime::parse_from_rfc3339(&response.timestamp)
+            .map(|t| t.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+
+        // Entity details are enrichment; a failed lookup keeps the cluster from the risk response
+        let entity = if self.entity_lookup_enabled() {
+            match self.get_entity_info(address.address()).await {
+                Ok(Some(entity)) => Some(entity.attribution(cluster.as_ref())),
+                Ok(None) => cluster.as_ref().map(|c| c.attribution()),
+                Err(e) => {
+                    warn!("TRM entity lookup failed for {}: {}", address, e);
+                    cluster.as_ref().map(|c| c.attribution())
+                }
+            }
+        } else {
+            cluster.as_ref().map(|c| c.attribution())
+        };
+
+        let indicators: Vec<RiskIndicator> = response.into();
+        Ok(ProviderReport { indicators, metadata: HashMap::new(), entity, responded_at })
+    }
+}
+
+#[async_trait]
+impl RiskDataProvider for ChainalysisClient {
+    fn name(&self) -> &str {
+        "chainalysis"
+    }
+
+    fn source(&self) -> DataSource {
+        DataSource::Chainalysis
+    }
+
+    fn chains(&self) -> Vec<Chain> {
+        Chain::all()
+    }
+
+    // Sanctions check and KYT registration, each when enabled
+    fn requests_per_fetch(&self) -> u64 {
+        self.sanctions_enabled() as u64 + self.kyt_enabled() as u64
+    }
+
+    async fn fetch(&self, address: &ChainAddress) -> Result<ProviderReport, ProviderError> {
+        let screening = self.get_address_screening(address).await?;
+        Ok(screening.to_indicators(address.address()).into())
+    }
+
+    fn link_address(&self, address: &ChainAddress, wallet_address: &str) {
+        self.set_kyt_user(address.address(), wallet_address);
+    }
+
+    fn forget_wallet(&self, wallet_address: &str) {
+        self.forget_kyt_user(wallet_address);
+    }
+}
+
+#[async_trait]
+impl RiskDataProvider for CrystalClient {
+    fn name(&self) -> &str {
+        "crystal"
+    }
+
+    fn source(&self) -> DataSource {
+        DataSource::Crystal
+    }
+
+    fn chains(&self) -> Vec<Chain> {
+        Chain::all()
+    }
+
+    // Address risk, plus the cross-chain exposure when enabled
+    fn requests_per_fetch(&self) -> u64 {
+        1 + self.cross_chain_enabled() as u64
+    }
+
+    async fn fetch(&self, address: &ChainAddress) -> Result<ProviderReport, ProviderError> {
+        let risk = self.get_wallet_risk(address).await?;
+        Ok(risk.into_indicators(address.address()).into())
+    }
+}
+
+#[async_trait]
+impl RiskDataProvider for OfacSdnProvider {
+    fn name(&self) -> &str {
+        "ofac_sdn"
+    }
+
+    fn source(&self) -> DataSource {
+        DataSource::Internal
+    }
+
+    fn coverage(&self) -> Vec<RiskCategory> {
+        vec![RiskCategory::Sanctions]
+    }
+
+    fn billable(&self) -> bool {
+        false
+    }
+
+    // SDN digital currency entries cover every chain we screen
+    fn chains(&self) -> Vec<Chain> {
+        Chain::all()
+    }
+
+    async fn fetch(&self, address: &ChainAddress) -> Result<ProviderReport, ProviderError> {
+        let (indicator, version) = self.screen(address.address());
+        Ok(ProviderReport {
+            indicators: indicator.into_iter().collect(),
+            metadata: HashMap::from([(
+                "sanctions_list_version".to_string(),
+                serde_json::to_value(&version).unwrap_or_default(),
+            )]),
+            entity: None,
+            responded_at: Utc::now(),
+        })
+    }
+
+    fn refresh(&self) {
+        if let Err(e) = self.reload_if_changed() {
+            error!("Failed to reload OFAC SDN list, keeping previous version: {}", e);
+        }
+    }
+}
+
+// Builds the providers named in RISK_PROVIDERS. Without that setting every
+// provider whose own config enables it is used. Billable providers are
+// metered by `usage` on every attempt, retries included; with a cache store,
+// providers with a non-zero TTL answer from cache before calling out (and
+// before spending budget).
+pub fn build_providers(
+    config: &RiskEngineConfig,
+    cache: Option<Arc<dyn ProviderCacheStore>>,
+    usage: Arc<UsageTracker>,
+) -> Result<Vec<Arc<dyn RiskDataProvider>>, String> {
+    let names = match &config.providers {
+        Some(names) => names.clone(),
+        None => default_provider_names(config),
+    };
+
+    let mut providers: Vec<Arc<dyn RiskDataProvider>> = Vec::new();
+    for name in names {
+        if providers.iter().any(|p| p.name() == name) {
+            return Err(format!("Provider {} is listed more than once", name));
+        }
+
+        let provider: Arc<dyn RiskDataProvider> = match name.as_str() {
+            "trm" => Arc::new(TrmClient::new(Arc::new(config.trm.clone()))),
+            "chainalysis" => Arc::new(
+                ChainalysisClient::new(Arc::new(config.chainalysis.clone())).map_err(|e| e.to_string())?
+            ),
+            "crystal" => Arc::new(
+                CrystalClient::new(Arc::new(config.crystal.clone())).map_err(|e| e.to_string())?
+            ),
+            "ofac_sdn" => Arc::new(
+                OfacSdnProvider::new(&config.ofac_sdn)
+                    .map_err(|e| e.to_string())?
+                    .ok_or_else(|| "ofac_sdn provider requires OFAC_SDN_PATH".to_string())?
+            ),
+            other => return Err(format!("Unknown risk provider: {}", other)),
+        };
+
+        info!("Risk provider enabled: {}", provider.name());
+        let meter = provider.billable().then(|| usage.clone());
+        let provider: Arc<dyn RiskDataProvider> =
+            Arc::new(ResilientProvider::new(provider, config.provider_resilience.clone(), meter));
+
+        let ttl_secs = config.provider_cache.ttl_for(&name);
+        let provider: Arc<dyn RiskDataProvider> = match &cache {
+            Some(store) if ttl_secs > 0 => Arc::new(CachedProvider::new(
+                provider,
+                store.clone(),
+                ttl_secs,
+                config.provider_cache.negative_ttl_secs,
+            )),
+            _ => provider,
+        };
+        providers.push(provider);
+    }
+
+    Ok(providers)
+}
+
+fn default_provider_names(config: &RiskEngineConfig) -> Vec<String> {
+    let mut names = Vec::new();
+    if config.trm.enabled {
+        names.push("trm".to_string());
+    }
+    if config.chainalysis.kyt_enabled || config.chainalysis.sanctions_enabled {
+        names.push("chainalysis".to_string());
+    }
+    if config.crystal.api_key.is_some() {
+        names.push("crystal".to_string());
+    }
+    if config.ofac_sdn.list_path.is_some() {
+        names.push("ofac_sdn".to_string());
+    }
+    names
+}
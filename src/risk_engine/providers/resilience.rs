@@ -0,0 +1,251 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::config::ProviderResilienceConfig;
+use crate::risk_engine::providers::{ProviderError, ProviderReport, RiskDataProvider, UsageTracker};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use tokio::time::{sleep, Duration};
+use tracing::{info, warn};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    Closed,
+    Open,
+    // Cool-down elapsed; one trial call is let through at a time
+    HalfOpen,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProviderHealth {
+    pub name: String,
+    pub source: DataSource,
+    pub state: CircuitState,
+    pub consecutive_failures: u32,
+    pub total_calls: u64,
+    pub total_failures: u64,
+    pub last_error: Option<String>,
+    pub last_success_at: Option<DateTime<Utc>>,
+    pub last_failure_at: Option<DateTime<Utc>>,
+    pub open_until: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug)]
+struct BreakerState {
+    consecutive_failures: u32,
+    open_until: Option<DateTime<Utc>>,
+    total_calls: u64,
+    total_failures: u64,
+    last_error: Option<String>,
+    last_success_at: Option<DateTime<Utc>>,
+    last_failure_at: Option<DateTime<Utc>>,
+    // A half-open trial is running; other callers are rejected until it resolves
+    probe_in_flight: bool,
+}
+
+// Clears the trial flag however the trial ends, including when the caller
+// drops the future on a timeout
+struct ProbeGuard<'a> {
+    state: &'a Mutex<BreakerState>,
+}
+
+impl Drop for ProbeGuard<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.probe_in_flight = false;
+        }
+    }
+}
+
+// Retries transient failures with jittered exponential backoff and stops
+// calling the inner provider for a cool-down after repeated failures.
+// Billable providers are metered here, per attempt, so retries are paid for
+// and calls refused by the open circuit are not.
+#[derive(Debug)]
+pub struct ResilientProvider {
+    inner: Arc<dyn RiskDataProvider>,
+    config: ProviderResilienceConfig,
+    meter: Option<Arc<UsageTracker>>,
+    state: Mutex<BreakerState>,
+}
+
+impl ResilientProvider {
+    pub fn new(
+        inner: Arc<dyn RiskDataProvider>,
+        config: ProviderResilienceConfig,
+        meter: Option<Arc<UsageTracker>>,
+    ) -> Self {
+        Self {
+            inner,
+            config,
+            meter,
+            state: Mutex::new(BreakerState {
+                consecutive_failures: 0,
+                open_until: None,
+                total_calls: 0,
+                total_failures: 0,
+                last_error: None,
+                last_success_at: None,
+                last_failure_at: None,
+                probe_in_flight: false,
+            }),
+        }
+    }
+
+    fn circuit_state(&self, state: &BreakerState) -> CircuitState {
+        match state.open_until {
+            Some(until) if until > Utc::now() => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+            None => CircuitState::Closed,
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        // Full jitter: uniform in [0, min(max, base * 2^attempt)]
+        let cap = self.config.base_delay_ms
+            .saturating_mul(1u64 << attempt.min(16))
+            .min(self.config.max_delay_ms);
+        Duration::from_millis(rand::thread_rng().gen_range(0..=cap))
+    }
+
+    fn record_success(&self) {
+        let mut state = self.state.lock().unwrap();
+        if state.open_until.is_some() {
+            info!("Circuit for provider {} closed", self.inner.name());
+        }
+        state.consecutive_failures = 0;
+        state.open_until = None;
+        state.last_success_at = Some(Utc::now());
+    }
+
+    fn record_failure(&self, error: &ProviderError) {
+        let mut state = self.state.lock().unwrap();
+        let now = Utc::now();
+        let was_half_open = self.circuit_state(&state) == CircuitState::HalfOpen;
+
+        state.consecutive_failures += 1;
+        state.total_failures += 1;
+        state.last_error = Some(error.to_string());
+        state.last_failure_at = Some(now);
+
+        // A failed trial re-opens immediately
+        if was_half_open || state.consecutive_failures >= self.config.failure_threshold {
+            let until = now + chrono::Duration::seconds(self.config.cool_down_secs as i64);
+            warn!(
+                "Circuit for provider {} opened until {} after {} consecutive failures",
+                self.inner.name(),
+                until,
+                state.consecutive_failures
+            );
+            state.open_until = Some(until);
+        }
+    }
+}
+
+#[async_trait]
+impl RiskDataProvider for ResilientProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn source(&self) -> DataSource {
+        self.inner.source()
+    }
+
+    fn coverage(&self) -> Vec<RiskCategory> {
+        self.inner.coverage()
+    }
+
+    fn billable(&self) -> bool {
+        self.inner.billable()
+    }
+
+    fn chains(&self) -> Vec<Chain> {
+        self.inner.chains()
+    }
+
+    fn requests_per_fetch(&self) -> u64 {
+        self.inner.requests_per_fetch()
+    }
+
+    async fn fetch(&self, address: &ChainAddress) -> Result<ProviderReport, ProviderError> {
+        let _probe = {
+            let mut state = self.state.lock().unwrap();
+            let probe = match (self.circuit_state(&state), state.open_until) {
+                (CircuitState::Open, Some(until)) => return Err(ProviderError::CircuitOpen(until)),
+                (CircuitState::HalfOpen, Some(until)) => {
+                    if state.probe_in_flight {
+                        return Err(ProviderError::CircuitOpen(until));
+                    }
+                    state.probe_in_flight = true;
+                    Some(ProbeGuard { state: &self.state })
+                }
+                _ => None,
+            };
+            state.total_calls += 1;
+            probe
+        };
+
+        let mut attempt = 0;
+        loop {
+            // A spent budget isn't a provider failure, so it doesn't trip the circuit
+            if let Some(meter) = &self.meter {
+                meter.reserve(self.inner.name(), address.address(), self.inner.requests_per_fetch())?;
+            }
+
+            match self.inner.fetch(address).await {
+                Ok(report) => {
+                    self.record_success();
+                    return Ok(report);
+                }
+                Err(e) if e.is_transient() && attempt < self.config.max_retries => {
+                    let delay = self.backoff(attempt);
+                    warn!(
+                        "Provider {} attempt {} failed for {}: {}; retrying in {:?}",
+                        self.inner.name(),
+                        attempt + 1,
+                        address,
+                        e,
+                        delay
+                    );
+                    attempt += 1;
+                    sleep(delay).await;
+                }
+                Err(e) => {
+                    self.record_failure(&e);
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    fn refresh(&self) {
+        self.inner.refresh()
+    }
+
+    fn link_address(&self, address: &ChainAddress, wallet_address: &str) {
+        self.inner.link_address(address, wallet_address)
+    }
+
+    fn forget_wallet(&self, wallet_address: &str) {
+        self.inner.forget_wallet(wallet_address)
+    }
+
+    fn health(&self) -> Option<ProviderHealth> {
+        let state = self.state.lock().unwrap();
+        Some(ProviderHealth {
+            name: self.inner.name().to_string(),
+            source: self.inner.source(),
+            state: self.circuit_state(&state),
+            consecutive_failures: state.consecutive_failures,
+            total_calls: state.total_calls,
+            total_failures: state.total_failures,
+            last_error: state.last_error.clone(),
+            last_success_at: state.last_success_at,
+            last_failure_at: state.last_failure_at,
+            open_until: state.open_until,
+        })
+    }
+}
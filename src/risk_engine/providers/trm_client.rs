@@ -10,6 +10,8 @@ use tokio::time::{timeout, Duration};
 pub enum TrmError {
     #[error("API request failed: {0}")]
     ApiError(String),
+    #[error("API returned status {0}: {1}")]
+    StatusError(u16, String),
     #[error("Network error: {0}")]
     NetworkError(#[from] reqwest::Error),
     #[error("Timeout error")]
@@ -34,7 +36,7 @@ impl TrmClient {
         Self { client, config }
     }
     
-    pub async fn get_address_risk(&self, address: &str) -> Result<TrmRiskResponse, TrmError> {
+    pub async fn get_address_risk(&self, address: &ChainAddress) -> Result<TrmRiskResponse, TrmError> {
         if !self.config.enabled {
             return Err(TrmError::ApiError("TRM provider is disabled".to_string()));
         }
@@ -46,8 +48,8 @@ impl TrmClient {
             .header("Authorization", format!("Bearer {}", self.config.api_key))
             .header("Content-Type", "application/json")
             .json(&TrmAddressRequest {
-                address: address.to_string(),
-                chain: "solana".to_string(),
+                address: address.address().to_string(),
+                chain: address.chain().as_str().to_string(),
             });
         
         let response = timeout(
@@ -65,7 +67,7 @@ impl TrmClient {
             }
             status => {
                 let error_text = response.text().await.unwrap_or_default();
-                Err(TrmError::ApiError(format!("Status: {}, Error: {}", status, error_text)))
+                Err(TrmError::StatusError(status.as_u16(), error_text))
             }
         }
     }
@@ -93,11 +95,16 @@ impl TrmClient {
                 .map_err(|e| TrmError::ParseError(e.to_string()))?;
             Ok(screening)
         } else {
+            let status = response.status();
             let error_text = response.text().await.unwrap_or_default();
-            Err(TrmError::ApiError(format!("Transaction screening failed: {}", error_text)))
+            Err(TrmError::StatusError(status.as_u16(), format!("Transaction screening failed: {}", error_text)))
         }
     }
     
+    pub fn entity_lookup_enabled(&self) -> bool {
+        self.config.entity_lookup
+    }
+    
     pub async fn get_entity_info(&self, address: &str) -> Result<Option<TrmEntity>, TrmError> {
         let url = format!("{}/entities/{}", self.config.api_url, address);
         
@@ -115,8 +122,9 @@ impl TrmClient {
         } else if response.status() == reqwest::StatusCode::NOT_FOUND {
             Ok(None)
         } else {
+            let status = response.status();
             let error_text = response.text().await.unwrap_or_default();
-            Err(TrmError::ApiError(error_text))
+            Err(TrmError::StatusError(status.as_u16(), error_text))
         }
     }
 }
@@ -210,6 +218,38 @@ pub struct TrmEntityAddress {
     pub last_seen: String,
 }
 
+impl TrmEntityCluster {
+    pub fn attribution(&self) -> EntityAttribution {
+        EntityAttribution {
+            entity_id: self.cluster_id.clone(),
+            name: self.name.clone(),
+            entity_type: self.cluster_type.clone(),
+            category: self.category.clone(),
+            risk_score: self.risk_score,
+            addresses: Vec::new(),
+            source: DataSource::TrmLabs,
+        }
+    }
+}
+
+impl TrmEntity {
+    // The cluster id is kept when known so wallets from either endpoint group together
+    pub fn attribution(&self, cluster: Option<&TrmEntityCluster>) -> EntityAttribution {
+        EntityAttribution {
+            entity_id: cluster.map(|c| c.cluster_id.clone()).unwrap_or_else(|| self.entity_id.clone()),
+            name: self.name.clone().or_else(|| cluster.and_then(|c| c.name.clone())),
+            entity_type: self.entity_type.clone(),
+            category: self.category.clone(),
+            risk_score: cluster.map(|c| c.risk_score.max(self.risk_score)).unwrap_or(self.risk_score),
+            addresses: self.addresses.iter()
+                .filter(|a| a.chain == "solana")
+                .map(|a| a.address.clone())
+                .collect(),
+            source: DataSource::TrmLabs,
+        }
+    }
+}
+
 // Conversion from TRM types to our internal types
 impl From<TrmRiskResponse> for Vec<RiskIndicator> {
     fn from(trm_response: TrmRiskResponse) -> Self {
@@ -268,6 +308,8 @@ use tokio::time::{timeout, Duration};
 pub enum TrmError {
     #[error("API request failed: {0}")]
     ApiError(String),
+    #[error("API returned status {0}: {1}")]
+    StatusError(u16, String),
     #[error("Network error: {0}")]
     NetworkError(#[from] reqwest::Error),
     #[error("Timeout error")]
@@ -292,7 +334,7 @@ impl TrmClient {
         Self { client, config }
     }
     
-    pub async fn get_address_risk(&self, address: &str) -> Result<TrmRiskResponse, TrmError> {
+    pub async fn get_address_risk(&self, address: &ChainAddress) -> Result<TrmRiskResponse, TrmError> {
         if !self.config.enabled {
             return Err(TrmError::ApiError("TRM provider is disabled".to_string()));
         }
@@ -304,8 +346,8 @@ impl TrmClient {
             .header("Authorization", format!("Bearer {}", self.config.api_key))
             .header("Content-Type", "application/json")
             .json(&TrmAddressRequest {
-                address: address.to_string(),
-                chain: "solana".to_string(),
+                address: address.address().to_string(),
+                chain: address.chain().as_str().to_string(),
             });
         
         let response = timeout(
@@ -323,7 +365,7 @@ impl TrmClient {
             }
             status => {
                 let error_text = response.text().await.unwrap_or_default();
-                Err(TrmError::ApiError(format!("Status: {}, Error: {}", status, error_text)))
+                Err(TrmError::StatusError(status.as_u16(), error_text))
             }
         }
     }
@@ -351,11 +393,16 @@ impl TrmClient {
                 .map_err(|e| TrmError::ParseError(e.to_string()))?;
             Ok(screening)
         } else {
+            let status = response.status();
             let error_text = response.text().await.unwrap_or_default();
-            Err(TrmError::ApiError(format!("Transaction screening failed: {}", error_text)))
+            Err(TrmError::StatusError(status.as_u16(), format!("Transaction screening failed: {}", error_text)))
         }
     }
     
+    pub fn entity_lookup_enabled(&self) -> bool {
+        self.config.entity_lookup
+    }
+    
     pub async fn get_entity_info(&self, address: &str) -> Result<Option<TrmEntity>, TrmError> {
         let url = format!("{}/entities/{}", self.config.api_url, address);
         
@@ -373,8 +420,9 @@ impl TrmClient {
         } else if response.status() == reqwest::StatusCode::NOT_FOUND {
             Ok(None)
         } else {
+            let status = response.status();
             let error_text = response.text().await.unwrap_or_default();
-            Err(TrmError::ApiError(error_text))
+            Err(TrmError::StatusError(status.as_u16(), error_text))
         }
     }
 }
@@ -468,6 +516,38 @@ pub struct TrmEntityAddress {
     pub last_seen: String,
 }
 
+impl TrmEntityCluster {
+    pub fn attribution(&self) -> EntityAttribution {
+        EntityAttribution {
+            entity_id: self.cluster_id.clone(),
+            name: self.name.clone(),
+            entity_type: self.cluster_type.clone(),
+            category: self.category.clone(),
+            risk_score: self.risk_score,
+            addresses: Vec::new(),
+            source: DataSource::TrmLabs,
+        }
+    }
+}
+
+impl TrmEntity {
+    // The cluster id is kept when known so wallets from either endpoint group together
+    pub fn attribution(&self, cluster: Option<&TrmEntityCluster>) -> EntityAttribution {
+        EntityAttribution {
+            entity_id: cluster.map(|c| c.cluster_id.clone()).unwrap_or_else(|| self.entity_id.clone()),
+            name: self.name.clone().or_else(|| cluster.and_then(|c| c.name.clone())),
+            entity_type: self.entity_type.clone(),
+            category: self.category.clone(),
+            risk_score: cluster.map(|c| c.risk_score.max(self.risk_score)).unwrap_or(self.risk_score),
+            addresses: self.addresses.iter()
+                .filter(|a| a.chain == "solana")
+                .map(|a| a.address.clone())
+                .collect(),
+            source: DataSource::TrmLabs,
+        }
+    }
+}
+
 // Conversion from TRM types to our internal types
 impl From<TrmRiskResponse> for Vec<RiskIndicator> {
     fn from(trm_response: TrmRiskResponse) -> Self {
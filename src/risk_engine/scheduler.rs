@@ -0,0 +1,156 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::config::SchedulingConfig;
+use chrono::{DateTime, Duration, Utc};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ScheduledCheck {
+    due_at: DateTime<Utc>,
+    wallet_address: String,
+}
+
+impl Ord for ScheduledCheck {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.due_at.cmp(&other.due_at)
+            .then_with(|| self.wallet_address.cmp(&other.wallet_address))
+    }
+}
+
+impl PartialOrd for ScheduledCheck {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Min-heap of per-wallet due times. Rescheduling pushes a new entry and
+// records it in `next_due`; superseded heap entries are skipped when popped.
+#[derive(Debug, Clone)]
+pub struct MonitoringScheduler {
+    config: SchedulingConfig,
+    queue: BinaryHeap<Reverse<ScheduledCheck>>,
+    next_due: HashMap<String, DateTime<Utc>>,
+    last_anomaly: HashMap<String, DateTime<Utc>>,
+}
+
+impl MonitoringScheduler {
+    pub fn new(config: SchedulingConfig) -> Self {
+        Self {
+            config,
+            queue: BinaryHeap::new(),
+            next_due: HashMap::new(),
+            last_anomaly: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.next_due.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.next_due.is_empty()
+    }
+
+    pub fn schedule_at(&mut self, wallet_address: &str, due_at: DateTime<Utc>) {
+        self.next_due.insert(wallet_address.to_string(), due_at);
+        self.queue.push(Reverse(ScheduledCheck {
+            due_at,
+            wallet_address: wallet_address.to_string(),
+        }));
+    }
+
+    pub fn schedule_now(&mut self, wallet_address: &str) {
+        self.schedule_at(wallet_address, Utc::now());
+    }
+
+    pub fn remove(&mut self, wallet_address: &str) {
+        self.next_due.remove(wallet_address);
+        self.last_anomaly.remove(wallet_address);
+    }
+
+    pub fn next_due(&self, wallet_address: &str) -> Option<DateTime<Utc>> {
+        self.next_due.get(wallet_address).copied()
+    }
+
+    // Pops up to `limit` wallets whose check is due
+    pub fn take_due(&mut self, now: DateTime<Utc>, limit: usize) -> Vec<String> {
+        let mut due = Vec::new();
+
+        while due.len() < limit {
+            let Some(Reverse(head)) = self.queue.peek() else { break };
+            if head.due_at > now {
+                break;
+            }
+
+            let Reverse(check) = self.queue.pop().expect("peeked entry");
+            if self.next_due.get(&check.wallet_address) == Some(&check.due_at) {
+                // Stays tracked until rescheduled after processing
+                due.push(check.wallet_address);
+            }
+        }
+
+        due
+    }
+
+    // Anomalies pull the next check forward to the anomaly interval
+    pub fn record_anomaly(&mut self, wallet_address: &str) {
+        let now = Utc::now();
+        self.last_anomaly.insert(wallet_address.to_string(), now);
+
+        let soon = now + Duration::minutes(self.config.anomaly_minutes as i64);
+        if let Some(due) = self.next_due.get(wallet_address) {
+            if *due > soon {
+                self.schedule_at(wallet_address, soon);
+            }
+        }
+    }
+
+    pub fn reschedule(
+        &mut self,
+        wallet_address: &str,
+        risk_level: &RiskLevel,
+        attestation_expires_at: Option<i64>,
+    ) -> DateTime<Utc> {
+        let now = Utc::now();
+        let mut interval = self.interval_for_level(risk_level);
+
+        let anomaly_window = Duration::hours(self.config.anomaly_window_hours as i64);
+        if let Some(last) = self.last_anomaly.get(wallet_address) {
+            if now - *last < anomaly_window {
+                interval = interval.min(Duration::minutes(self.config.anomaly_minutes as i64));
+            } else {
+                self.last_anomaly.remove(wallet_address);
+            }
+        }
+
+        // Re-check right after an approaching expiry instead of waiting a full interval
+        if let Some(expires_at) = attestation_expires_at.and_then(|t| DateTime::<Utc>::from_timestamp(t, 0)) {
+            let until_expiry = expires_at - now;
+            let window = Duration::hours(self.config.expiry_window_hours as i64);
+            if until_expiry > Duration::zero() && until_expiry < window {
+                interval = interval.min(until_expiry + Duration::minutes(1));
+            }
+        }
+
+        let due_at = now + interval;
+        self.schedule_at(wallet_address, due_at);
+        due_at
+    }
+
+    pub fn retry_later(&mut self, wallet_address: &str) {
+        let due_at = Utc::now() + Duration::minutes(self.config.retry_minutes as i64);
+        self.schedule_at(wallet_address, due_at);
+    }
+
+    fn interval_for_level(&self, risk_level: &RiskLevel) -> Duration {
+        let minutes = match risk_level {
+            RiskLevel::Critical => self.config.critical_minutes,
+            RiskLevel::High => self.config.high_minutes,
+            RiskLevel::Medium => self.config.medium_minutes,
+            RiskLevel::Low => self.config.low_minutes,
+            RiskLevel::Safe => self.config.safe_minutes,
+            RiskLevel::Unknown => self.config.unknown_minutes,
+        };
+        Duration::minutes(minutes as i64)
+    }
+}
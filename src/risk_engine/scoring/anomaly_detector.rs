@@ -6,6 +6,8 @@ use chrono::{Duration, Utc};
 pub struct BehavioralAnomalyDetector {
     window_size: usize,
     history: HashMap<String, VecDeque<TransactionRecord>>,
+    // Detections kept for scoring, oldest first
+    detections: HashMap<String, VecDeque<AnomalyDetection>>,
     thresholds: AnomalyThresholds,
 }
 
@@ -30,6 +32,7 @@ impl BehavioralAnomalyDetector {
         Self {
             window_size: 100, // Keep last 100 transactions
             history: HashMap::new(),
+            detections: HashMap::new(),
             thresholds: AnomalyThresholds {
                 volume_spike: 3.0,
                 frequency_spike: 5.0,
@@ -76,9 +79,30 @@ impl BehavioralAnomalyDetector {
             anomalies.extend(self.detect_pattern_anomalies(wallet_address, history));
         }
         
+        let detections = self.detections
+            .entry(wallet_address.to_string())
+            .or_insert_with(VecDeque::new);
+        detections.extend(anomalies.iter().cloned());
+        while detections.len() > self.window_size {
+            detections.pop_front();
+        }
+        
         anomalies
     }
     
+    // Detections for the wallet within the last `window`
+    pub fn recent_anomalies(&self, wallet_address: &str, window: Duration) -> Vec<AnomalyDetection> {
+        let since = Utc::now() - window;
+        self.detections.get(wallet_address)
+            .map(|detections| detections.iter().filter(|d| d.timestamp >= since).cloned().collect())
+            .unwrap_or_default()
+    }
+    
+    pub fn forget(&mut self, wallet_address: &str) {
+        self.history.remove(wallet_address);
+        self.detections.remove(wallet_address);
+    }
+    
     fn detect_volume_anomalies(
         &self,
         wallet_address: &str,
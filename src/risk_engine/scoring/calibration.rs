@@ -0,0 +1,285 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::scoring::{AssessmentLabel, WeightedAverageStrategy};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use tracing::info;
+
+const CATEGORIES: [RiskCategory; 8] = [
+    RiskCategory::Sanctions,
+    RiskCategory::IllicitActivity,
+    RiskCategory::HighRiskService,
+    RiskCategory::BehavioralAnomaly,
+    RiskCategory::CounterpartyRisk,
+    RiskCategory::ReputationRisk,
+    RiskCategory::TechnicalRisk,
+    RiskCategory::GeographicRisk,
+];
+
+const MIN_TRAINING_LABELS: usize = 20;
+// Sources with fewer labelled indicators keep a multiplier of 1.0
+const MIN_SOURCE_LABELS: usize = 5;
+const MIN_CATEGORY_WEIGHT: f64 = 0.1;
+
+const ITERATIONS: usize = 2000;
+const LEARNING_RATE: f64 = 0.5;
+const L2_PENALTY: f64 = 0.01;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CalibrationMetrics {
+    pub samples: usize,
+    pub true_positives: usize,
+    pub false_positives: usize,
+    pub false_negatives: usize,
+    pub precision: f64,
+    pub recall: f64,
+}
+
+impl CalibrationMetrics {
+    // A case counts as flagged when the strategy scores it at or above the threshold
+    fn measure(labels: &[&AssessmentLabel], strategy: &WeightedAverageStrategy, threshold: f64) -> Self {
+        let mut metrics = Self {
+            samples: labels.len(),
+            ..Self::default()
+        };
+
+        for label in labels {
+            let score = strategy.combine(label.indicators.iter().map(|i| {
+                (&i.category, i.source.as_ref(), i.score, i.confidence)
+            }));
+            match (score >= threshold, label.outcome.is_positive()) {
+                (true, true) => metrics.true_positives += 1,
+                (true, false) => metrics.false_positives += 1,
+                (false, true) => metrics.false_negatives += 1,
+                (false, false) => {}
+            }
+        }
+
+        metrics.precision = ratio(metrics.true_positives, metrics.true_positives + metrics.false_positives);
+        metrics.recall = ratio(metrics.true_positives, metrics.true_positives + metrics.false_negatives);
+        metrics
+    }
+}
+
+// Weights file written by `calibrate-weights` and loaded from SCORING_WEIGHTS_PATH.
+// Categories and sources it doesn't list keep their built-in weight.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoringWeights {
+    pub trained_at: DateTime<Utc>,
+    pub training_labels: usize,
+    // Score at which a holdout case counted as flagged
+    pub threshold: f64,
+    pub category_weights: HashMap<RiskCategory, f64>,
+    #[serde(default)]
+    pub confidence_multipliers: HashMap<DataSource, f64>,
+    // Holdout results with the built-in weights and with these
+    #[serde(default)]
+    pub before: CalibrationMetrics,
+    #[serde(default)]
+    pub after: CalibrationMetrics,
+}
+
+impl ScoringWeights {
+    pub fn load(path: &str) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read scoring weights {}: {}", path, e))?;
+        let weights: Self = serde_json::from_str(&contents)
+            .map_err(|e| format!("Invalid scoring weights {}: {}", path, e))?;
+
+        let invalid = weights.category_weights.values()
+            .chain(weights.confidence_multipliers.values())
+            .any(|w| !w.is_finite() || *w <= 0.0);
+        if invalid {
+            return Err(format!("Scoring weights {} must all be positive", path));
+        }
+
+        info!(
+            "Loaded scoring weights from {} trained {} on {} labels",
+            path, weights.trained_at, weights.training_labels
+        );
+        Ok(weights)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let contents = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize scoring weights: {}", e))?;
+        std::fs::write(path, contents)
+            .map_err(|e| format!("Failed to write scoring weights {}: {}", path, e))
+    }
+
+    pub fn category_weight(&self, category: &RiskCategory) -> f64 {
+        self.category_weights.get(category).copied().unwrap_or_else(|| category.weight())
+    }
+
+    pub fn confidence_multiplier(&self, source: &DataSource) -> f64 {
+        self.confidence_multipliers.get(source).copied().unwrap_or(1.0)
+    }
+}
+
+// Fits weights on labelled cases outside the holdout and measures them against
+// the built-in weights on the holdout. The split is by case id, so re-running
+// on a grown label file keeps earlier cases on the same side.
+pub fn calibrate(labels: &[AssessmentLabel], threshold: f64, holdout: f64) -> Result<ScoringWeights, String> {
+    if !(holdout > 0.0 && holdout < 1.0) {
+        return Err(format!("Holdout fraction {} must be in (0, 1)", holdout));
+    }
+
+    let (holdout_set, training): (Vec<&AssessmentLabel>, Vec<&AssessmentLabel>) =
+        labels.iter().partition(|label| in_holdout(&label.id, holdout));
+
+    if training.len() < MIN_TRAINING_LABELS {
+        return Err(format!(
+            "Need at least {} training labels, have {}",
+            MIN_TRAINING_LABELS,
+            training.len()
+        ));
+    }
+    if !training.iter().any(|l| l.outcome.is_positive()) || training.iter().all(|l| l.outcome.is_positive()) {
+        return Err("Training labels need both true and false positives".to_string());
+    }
+    if holdout_set.is_empty() {
+        return Err("No labels fell in the holdout set".to_string());
+    }
+
+    let confidence_multipliers = fit_confidence_multipliers(&training);
+    let category_weights = fit_category_weights(&training, &confidence_multipliers);
+
+    let mut weights = ScoringWeights {
+        trained_at: Utc::now(),
+        training_labels: training.len(),
+        threshold,
+        category_weights,
+        confidence_multipliers,
+        before: CalibrationMetrics::default(),
+        after: CalibrationMetrics::default(),
+    };
+
+    weights.before = CalibrationMetrics::measure(&holdout_set, &WeightedAverageStrategy::new(), threshold);
+    weights.after = CalibrationMetrics::measure(
+        &holdout_set,
+        &WeightedAverageStrategy::with_weights(weights.clone()),
+        threshold,
+    );
+    Ok(weights)
+}
+
+fn in_holdout(case_id: &str, fraction: f64) -> bool {
+    let digest = Sha256::digest(case_id.as_bytes());
+    (u16::from_be_bytes([digest[0], digest[1]]) as f64 / 65536.0) < fraction
+}
+
+// A source's multiplier is its smoothed indicator precision relative to all
+// sources, so a provider whose findings analysts keep dismissing counts for less
+fn fit_confidence_multipliers(training: &[&AssessmentLabel]) -> HashMap<DataSource, f64> {
+    let mut by_source: HashMap<&DataSource, (usize, usize)> = HashMap::new();
+    let mut positives = 0;
+    let mut total = 0;
+
+    for indicator in training.iter().flat_map(|label| &label.indicators) {
+        let positive = indicator.outcome.is_positive() as usize;
+        positives += positive;
+        total += 1;
+        if let Some(source) = &indicator.source {
+            let entry = by_source.entry(source).or_default();
+            entry.0 += positive;
+            entry.1 += 1;
+        }
+    }
+
+    let overall = smoothed_precision(positives, total);
+    by_source.into_iter()
+        .filter(|(_, (_, count))| *count >= MIN_SOURCE_LABELS)
+        .map(|(source, (positives, count))| {
+            (source.clone(), (smoothed_precision(positives, count) / overall).clamp(0.25, 2.0))
+        })
+        .collect()
+}
+
+fn smoothed_precision(positives: usize, total: usize) -> f64 {
+    (positives as f64 + 1.0) / (total as f64 + 2.0)
+}
+
+// Logistic regression of the case outcome on each category's confidence-weighted
+// mean score. Positive coefficients become the category weights, rescaled to the
+// built-in weights' mean; categories absent from training keep their default.
+fn fit_category_weights(
+    training: &[&AssessmentLabel],
+    multipliers: &HashMap<DataSource, f64>,
+) -> HashMap<RiskCategory, f64> {
+    let rows: Vec<(Vec<f64>, f64)> = training.iter()
+        .map(|label| {
+            let outcome = if label.outcome.is_positive() { 1.0 } else { 0.0 };
+            (category_features(label, multipliers), outcome)
+        })
+        .collect();
+    let coefficients = logistic_regression(&rows, CATEGORIES.len());
+
+    let observed: Vec<usize> = (0..CATEGORIES.len())
+        .filter(|&c| rows.iter().any(|(features, _)| features[c] > 0.0))
+        .collect();
+    if observed.is_empty() {
+        return HashMap::new();
+    }
+
+    let raw: Vec<f64> = observed.iter().map(|&c| coefficients[c].max(1e-3)).collect();
+    let raw_mean = raw.iter().sum::<f64>() / raw.len() as f64;
+    let default_mean = observed.iter().map(|&c| CATEGORIES[c].weight()).sum::<f64>() / observed.len() as f64;
+
+    observed.iter()
+        .zip(raw)
+        .map(|(&c, w)| (CATEGORIES[c].clone(), (w / raw_mean * default_mean).max(MIN_CATEGORY_WEIGHT)))
+        .collect()
+}
+
+fn category_features(label: &AssessmentLabel, multipliers: &HashMap<DataSource, f64>) -> Vec<f64> {
+    CATEGORIES.iter()
+        .map(|category| {
+            let mut weighted_sum = 0.0;
+            let mut total_weight = 0.0;
+            for indicator in label.indicators.iter().filter(|i| &i.category == category) {
+                let weight = indicator.confidence
+                    * indicator.source.as_ref().and_then(|s| multipliers.get(s)).copied().unwrap_or(1.0);
+                weighted_sum += indicator.score / 100.0 * weight;
+                total_weight += weight;
+            }
+            if total_weight > 0.0 { weighted_sum / total_weight } else { 0.0 }
+        })
+        .collect()
+}
+
+// Batch gradient descent with an L2 penalty; returns coefficients without the intercept
+fn logistic_regression(rows: &[(Vec<f64>, f64)], dims: usize) -> Vec<f64> {
+    let mut coefficients = vec![0.0; dims];
+    let mut intercept = 0.0;
+    let n = rows.len() as f64;
+
+    for _ in 0..ITERATIONS {
+        let mut gradient = vec![0.0; dims];
+        let mut intercept_gradient = 0.0;
+
+        for (features, outcome) in rows {
+            let margin = intercept + features.iter().zip(&coefficients).map(|(x, w)| x * w).sum::<f64>();
+            let error = 1.0 / (1.0 + (-margin).exp()) - outcome;
+            intercept_gradient += error;
+            for (g, x) in gradient.iter_mut().zip(features) {
+                *g += error * x;
+            }
+        }
+
+        intercept -= LEARNING_RATE * intercept_gradient / n;
+        for (w, g) in coefficients.iter_mut().zip(&gradient) {
+            *w -= LEARNING_RATE * (g / n + L2_PENALTY * *w);
+        }
+    }
+
+    coefficients
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
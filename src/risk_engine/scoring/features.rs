@@ -0,0 +1,152 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::scoring::{AnomalyDetection, AnomalyType, BehaviorProfile, RiskTrend, SeverityLevel};
+use std::collections::HashSet;
+
+// Feature order of every vector built here. Models refer to features by these
+// names, so renaming one breaks deployed models.
+pub const FEATURE_NAMES: &[&str] = &[
+    "sanctions_max_score",
+    "sanctions_count",
+    "illicit_activity_max_score",
+    "illicit_activity_count",
+    "high_risk_service_max_score",
+    "high_risk_service_count",
+    "behavioral_anomaly_max_score",
+    "behavioral_anomaly_count",
+    "counterparty_risk_max_score",
+    "counterparty_risk_count",
+    "reputation_risk_max_score",
+    "reputation_risk_count",
+    "technical_risk_max_score",
+    "technical_risk_count",
+    "geographic_risk_max_score",
+    "geographic_risk_count",
+    "indicator_count",
+    "max_indicator_score",
+    "mean_confidence",
+    "source_count",
+    "days_since_last_seen",
+    "anomaly_count",
+    "anomaly_max_severity",
+    "volume_spike_count",
+    "frequency_spike_count",
+    "new_counterparties_count",
+    "structuring_count",
+    "total_transactions",
+    "total_volume",
+    "avg_transaction_size",
+    "unique_counterparties",
+    "risk_trend",
+];
+
+// Transaction behaviour the anomaly detector has seen for the wallet
+#[derive(Debug, Clone, Default)]
+pub struct BehaviorContext {
+    pub anomalies: Vec<AnomalyDetection>,
+    pub profile: Option<BehaviorProfile>,
+}
+
+pub fn feature_index(name: &str) -> Option<usize> {
+    FEATURE_NAMES.iter().position(|f| *f == name)
+}
+
+// Values in FEATURE_NAMES order; NaN marks a value that isn't known, such as
+// behaviour features for a wallet with no screened transactions
+pub fn build_features(indicators: &[RiskIndicator], behavior: &BehaviorContext) -> Vec<f64> {
+    let mut features = vec![0.0; FEATURE_NAMES.len()];
+    let mut set = |name: &str, value: f64| {
+        if let Some(i) = feature_index(name) {
+            features[i] = value;
+        }
+    };
+
+    for category in [
+        RiskCategory::Sanctions,
+        RiskCategory::IllicitActivity,
+        RiskCategory::HighRiskService,
+        RiskCategory::BehavioralAnomaly,
+        RiskCategory::CounterpartyRisk,
+        RiskCategory::ReputationRisk,
+        RiskCategory::TechnicalRisk,
+        RiskCategory::GeographicRisk,
+    ] {
+        let scores: Vec<f64> = indicators.iter()
+            .filter(|i| i.category == category)
+            .map(|i| i.score)
+            .collect();
+        let key = category_key(&category);
+        set(&format!("{}_max_score", key), scores.iter().cloned().fold(0.0, f64::max));
+        set(&format!("{}_count", key), scores.len() as f64);
+    }
+
+    let sources: HashSet<&str> = indicators.iter()
+        .flat_map(|i| i.evidence.iter().map(|e| e.source.as_str()))
+        .collect();
+    set("indicator_count", indicators.len() as f64);
+    set("max_indicator_score", indicators.iter().map(|i| i.score).fold(0.0, f64::max));
+    set("mean_confidence", if indicators.is_empty() {
+        f64::NAN
+    } else {
+        indicators.iter().map(|i| i.confidence).sum::<f64>() / indicators.len() as f64
+    });
+    set("source_count", sources.len() as f64);
+    set("days_since_last_seen", indicators.iter()
+        .map(|i| (chrono::Utc::now() - i.last_seen).num_days().max(0) as f64)
+        .fold(f64::NAN, f64::min));
+
+    let count_of = |anomaly_type: AnomalyType| {
+        behavior.anomalies.iter().filter(|a| a.anomaly_type == anomaly_type).count() as f64
+    };
+    set("anomaly_count", behavior.anomalies.len() as f64);
+    set("anomaly_max_severity", behavior.anomalies.iter()
+        .map(|a| severity_rank(&a.severity))
+        .fold(0.0, f64::max));
+    set("volume_spike_count", count_of(AnomalyType::VolumeSpike));
+    set("frequency_spike_count", count_of(AnomalyType::FrequencySpike));
+    set("new_counterparties_count", count_of(AnomalyType::NewCounterparties));
+    set("structuring_count", count_of(AnomalyType::Structuring));
+
+    match &behavior.profile {
+        Some(profile) => {
+            set("total_transactions", profile.total_transactions as f64);
+            set("total_volume", profile.total_volume);
+            set("avg_transaction_size", profile.avg_transaction_size);
+            set("unique_counterparties", profile.unique_counterparties as f64);
+            set("risk_trend", match profile.risk_trend {
+                RiskTrend::Increasing => 1.0,
+                RiskTrend::Decreasing => -1.0,
+                RiskTrend::Stable => 0.0,
+                RiskTrend::InsufficientData => f64::NAN,
+            });
+        }
+        None => {
+            for name in ["total_transactions", "total_volume", "avg_transaction_size", "unique_counterparties", "risk_trend"] {
+                set(name, f64::NAN);
+            }
+        }
+    }
+
+    features
+}
+
+fn category_key(category: &RiskCategory) -> &'static str {
+    match category {
+        RiskCategory::Sanctions => "sanctions",
+        RiskCategory::IllicitActivity => "illicit_activity",
+        RiskCategory::HighRiskService => "high_risk_service",
+        RiskCategory::BehavioralAnomaly => "behavioral_anomaly",
+        RiskCategory::CounterpartyRisk => "counterparty_risk",
+        RiskCategory::ReputationRisk => "reputation_risk",
+        RiskCategory::TechnicalRisk => "technical_risk",
+        RiskCategory::GeographicRisk => "geographic_risk",
+    }
+}
+
+fn severity_rank(severity: &SeverityLevel) -> f64 {
+    match severity {
+        SeverityLevel::Low => 1.0,
+        SeverityLevel::Medium => 2.0,
+        SeverityLevel::High => 3.0,
+        SeverityLevel::Critical => 4.0,
+    }
+}
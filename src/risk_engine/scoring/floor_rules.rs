@@ -0,0 +1,136 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::config::RiskThresholds;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+// Post-scoring override: if any indicator matches, the wallet's level is
+// raised to at least `floor_level`, however the weighted average came out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FloorRule {
+    pub id: String,
+    pub category: RiskCategory,
+    #[serde(default)]
+    pub min_indicator_score: Option<f64>,
+    #[serde(default)]
+    pub min_confidence: Option<f64>,
+    // Empty matches indicators from any source
+    #[serde(default)]
+    pub sources: Vec<DataSource>,
+    pub floor_level: RiskLevel,
+}
+
+impl FloorRule {
+    fn matches(&self, indicator: &RiskIndicator) -> bool {
+        if indicator.category != self.category {
+            return false;
+        }
+
+        if let Some(min_score) = self.min_indicator_score {
+            if indicator.score < min_score {
+                return false;
+            }
+        }
+
+        if let Some(min_confidence) = self.min_confidence {
+            if indicator.confidence < min_confidence {
+                return false;
+            }
+        }
+
+        if !self.sources.is_empty()
+            && !indicator.evidence.iter().any(|e| self.sources.contains(&e.source))
+        {
+            return false;
+        }
+
+        true
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FloorRuleSet {
+    rules: Vec<FloorRule>,
+}
+
+impl FloorRuleSet {
+    pub fn new(rules: Vec<FloorRule>) -> Self {
+        Self { rules }
+    }
+
+    pub fn default_rules() -> Self {
+        Self::new(vec![
+            FloorRule {
+                id: "sanctions_hit_critical".to_string(),
+                category: RiskCategory::Sanctions,
+                min_indicator_score: Some(90.0),
+                min_confidence: None,
+                sources: vec![],
+                floor_level: RiskLevel::Critical,
+            },
+            FloorRule {
+                id: "illicit_activity_high".to_string(),
+                category: RiskCategory::IllicitActivity,
+                min_indicator_score: None,
+                min_confidence: Some(0.9),
+                sources: vec![],
+                floor_level: RiskLevel::High,
+            },
+        ])
+    }
+
+    pub fn load(path: &str) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read floor rules {}: {}", path, e))?;
+        let rules: Vec<FloorRule> = serde_json::from_str(&contents)
+            .map_err(|e| format!("Invalid floor rules {}: {}", path, e))?;
+        Ok(Self::new(rules))
+    }
+
+    // Raises the profile to the strictest matching floor and records each rule that changed it
+    pub fn apply(&self, profile: &mut WalletRiskProfile, thresholds: &RiskThresholds) {
+        for rule in &self.rules {
+            let Some(indicator) = profile.risk_indicators.iter().find(|i| rule.matches(i)) else {
+                continue;
+            };
+
+            if profile.risk_level.rank() >= rule.floor_level.rank() {
+                continue;
+            }
+
+            let indicator_id = indicator.id.clone();
+            let previous_level = profile.risk_level.clone();
+            let previous_score = profile.overall_risk_score;
+
+            profile.risk_level = rule.floor_level.clone();
+            profile.overall_risk_score = previous_score.max(Self::level_floor_score(&rule.floor_level, thresholds));
+
+            info!(
+                "Floor rule {} raised {} from {} to {} (indicator {})",
+                rule.id,
+                profile.wallet_address,
+                previous_level.as_str(),
+                rule.floor_level.as_str(),
+                indicator_id
+            );
+
+            profile.score_overrides.push(ScoreOverride {
+                rule_id: rule.id.clone(),
+                indicator_id,
+                previous_level,
+                previous_score,
+                new_level: profile.risk_level.clone(),
+                new_score: profile.overall_risk_score,
+            });
+        }
+    }
+
+    fn level_floor_score(level: &RiskLevel, thresholds: &RiskThresholds) -> f64 {
+        match level {
+            RiskLevel::Critical => thresholds.critical,
+            RiskLevel::High => thresholds.high,
+            RiskLevel::Medium => thresholds.medium,
+            RiskLevel::Low => thresholds.low,
+            RiskLevel::Safe | RiskLevel::Unknown => 0.0,
+        }
+    }
+}
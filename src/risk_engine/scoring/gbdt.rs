@@ -0,0 +1,353 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::config::ScoringConfig;
+use crate::risk_engine::scoring::{build_features, feature_index, BehaviorContext, ScoringStrategy, FEATURE_NAMES};
+use serde::Deserialize;
+use std::collections::HashMap;
+use tracing::info;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Objective {
+    // Margin is a log-odds; the score is the probability scaled to 0-100
+    Logistic,
+    // Margin is the score itself
+    Regression,
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Split {
+        feature: usize,
+        threshold: f64,
+        // XGBoost tests `<`, LightGBM `<=`
+        inclusive: bool,
+        left: usize,
+        right: usize,
+        missing_left: bool,
+        // LightGBM "Zero" missing type also routes 0.0 as missing
+        zero_is_missing: bool,
+    },
+    Leaf(f64),
+}
+
+// One tree, flattened so evaluation is an index walk from node 0
+#[derive(Debug, Clone)]
+struct Tree {
+    nodes: Vec<Node>,
+}
+
+impl Tree {
+    fn evaluate(&self, features: &[f64]) -> f64 {
+        let mut index = 0;
+        loop {
+            match &self.nodes[index] {
+                Node::Leaf(value) => return *value,
+                Node::Split { feature, threshold, inclusive, left, right, missing_left, zero_is_missing } => {
+                    let value = features[*feature];
+                    let missing = value.is_nan() || (*zero_is_missing && value == 0.0);
+                    let go_left = if missing {
+                        *missing_left
+                    } else if *inclusive {
+                        value <= *threshold
+                    } else {
+                        value < *threshold
+                    };
+                    index = if go_left { *left } else { *right };
+                }
+            }
+        }
+    }
+}
+
+// XGBoost `dump_model(..., dump_format="json")` node
+#[derive(Debug, Deserialize)]
+struct XgbNode {
+    nodeid: usize,
+    #[serde(default)]
+    split: Option<String>,
+    #[serde(default)]
+    split_condition: Option<f64>,
+    #[serde(default)]
+    yes: Option<usize>,
+    #[serde(default)]
+    no: Option<usize>,
+    #[serde(default)]
+    missing: Option<usize>,
+    #[serde(default)]
+    leaf: Option<f64>,
+    #[serde(default)]
+    children: Vec<XgbNode>,
+}
+
+// LightGBM `dump_model()` JSON
+#[derive(Debug, Deserialize)]
+struct LgbModel {
+    #[serde(default)]
+    num_class: Option<u32>,
+    #[serde(default)]
+    objective: Option<String>,
+    feature_names: Vec<String>,
+    tree_info: Vec<LgbTree>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LgbTree {
+    tree_structure: LgbNode,
+}
+
+#[derive(Debug, Deserialize)]
+struct LgbNode {
+    #[serde(default)]
+    split_feature: Option<usize>,
+    #[serde(default)]
+    threshold: Option<serde_json::Value>,
+    #[serde(default)]
+    decision_type: Option<String>,
+    #[serde(default)]
+    default_left: Option<bool>,
+    #[serde(default)]
+    missing_type: Option<String>,
+    #[serde(default)]
+    left_child: Option<Box<LgbNode>>,
+    #[serde(default)]
+    right_child: Option<Box<LgbNode>>,
+    #[serde(default)]
+    leaf_value: Option<f64>,
+}
+
+// Gradient-boosted tree ensemble trained offline and exported as an XGBoost
+// or LightGBM JSON dump, evaluated over the engine's feature vector
+#[derive(Debug, Clone)]
+pub struct GradientBoostedStrategy {
+    name: String,
+    trees: Vec<Tree>,
+    base_margin: f64,
+    objective: Objective,
+}
+
+impl GradientBoostedStrategy {
+    pub fn load(config: &ScoringConfig) -> Result<Self, String> {
+        let path = config.model_path.as_deref()
+            .ok_or_else(|| "No scoring model path configured".to_string())?;
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read scoring model {}: {}", path, e))?;
+        let model = Self::parse(&contents, config)
+            .map_err(|e| format!("Invalid scoring model {}: {}", path, e))?;
+
+        info!("Loaded {} scoring model with {} trees from {}", model.name, model.trees.len(), path);
+        Ok(model)
+    }
+
+    // A JSON array is an XGBoost dump (one entry per tree); an object with
+    // `tree_info` is a LightGBM dump
+    pub fn parse(contents: &str, config: &ScoringConfig) -> Result<Self, String> {
+        let value: serde_json::Value = serde_json::from_str(contents).map_err(|e| e.to_string())?;
+
+        if value.is_array() {
+            let roots: Vec<XgbNode> = serde_json::from_value(value).map_err(|e| e.to_string())?;
+            Self::from_xgboost(roots, config)
+        } else if value.get("tree_info").is_some() {
+            let model: LgbModel = serde_json::from_value(value).map_err(|e| e.to_string())?;
+            Self::from_lightgbm(model)
+        } else {
+            Err("expected an XGBoost JSON dump or a LightGBM dump_model() JSON".to_string())
+        }
+    }
+
+    fn from_xgboost(roots: Vec<XgbNode>, config: &ScoringConfig) -> Result<Self, String> {
+        let objective = Self::objective(&config.model_objective)?;
+        // base_score is given in output space
+        let base_margin = match objective {
+            Objective::Logistic => {
+                let p = config.model_base_score;
+                if !(p > 0.0 && p < 1.0) {
+                    return Err(format!("base score {} must be in (0, 1) for a logistic objective", p));
+                }
+                (p / (1.0 - p)).ln()
+            }
+            Objective::Regression => config.model_base_score,
+        };
+
+        let trees = roots.iter()
+            .enumerate()
+            .map(|(i, root)| Self::xgboost_tree(root).map_err(|e| format!("tree {}: {}", i, e)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            name: "xgboost".to_string(),
+            trees,
+            base_margin,
+            objective,
+        })
+    }
+
+    fn xgboost_tree(root: &XgbNode) -> Result<Tree, String> {
+        // Children refer to each other by nodeid; index them first
+        let mut by_id: HashMap<usize, &XgbNode> = HashMap::new();
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            if by_id.insert(node.nodeid, node).is_some() {
+                return Err(format!("duplicate node {}", node.nodeid));
+            }
+            stack.extend(node.children.iter());
+        }
+
+        let mut nodes = Vec::new();
+        Self::flatten_xgboost(root.nodeid, &by_id, &mut nodes)?;
+        Ok(Tree { nodes })
+    }
+
+    fn flatten_xgboost(
+        nodeid: usize,
+        by_id: &HashMap<usize, &XgbNode>,
+        nodes: &mut Vec<Node>,
+    ) -> Result<usize, String> {
+        let node = by_id.get(&nodeid).ok_or_else(|| format!("missing node {}", nodeid))?;
+        let index = nodes.len();
+
+        if let Some(leaf) = node.leaf {
+            nodes.push(Node::Leaf(leaf));
+            return Ok(index);
+        }
+
+        let (Some(split), Some(threshold), Some(yes), Some(no)) =
+            (&node.split, node.split_condition, node.yes, node.no)
+        else {
+            return Err(format!("node {} is neither a leaf nor a complete split", nodeid));
+        };
+        let feature = Self::resolve_feature(split)?;
+        if ![yes, no].iter().all(|id| node.children.iter().any(|c| c.nodeid == *id)) {
+            return Err(format!("node {} branches to a node that isn't its child", nodeid));
+        }
+
+        // Placeholder until both children are placed
+        nodes.push(Node::Leaf(0.0));
+        let left = Self::flatten_xgboost(yes, by_id, nodes)?;
+        let right = Self::flatten_xgboost(no, by_id, nodes)?;
+
+        nodes[index] = Node::Split {
+            feature,
+            threshold,
+            inclusive: false,
+            left,
+            right,
+            missing_left: node.missing.unwrap_or(yes) == yes,
+            zero_is_missing: false,
+        };
+        Ok(index)
+    }
+
+    fn from_lightgbm(model: LgbModel) -> Result<Self, String> {
+        if model.num_class.unwrap_or(1) > 1 {
+            return Err("multiclass models are not supported".to_string());
+        }
+
+        // LightGBM splits refer to features by position in its own list
+        let features = model.feature_names.iter()
+            .map(|name| Self::resolve_feature(name))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let objective = match model.objective.as_deref() {
+            Some(o) if o.starts_with("binary") || o.starts_with("cross_entropy") => Objective::Logistic,
+            Some(o) if o.starts_with("regression") || o.starts_with("huber") || o.starts_with("fair") => Objective::Regression,
+            Some(o) => return Err(format!("unsupported objective {}", o)),
+            None => Objective::Regression,
+        };
+
+        let trees = model.tree_info.iter()
+            .enumerate()
+            .map(|(i, tree)| {
+                let mut nodes = Vec::new();
+                Self::flatten_lightgbm(&tree.tree_structure, &features, &mut nodes)
+                    .map_err(|e| format!("tree {}: {}", i, e))?;
+                Ok(Tree { nodes })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Self {
+            name: "lightgbm".to_string(),
+            trees,
+            base_margin: 0.0,
+            objective,
+        })
+    }
+
+    fn flatten_lightgbm(node: &LgbNode, features: &[usize], nodes: &mut Vec<Node>) -> Result<usize, String> {
+        let index = nodes.len();
+
+        if let Some(leaf) = node.leaf_value {
+            nodes.push(Node::Leaf(leaf));
+            return Ok(index);
+        }
+
+        let (Some(split_feature), Some(threshold), Some(left_child), Some(right_child)) =
+            (node.split_feature, &node.threshold, &node.left_child, &node.right_child)
+        else {
+            return Err("node is neither a leaf nor a complete split".to_string());
+        };
+
+        if node.decision_type.as_deref().unwrap_or("<=") != "<=" {
+            return Err("categorical splits are not supported".to_string());
+        }
+        let threshold = threshold.as_f64()
+            .ok_or_else(|| format!("non-numeric threshold {}", threshold))?;
+        let feature = *features.get(split_feature)
+            .ok_or_else(|| format!("split on unknown feature index {}", split_feature))?;
+
+        // Without a missing type LightGBM treats NaN as 0.0
+        let (missing_left, zero_is_missing) = match node.missing_type.as_deref().unwrap_or("None") {
+            "NaN" => (node.default_left.unwrap_or(true), false),
+            "Zero" => (node.default_left.unwrap_or(true), true),
+            _ => (0.0 <= threshold, false),
+        };
+
+        nodes.push(Node::Leaf(0.0));
+        let left = Self::flatten_lightgbm(left_child, features, nodes)?;
+        let right = Self::flatten_lightgbm(right_child, features, nodes)?;
+
+        nodes[index] = Node::Split {
+            feature,
+            threshold,
+            inclusive: true,
+            left,
+            right,
+            missing_left,
+            zero_is_missing,
+        };
+        Ok(index)
+    }
+
+    // Every feature the model uses must be one the engine builds
+    fn resolve_feature(name: &str) -> Result<usize, String> {
+        feature_index(name).ok_or_else(|| {
+            format!("model uses unknown feature {:?}; available features: {}", name, FEATURE_NAMES.join(", "))
+        })
+    }
+
+    fn objective(name: &str) -> Result<Objective, String> {
+        match name {
+            "binary:logistic" | "reg:logistic" => Ok(Objective::Logistic),
+            "reg:squarederror" | "reg:linear" => Ok(Objective::Regression),
+            other => Err(format!("unsupported objective {}", other)),
+        }
+    }
+
+    pub fn evaluate(&self, features: &[f64]) -> f64 {
+        let margin = self.base_margin + self.trees.iter().map(|t| t.evaluate(features)).sum::<f64>();
+
+        let score = match self.objective {
+            Objective::Logistic => 100.0 / (1.0 + (-margin).exp()),
+            Objective::Regression => margin,
+        };
+        score.clamp(0.0, 100.0)
+    }
+}
+
+impl ScoringStrategy for GradientBoostedStrategy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn score(&self, indicators: &[RiskIndicator], behavior: &BehaviorContext) -> f64 {
+        self.evaluate(&build_features(indicators, behavior))
+    }
+}
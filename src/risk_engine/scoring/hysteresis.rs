@@ -0,0 +1,147 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::config::{HysteresisConfig, RiskThresholds};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{info, warn};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LevelState {
+    pub level: RiskLevel,
+    pub pending_level: Option<RiskLevel>,
+    pub pending_cycles: u32,
+    pub updated_at: chrono::DateTime<Utc>,
+}
+
+// Keeps a wallet at its previous level until a change clears the hysteresis
+// band and has been seen for the configured number of consecutive cycles.
+#[derive(Debug, Clone)]
+pub struct RiskLevelStabilizer {
+    config: HysteresisConfig,
+    thresholds: RiskThresholds,
+    states: HashMap<String, LevelState>,
+}
+
+impl RiskLevelStabilizer {
+    pub fn new(config: HysteresisConfig, thresholds: RiskThresholds) -> Self {
+        let states = match &config.state_path {
+            Some(path) => Self::load_states(path),
+            None => HashMap::new(),
+        };
+
+        Self { config, thresholds, states }
+    }
+
+    pub fn previous_level(&self, wallet_address: &str) -> Option<&RiskLevel> {
+        self.states.get(wallet_address).map(|s| &s.level)
+    }
+
+    pub fn stabilize(&mut self, profile: &mut WalletRiskProfile) {
+        let raw_level = profile.risk_level.clone();
+        let now = Utc::now();
+
+        // Unknown is an outage marker, not a level to settle on
+        if raw_level == RiskLevel::Unknown {
+            return;
+        }
+
+        let Some(state) = self.states.get_mut(&profile.wallet_address) else {
+            self.states.insert(profile.wallet_address.clone(), LevelState {
+                level: raw_level,
+                pending_level: None,
+                pending_cycles: 0,
+                updated_at: now,
+            });
+            return;
+        };
+
+        // Floor rules are deliberate hard overrides and apply immediately
+        if !profile.score_overrides.is_empty() || raw_level == state.level {
+            state.level = raw_level;
+            state.pending_level = None;
+            state.pending_cycles = 0;
+            state.updated_at = now;
+            return;
+        }
+
+        let upgrading = raw_level.rank() > state.level.rank();
+        let candidate = if upgrading {
+            let shifted = self.level_for_score(profile.overall_risk_score - self.config.band);
+            if shifted.rank() > state.level.rank() { shifted } else { state.level.clone() }
+        } else {
+            let shifted = self.level_for_score(profile.overall_risk_score + self.config.band);
+            if shifted.rank() < state.level.rank() { shifted } else { state.level.clone() }
+        };
+
+        if candidate == state.level {
+            state.pending_level = None;
+            state.pending_cycles = 0;
+        } else {
+            if state.pending_level.as_ref() == Some(&candidate) {
+                state.pending_cycles += 1;
+            } else {
+                state.pending_level = Some(candidate.clone());
+                state.pending_cycles = 1;
+            }
+
+            let required = if upgrading { self.config.upgrade_cycles } else { self.config.downgrade_cycles };
+            if state.pending_cycles >= required.max(1) {
+                info!(
+                    "Risk level for {} moved {} -> {} after {} cycles",
+                    profile.wallet_address,
+                    state.level.as_str(),
+                    candidate.as_str(),
+                    state.pending_cycles
+                );
+                state.level = candidate;
+                state.pending_level = None;
+                state.pending_cycles = 0;
+            }
+        }
+        state.updated_at = now;
+
+        // Only the level is held; the score stays what the providers reported
+        profile.metadata.insert("raw_risk_level".to_string(), serde_json::json!(raw_level.as_str()));
+        profile.risk_level = state.level.clone();
+    }
+
+    pub fn forget(&mut self, wallet_address: &str) {
+        self.states.remove(wallet_address);
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let Some(path) = &self.config.state_path else {
+            return Ok(());
+        };
+
+        let contents = serde_json::to_string(&self.states)
+            .map_err(|e| format!("Failed to serialize level state: {}", e))?;
+        std::fs::write(path, contents)
+            .map_err(|e| format!("Failed to write level state {}: {}", path, e))
+    }
+
+    fn load_states(path: &str) -> HashMap<String, LevelState> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+                warn!("Ignoring unreadable level state {}: {}", path, e);
+                HashMap::new()
+            }),
+            Err(_) => HashMap::new(),
+        }
+    }
+
+    fn level_for_score(&self, score: f64) -> RiskLevel {
+        let t = &self.thresholds;
+        if score >= t.critical {
+            RiskLevel::Critical
+        } else if score >= t.high {
+            RiskLevel::High
+        } else if score >= t.medium {
+            RiskLevel::Medium
+        } else if score >= t.low {
+            RiskLevel::Low
+        } else {
+            RiskLevel::Safe
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::risk_engine::types::*;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// How an analyst closed a case
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum CaseOutcome {
+    TruePositive,
+    FalsePositive,
+}
+
+impl CaseOutcome {
+    pub fn is_positive(&self) -> bool {
+        matches!(self, CaseOutcome::TruePositive)
+    }
+}
+
+// Outcome of one indicator on the labelled assessment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndicatorLabel {
+    pub indicator_id: String,
+    pub category: RiskCategory,
+    pub subcategory: String,
+    // Source of the indicator's first evidence
+    #[serde(default)]
+    pub source: Option<DataSource>,
+    pub score: f64,
+    pub confidence: f64,
+    pub outcome: CaseOutcome,
+}
+
+impl IndicatorLabel {
+    pub fn from_indicator(indicator: &RiskIndicator, outcome: CaseOutcome) -> Self {
+        Self {
+            indicator_id: indicator.id.clone(),
+            category: indicator.category.clone(),
+            subcategory: indicator.subcategory.clone(),
+            source: indicator.evidence.first().map(|e| e.source.clone()),
+            score: indicator.score,
+            confidence: indicator.confidence,
+            outcome,
+        }
+    }
+}
+
+// Snapshot of the assessment an analyst reviewed, with the case outcome.
+// Features are keyed by name and omit unknown values, so labels stay usable
+// for training after the feature set grows.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssessmentLabel {
+    pub id: String,
+    pub wallet_address: String,
+    pub analyst: String,
+    pub outcome: CaseOutcome,
+    pub assessed_at: DateTime<Utc>,
+    pub labeled_at: DateTime<Utc>,
+    pub overall_score: f64,
+    pub risk_level: RiskLevel,
+    #[serde(default)]
+    pub scoring_strategy: Option<String>,
+    #[serde(default)]
+    pub features: HashMap<String, f64>,
+    pub indicators: Vec<IndicatorLabel>,
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LabelStore {
+    labels: Vec<AssessmentLabel>,
+}
+
+impl LabelStore {
+    pub fn load(path: &str) -> Result<Self, String> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(format!("Failed to read labels {}: {}", path, e)),
+        };
+
+        let labels: Vec<AssessmentLabel> = serde_json::from_str(&contents)
+            .map_err(|e| format!("Invalid labels file {}: {}", path, e))?;
+        Ok(Self { labels })
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let contents = serde_json::to_string_pretty(&self.labels)
+            .map_err(|e| format!("Failed to serialize labels: {}", e))?;
+        std::fs::write(path, contents)
+            .map_err(|e| format!("Failed to write labels {}: {}", path, e))
+    }
+
+    // Relabelling a case replaces its earlier label
+    pub fn add(&mut self, label: AssessmentLabel) {
+        self.labels.retain(|l| l.id != label.id);
+        self.labels.push(label);
+    }
+
+    pub fn labels(&self) -> &[AssessmentLabel] {
+        &self.labels
+    }
+
+    pub fn for_wallet(&self, wallet_address: &str) -> Vec<AssessmentLabel> {
+        self.labels.iter()
+            .filter(|l| l.wallet_address == wallet_address)
+            .cloned()
+            .collect()
+    }
+}
+
+// Latest full assessment of a wallet, as policies saw it, so a case can be
+// labelled after the cached profile has expired. Features are taken when the
+// wallet was scored, not when the case is closed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssessmentSnapshot {
+    pub wallet_address: String,
+    pub assessed_at: DateTime<Utc>,
+    pub overall_score: f64,
+    pub risk_level: RiskLevel,
+    #[serde(default)]
+    pub scoring_strategy: Option<String>,
+    #[serde(default)]
+    pub features: HashMap<String, f64>,
+    pub indicators: Vec<RiskIndicator>,
+}
+
+impl AssessmentSnapshot {
+    pub fn new(profile: &WalletRiskProfile, features: HashMap<String, f64>) -> Self {
+        Self {
+            wallet_address: profile.wallet_address.clone(),
+            assessed_at: profile.last_updated,
+            overall_score: profile.overall_risk_score,
+            risk_level: profile.risk_level.clone(),
+            scoring_strategy: profile.metadata.get("scoring_strategy")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            features,
+            indicators: profile.risk_indicators.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AssessmentStore {
+    snapshots: HashMap<String, AssessmentSnapshot>,
+}
+
+impl AssessmentStore {
+    pub fn load(path: &str) -> Result<Self, String> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(format!("Failed to read assessments {}: {}", path, e)),
+        };
+
+        let snapshots: HashMap<String, AssessmentSnapshot> = serde_json::from_str(&contents)
+            .map_err(|e| format!("Invalid assessments file {}: {}", path, e))?;
+        Ok(Self { snapshots })
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let contents = serde_json::to_string(&self.snapshots)
+            .map_err(|e| format!("Failed to serialize assessments: {}", e))?;
+        std::fs::write(path, contents)
+            .map_err(|e| format!("Failed to write assessments {}: {}", path, e))
+    }
+
+    pub fn record(&mut self, snapshot: AssessmentSnapshot) {
+        self.snapshots.insert(snapshot.wallet_address.clone(), snapshot);
+    }
+
+    pub fn latest(&self, wallet_address: &str) -> Option<&AssessmentSnapshot> {
+        self.snapshots.get(wallet_address)
+    }
+
+    pub fn forget(&mut self, wallet_address: &str) {
+        self.snapshots.remove(wallet_address);
+    }
+}
@@ -19,6 +19,7 @@ struct CategoryWeights {
     counterparty_risk: f64,
     reputation_risk: f64,
     technical_risk: f64,
+    geographic_risk: f64,
 }
 
 #[derive(Debug, Clone)]
@@ -39,6 +40,7 @@ impl RiskScoringModel {
                 counterparty_risk: 1.2,
                 reputation_risk: 1.1,
                 technical_risk: 1.0,
+                geographic_risk: 1.2,
             },
             decay_config: ScoreDecayConfig {
                 half_life_days: 30.0,
@@ -104,6 +106,7 @@ impl RiskScoringModel {
             RiskCategory::CounterpartyRisk => self.weights.counterparty_risk,
             RiskCategory::ReputationRisk => self.weights.reputation_risk,
             RiskCategory::TechnicalRisk => self.weights.technical_risk,
+            RiskCategory::GeographicRisk => self.weights.geographic_risk,
         }
     }
     
@@ -0,0 +1,233 @@
+use crate::risk_engine::types::*;
+use std::collections::HashMap;
+
+// Score gap between sources reporting the same exposure that counts as disagreement
+const SCORE_DIVERGENCE: f64 = 30.0;
+// A single-source indicator at or above this score is material on its own
+const MATERIAL_SCORE: f64 = 70.0;
+
+// Merges indicators that describe the same exposure so it is weighted once,
+// and records where providers that both responded tell different stories.
+#[derive(Debug, Clone, Default)]
+pub struct IndicatorReconciler;
+
+impl IndicatorReconciler {
+    pub fn new() -> Self {
+        Self
+    }
+
+    // `responding` lists every source that returned data this run with the
+    // categories it covers (empty = all); a failed source is an outage, not a
+    // disagreement.
+    pub fn reconcile(
+        &self,
+        indicators: Vec<RiskIndicator>,
+        responding: &[(DataSource, Vec<RiskCategory>)],
+    ) -> (Vec<RiskIndicator>, Vec<ProviderDisagreement>) {
+        let mut groups: Vec<(String, Vec<RiskIndicator>)> = Vec::new();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+
+        for indicator in indicators {
+            let key = Self::merge_key(&indicator);
+            match positions.get(&key) {
+                Some(&i) => groups[i].1.push(indicator),
+                None => {
+                    positions.insert(key.clone(), groups.len());
+                    groups.push((key, vec![indicator]));
+                }
+            }
+        }
+
+        let mut disagreements = Vec::new();
+        let mut merged = Vec::with_capacity(groups.len());
+
+        for (key, group) in groups {
+            if let Some(disagreement) = Self::score_divergence(&key, &group) {
+                disagreements.push(disagreement);
+            }
+            merged.push(Self::merge(group));
+        }
+
+        disagreements.extend(Self::missing_exposure(&merged, responding));
+
+        (merged, disagreements)
+    }
+
+    // Category, canonical subcategory and the screened address. Providers list
+    // different counterparties for the same exposure, so those aren't part of
+    // it; findings on a linked address stay apart from the wallet's own.
+    fn merge_key(indicator: &RiskIndicator) -> String {
+        let subcategory = Self::canonical_subcategory(&indicator.subcategory);
+        let screened = indicator.metadata.get("linked_address")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+
+        format!("{:?}|{}|{}", indicator.category, subcategory, screened)
+    }
+
+    // Providers name the same exposure differently, e.g. Chainalysis "OFAC"
+    // and the SDN list "OFAC SDN" are both sanctions
+    fn canonical_subcategory(subcategory: &str) -> String {
+        let normalized: String = subcategory
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect();
+
+        let canonical = match normalized.as_str() {
+            "ofac" | "ofacsdn" | "sdn" | "sanctions" | "sanctioned" | "sanctionedentity"
+            | "sanctionedaddress" | "sanctionedjurisdiction" | "sanctionslist" => "sanctions",
+            "mixer" | "mixing" | "mixingservice" | "tumbler" => "mixing",
+            "darknet" | "darknetmarket" | "darkmarket" | "darknetmarketplace" => "darknet_market",
+            "ransom" | "ransomware" => "ransomware",
+            "stolen" | "stolenfunds" | "stolencoins" | "theft" | "hack" | "hacked" => "stolen_funds",
+            "scam" | "fraud" | "fraudshop" => "scam",
+            "terrorism" | "terroristfinancing" => "terrorist_financing",
+            "csam" | "childabusematerial" => "child_abuse_material",
+            "gambling" | "onlinegambling" => "gambling",
+            _ => return normalized,
+        };
+        canonical.to_string()
+    }
+
+    fn sources(indicator: &RiskIndicator) -> Vec<DataSource> {
+        let mut sources: Vec<DataSource> = Vec::new();
+        for evidence in &indicator.evidence {
+            if !sources.contains(&evidence.source) {
+                sources.push(evidence.source.clone());
+            }
+        }
+        sources
+    }
+
+    fn merge(mut group: Vec<RiskIndicator>) -> RiskIndicator {
+        if group.len() == 1 {
+            return group.pop().expect("non-empty group");
+        }
+
+        let merged_from: Vec<String> = group.iter().map(|i| i.id.clone()).collect();
+
+        // A source repeating itself isn't independent confirmation: take its most
+        // confident report, then combine distinct sources as 1 - Π(1 - c)
+        let mut by_source: Vec<(DataSource, f64)> = Vec::new();
+        for indicator in &group {
+            for source in Self::sources(indicator) {
+                match by_source.iter_mut().find(|(s, _)| *s == source) {
+                    Some((_, confidence)) => *confidence = confidence.max(indicator.confidence),
+                    None => by_source.push((source, indicator.confidence)),
+                }
+            }
+        }
+        let confidence = 1.0 - by_source.iter().map(|(_, c)| 1.0 - c.clamp(0.0, 1.0)).product::<f64>();
+        let weight: f64 = group.iter().map(|i| i.confidence).sum();
+        let score = if weight > 0.0 {
+            group.iter().map(|i| i.score * i.confidence).sum::<f64>() / weight
+        } else {
+            group.iter().map(|i| i.score).fold(0.0, f64::max)
+        };
+
+        let mut iter = group.into_iter();
+        let mut base = iter.next().expect("non-empty group");
+        for other in iter {
+            base.evidence.extend(other.evidence);
+            base.first_seen = base.first_seen.min(other.first_seen);
+            base.last_seen = base.last_seen.max(other.last_seen);
+            for hash in other.transaction_hashes {
+                if !base.transaction_hashes.contains(&hash) {
+                    base.transaction_hashes.push(hash);
+                }
+            }
+            for address in other.addresses_involved {
+                if !base.addresses_involved.contains(&address) {
+                    base.addresses_involved.push(address);
+                }
+            }
+        }
+
+        let sources: Vec<&str> = Self::sources(&base).iter().map(DataSource::as_str).collect();
+        base.score = score;
+        base.confidence = confidence;
+        base.description = format!("{} (confirmed by {})", base.description, sources.join(", "));
+        base.metadata.insert("merged_from".to_string(), serde_json::json!(merged_from));
+        base.metadata.insert("sources".to_string(), serde_json::json!(sources));
+        base
+    }
+
+    fn score_divergence(key: &str, group: &[RiskIndicator]) -> Option<ProviderDisagreement> {
+        let mut by_source: Vec<(DataSource, f64)> = Vec::new();
+        for indicator in group {
+            for source in Self::sources(indicator) {
+                if !by_source.iter().any(|(s, _)| *s == source) {
+                    by_source.push((source, indicator.score));
+                }
+            }
+        }
+
+        let max = by_source.iter().map(|(_, s)| *s).fold(f64::MIN, f64::max);
+        let min = by_source.iter().map(|(_, s)| *s).fold(f64::MAX, f64::min);
+        if by_source.len() < 2 || max - min < SCORE_DIVERGENCE {
+            return None;
+        }
+
+        Some(ProviderDisagreement {
+            key: key.to_string(),
+            category: group[0].category.clone(),
+            scores: by_source,
+            silent_sources: Vec::new(),
+            reason: format!("Provider scores differ by {:.0} points", max - min),
+        })
+    }
+
+    // One source reports material exposure in a category the others saw nothing in
+    fn missing_exposure(
+        indicators: &[RiskIndicator],
+        responding: &[(DataSource, Vec<RiskCategory>)],
+    ) -> Vec<ProviderDisagreement> {
+        let mut by_category: Vec<(RiskCategory, Vec<(DataSource, f64)>)> = Vec::new();
+        for indicator in indicators {
+            let entry = match by_category.iter_mut().find(|(c, _)| *c == indicator.category) {
+                Some(entry) => entry,
+                None => {
+                    by_category.push((indicator.category.clone(), Vec::new()));
+                    by_category.last_mut().expect("just pushed")
+                }
+            };
+            for source in Self::sources(indicator) {
+                match entry.1.iter_mut().find(|(s, _)| *s == source) {
+                    Some((_, score)) => *score = score.max(indicator.score),
+                    None => entry.1.push((source, indicator.score)),
+                }
+            }
+        }
+
+        by_category.into_iter()
+            .filter_map(|(category, scores)| {
+                if !scores.iter().any(|(_, s)| *s >= MATERIAL_SCORE) {
+                    return None;
+                }
+
+                let silent: Vec<DataSource> = responding.iter()
+                    .filter(|(_, covers)| covers.is_empty() || covers.contains(&category))
+                    .filter(|(s, _)| !scores.iter().any(|(reported, _)| reported == s))
+                    .map(|(s, _)| s.clone())
+                    .collect();
+                if silent.is_empty() {
+                    return None;
+                }
+
+                Some(ProviderDisagreement {
+                    key: format!("{:?}", category),
+                    reason: format!(
+                        "{} exposure reported by {} but not by {}",
+                        category.description(),
+                        scores.iter().map(|(s, _)| s.as_str()).collect::<Vec<_>>().join(", "),
+                        silent.iter().map(DataSource::as_str).collect::<Vec<_>>().join(", ")
+                    ),
+                    category,
+                    scores,
+                    silent_sources: silent,
+                })
+            })
+            .collect()
+    }
+}
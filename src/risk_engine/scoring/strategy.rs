@@ -0,0 +1,83 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::scoring::{BehaviorContext, ScoringWeights};
+use std::fmt::Debug;
+
+// Turns a wallet's reconciled indicators into its overall score in [0, 100].
+// Floor rules are applied to whatever the strategy returns.
+pub trait ScoringStrategy: Send + Sync + Debug {
+    fn name(&self) -> &str;
+
+    fn score(&self, indicators: &[RiskIndicator], behavior: &BehaviorContext) -> f64;
+}
+
+// Confidence- and category-weighted average of indicator scores, stretched
+// above 60 so a few severe findings aren't diluted by minor ones
+#[derive(Debug, Clone, Default)]
+pub struct WeightedAverageStrategy {
+    // Calibrated from analyst labels; built-in category weights when unset
+    weights: Option<ScoringWeights>,
+}
+
+impl WeightedAverageStrategy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_weights(weights: ScoringWeights) -> Self {
+        Self { weights: Some(weights) }
+    }
+
+    // Score of (category, source, score, confidence) entries, so labelled
+    // assessments can be rescored without their full indicators
+    pub fn combine<'a>(
+        &self,
+        entries: impl IntoIterator<Item = (&'a RiskCategory, Option<&'a DataSource>, f64, f64)>,
+    ) -> f64 {
+        let mut weighted_sum = 0.0;
+        let mut total_weight = 0.0;
+
+        for (category, source, score, confidence) in entries {
+            let weight = match &self.weights {
+                Some(weights) => {
+                    weights.category_weight(category)
+                        * confidence
+                        * source.map(|s| weights.confidence_multiplier(s)).unwrap_or(1.0)
+                }
+                None => category.weight() * confidence,
+            };
+            weighted_sum += score * weight;
+            total_weight += weight;
+        }
+
+        if total_weight > 0.0 {
+            Self::apply_nonlinear_scaling(weighted_sum / total_weight)
+        } else {
+            0.0
+        }
+    }
+
+    fn apply_nonlinear_scaling(score: f64) -> f64 {
+        if score > 80.0 {
+            score + (score - 80.0) * 0.5
+        } else if score > 60.0 {
+            score + (score - 60.0) * 0.3
+        } else {
+            score
+        }.min(100.0)
+    }
+}
+
+impl ScoringStrategy for WeightedAverageStrategy {
+    fn name(&self) -> &str {
+        match self.weights {
+            Some(_) => "weighted_average_calibrated",
+            None => "weighted_average",
+        }
+    }
+
+    fn score(&self, indicators: &[RiskIndicator], _behavior: &BehaviorContext) -> f64 {
+        self.combine(indicators.iter().map(|i| {
+            (&i.category, i.evidence.first().map(|e| &e.source), i.score, i.confidence)
+        }))
+    }
+}
@@ -1,12 +1,28 @@
 use crate::risk_engine::{
     config::RiskEngineConfig,
-    providers::{TrmClient, ChainalysisClient, RiskDataAggregator},
-    scoring::{RiskScoringModel, BehavioralAnomalyDetector},
-    policy::{PolicyManager, ActionExecutor},
+    scheduler::MonitoringScheduler,
+    entity_clusters::{cluster_exposure_indicator, EntityClusterIndex},
+    jurisdiction::{jurisdiction_indicator, JurisdictionRules},
+    sybil::{fetch_wallet_activity, SybilDetector},
+    transaction_screening::{indicators_from_assessment, TransactionScreener},
+    providers::{build_providers, open_cache_store, ProviderCacheStore, ProviderHealth, RiskDataAggregator, TrmClient},
+    providers::{ProviderUsage, UsageReport, UsageTracker},
+    scoring::{RiskScoringModel, BehavioralAnomalyDetector, FloorRuleSet, RiskLevelStabilizer},
+    scoring::{BehaviorContext, GradientBoostedStrategy, ScoringStrategy, ScoringWeights, WeightedAverageStrategy},
+    scoring::{build_features, AssessmentLabel, CaseOutcome, IndicatorLabel, LabelStore, FEATURE_NAMES},
+    scoring::{AssessmentSnapshot, AssessmentStore},
+    policy::{PolicyManager, ActionExecutor, PolicyAction, ActionParameters, ActionType},
+    policy::{EnforcementGuard, GuardDecision, ActionExecutionResult, PolicyEvaluationResult},
+    policy::AnalystOverride,
+    policy::actions::risk_aware_attestation::{RiskAwareAttestation, RiskStatus},
     types::*,
 };
+use anchor_lang::AccountDeserialize;
+use futures::stream::{self, StreamExt};
+use attestation_registry::Attestation;
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::{pubkey::Pubkey, signature::Keypair};
+use std::str::FromStr;
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
 use tokio::time::{interval, Duration};
@@ -18,15 +34,36 @@ pub struct RiskMonitoringService {
     config: Arc<RiskEngineConfig>,
     rpc_client: Arc<RpcClient>,
     aggregator: Arc<RiskDataAggregator>,
+    provider_cache: Option<Arc<dyn ProviderCacheStore>>,
+    usage_tracker: Arc<UsageTracker>,
     scoring_model: Arc<RiskScoringModel>,
     anomaly_detector: Arc<Mutex<BehavioralAnomalyDetector>>,
     policy_manager: Arc<PolicyManager>,
+    labels: Arc<RwLock<LabelStore>>,
+    assessments: Arc<RwLock<AssessmentStore>>,
     action_executor: Arc<ActionExecutor>,
     
     // State management
     active_wallets: Arc<RwLock<HashSet<String>>>,
     wallet_attestations: Arc<RwLock<HashMap<String, Pubkey>>>,
+    linked_addresses: Arc<RwLock<HashMap<String, Vec<ChainAddress>>>>,
     risk_cache: Arc<RwLock<HashMap<String, (WalletRiskProfile, chrono::DateTime<Utc>)>>>,
+    // Last profile scored with complete data, kept for the keep_previous fail mode;
+    // unlike the risk cache it survives invalidation and is only dropped on unregister
+    last_complete_profiles: Arc<RwLock<HashMap<String, WalletRiskProfile>>>,
+    attestation_contexts: Arc<RwLock<HashMap<String, (AttestationContext, chrono::DateTime<Utc>)>>>,
+    attestation_registry_program_id: Option<Pubkey>,
+    enforcement_guard: Arc<Mutex<EnforcementGuard>>,
+    level_stabilizer: Arc<Mutex<RiskLevelStabilizer>>,
+    scheduler: Arc<Mutex<MonitoringScheduler>>,
+    entity_clusters: Arc<Mutex<EntityClusterIndex>>,
+    jurisdiction_rules: Arc<JurisdictionRules>,
+    sybil_detector: Arc<Mutex<SybilDetector>>,
+    transaction_screener: Option<Arc<TransactionScreener>>,
+    pending_approvals: Arc<RwLock<Vec<PendingAction>>>,
+    // suspension_end of expired suspensions already escalated, per wallet; kept in
+    // memory, so a restart escalates each still-risky wallet once more
+    escalated_suspensions: Arc<RwLock<HashMap<String, Option<i64>>>>,
     
     // Service state
     is_running: Arc<Mutex<bool>>,
@@ -39,28 +76,36 @@ impl RiskMonitoringService {
         // Initialize RPC client
         let rpc_client = Arc::new(RpcClient::new(config.rpc_url.clone()));
         
-        // Initialize providers
-        let trm_config = Arc::new(config.trm.clone());
-        let chainalysis_config = Arc::new(config.chainalysis.clone());
+        // Initialize providers behind the shared response cache
+        let provider_cache = open_cache_store(&config.provider_cache)
+            .map_err(|e| format!("Failed to open provider cache: {}", e))?;
+        let usage_tracker = Arc::new(UsageTracker::load(&config.provider_budgets)?);
+        let providers = build_providers(&config, provider_cache.clone(), usage_tracker.clone())?;
         
-        let trm_client = if config.trm.enabled {
-            Some(Arc::new(TrmClient::new(trm_config)))
-        } else {
-            None
+        // Initialize post-scoring floor rules
+        let floor_rules = match &config.floor_rules_path {
+            Some(path) => FloorRuleSet::load(path)?,
+            None => FloorRuleSet::default_rules(),
         };
         
-        let chainalysis_client = if config.chainalysis.kyt_enabled {
-            ChainalysisClient::new(chainalysis_config)
-                .map(|c| Some(Arc::new(c)))
-                .map_err(|e| e.to_string())?
-        } else {
-            None
+        // Initialize country ratings for attested jurisdictions
+        let jurisdiction_rules = match &config.jurisdiction_rules_path {
+            Some(path) => JurisdictionRules::load(path)?,
+            None => JurisdictionRules::default(),
+        };
+        
+        // Initialize overall scoring; a deployed tree model replaces the weighted average
+        let scoring: Arc<dyn ScoringStrategy> = match (&config.scoring.model_path, &config.scoring.weights_path) {
+            (Some(_), _) => Arc::new(GradientBoostedStrategy::load(&config.scoring)?),
+            (None, Some(path)) => Arc::new(WeightedAverageStrategy::with_weights(ScoringWeights::load(path)?)),
+            (None, None) => Arc::new(WeightedAverageStrategy::new()),
         };
         
         // Initialize aggregator
         let aggregator = Arc::new(RiskDataAggregator::new(
-            trm_client,
-            chainalysis_client,
+            providers,
+            scoring,
+            floor_rules,
             config.clone(),
         ));
         
@@ -71,7 +116,29 @@ impl RiskMonitoringService {
         let anomaly_detector = Arc::new(Mutex::new(BehavioralAnomalyDetector::new()));
         
         // Initialize policy manager
-        let policy_manager = Arc::new(PolicyManager::new(config.clone()));
+        let mut policy_manager = PolicyManager::new(config.clone());
+        if let Some(path) = &config.policy_bundles_path {
+            policy_manager.load_bundles(path).map_err(|e| e.to_string())?;
+        }
+        if let Some(path) = &config.overrides_path {
+            policy_manager.load_overrides(path)?;
+        }
+        let policy_manager = Arc::new(policy_manager);
+        
+        // Initialize analyst case labels
+        let labels = match &config.labels_path {
+            Some(path) => LabelStore::load(path)?,
+            None => LabelStore::default(),
+        };
+        let assessments = match &config.assessments_path {
+            Some(path) => AssessmentStore::load(path)?,
+            None => AssessmentStore::default(),
+        };
+        
+        let attestation_registry_program_id = config.attestation_registry_program_id.as_deref()
+            .map(Pubkey::from_str)
+            .transpose()
+            .map_err(|e| format!("Invalid attestation registry program ID: {}", e))?;
         
         // Initialize action executor
         let risk_engine_keypair = Keypair::new(); // In production, load from secure storage
@@ -81,17 +148,52 @@ impl RiskMonitoringService {
             risk_engine_keypair,
         ).map_err(|e| e.to_string())?;
         
+        let enforcement_guard = EnforcementGuard::new(config.enforcement_guard.clone());
+        let level_stabilizer = RiskLevelStabilizer::new(config.hysteresis.clone(), config.thresholds.clone());
+        let scheduler = MonitoringScheduler::new(config.monitoring.scheduling.clone());
+        let entity_clusters = EntityClusterIndex::new(config.thresholds.high);
+        let sybil_detector = SybilDetector::new(config.monitoring.sybil_detection.clone());
+        
+        // Transaction screening goes through TRM
+        let transaction_screener = if config.trm.enabled && config.monitoring.transaction_screening.enabled {
+            Some(Arc::new(TransactionScreener::new(
+                Arc::new(TrmClient::new(Arc::new(config.trm.clone()))),
+                usage_tracker.clone(),
+                rpc_client.clone(),
+                config.monitoring.transaction_screening.clone(),
+            )))
+        } else {
+            None
+        };
+        
         Ok(Self {
             config,
             rpc_client,
             aggregator,
+            provider_cache,
+            usage_tracker,
             scoring_model,
             anomaly_detector,
             policy_manager,
+            labels: Arc::new(RwLock::new(labels)),
+            assessments: Arc::new(RwLock::new(assessments)),
             action_executor,
             active_wallets: Arc::new(RwLock::new(HashSet::new())),
             wallet_attestations: Arc::new(RwLock::new(HashMap::new())),
+            linked_addresses: Arc::new(RwLock::new(HashMap::new())),
             risk_cache: Arc::new(RwLock::new(HashMap::new())),
+            last_complete_profiles: Arc::new(RwLock::new(HashMap::new())),
+            attestation_contexts: Arc::new(RwLock::new(HashMap::new())),
+            attestation_registry_program_id,
+            enforcement_guard: Arc::new(Mutex::new(enforcement_guard)),
+            level_stabilizer: Arc::new(Mutex::new(level_stabilizer)),
+            scheduler: Arc::new(Mutex::new(scheduler)),
+            entity_clusters: Arc::new(Mutex::new(entity_clusters)),
+            jurisdiction_rules: Arc::new(jurisdiction_rules),
+            sybil_detector: Arc::new(Mutex::new(sybil_detector)),
+            transaction_screener,
+            pending_approvals: Arc::new(RwLock::new(Vec::new())),
+            escalated_suspensions: Arc::new(RwLock::new(HashMap::new())),
             is_running: Arc::new(Mutex::new(false)),
         })
     }
@@ -120,6 +222,28 @@ impl RiskMonitoringService {
             service.anomaly_detection_loop().await;
         });
         
+        // Start suspension review loop
+        let service = self.clone();
+        tokio::spawn(async move {
+            service.suspension_review_loop().await;
+        });
+        
+        // Start transaction screening loop
+        if self.transaction_screener.is_some() {
+            let service = self.clone();
+            tokio::spawn(async move {
+                service.transaction_screening_loop().await;
+            });
+        }
+        
+        // Start sybil clustering loop
+        if self.config.monitoring.sybil_detection.enabled {
+            let service = self.clone();
+            tokio::spawn(async move {
+                service.sybil_detection_loop().await;
+            });
+        }
+        
         info!("Risk monitoring service started successfully");
     }
     
@@ -129,18 +253,20 @@ impl RiskMonitoringService {
         info!("Risk monitoring service stopped");
     }
     
+    // Wakes every tick and processes whichever wallets are due
     async fn monitoring_loop(&self) {
         let mut interval = interval(Duration::from_secs(
-            self.config.monitoring.poll_interval_minutes * 60
+            self.config.monitoring.scheduling.tick_seconds
         ));
         
         while *self.is_running.lock().await {
             interval.tick().await;
             
             match self.run_monitoring_cycle().await {
-                Ok(summary) => {
+                Ok(summary) if summary.total_wallets > 0 => {
                     info!("Monitoring cycle completed: {}", summary);
                 }
+                Ok(_) => {}
                 Err(e) => {
                     error!("Monitoring cycle failed: {}", e);
                 }
@@ -151,22 +277,49 @@ impl RiskMonitoringService {
     async fn run_monitoring_cycle(&self) -> Result<MonitoringCycleSummary, String> {
         let start_time = chrono::Utc::now();
         
-        // Get active wallets
+        self.aggregator.refresh_providers();
+        if let Some(cache) = &self.provider_cache {
+            if let Err(e) = cache.purge_expired().await {
+                warn!("Failed to purge provider cache: {}", e);
+            }
+        }
+        
+        // Pop the wallets that are due from the schedule
+        let due = self.scheduler.lock().await
+            .take_due(start_time, self.config.monitoring.batch_size * 10);
+        
         let active_wallets = self.active_wallets.read().await;
         let wallet_attestations = self.wallet_attestations.read().await;
         
-        let wallets_to_check: Vec<(String, Pubkey)> = active_wallets.iter()
+        let wallets_to_check: Vec<(String, Pubkey)> = due.into_iter()
+            .filter(|wallet| active_wallets.contains(wallet))
             .filter_map(|wallet| {
-                wallet_attestations.get(wallet)
+                wallet_attestations.get(&wallet)
                     .map(|attestation| (wallet.clone(), *attestation))
             })
             .collect();
+        let registered_wallets = active_wallets.len();
         
         drop(active_wallets);
         drop(wallet_attestations);
         
         let total_wallets = wallets_to_check.len();
-        info!("Starting monitoring cycle for {} wallets", total_wallets);
+        if total_wallets == 0 {
+            return Ok(MonitoringCycleSummary {
+                cycle_start: start_time,
+                cycle_end: chrono::Utc::now(),
+                total_wallets: 0,
+                processed: 0,
+                high_risk: 0,
+                actions_taken: 0,
+                errors: 0,
+                duration_seconds: 0,
+            });
+        }
+        info!("Starting monitoring cycle for {} due wallets", total_wallets);
+        
+        // Percentage limits are relative to the whole monitored population
+        self.enforcement_guard.lock().await.begin_cycle(registered_wallets);
         
         let mut processed = 0;
         let mut high_risk = 0;
@@ -181,6 +334,7 @@ impl RiskMonitoringService {
                 match self.process_wallet(wallet_address, attestation_key).await {
                     Ok(result) => {
                         processed += 1;
+                        self.schedule_next_check(wallet_address, &result.risk_profile.risk_level).await;
                         
                         if result.risk_level.requires_action() {
                             high_risk += 1;
@@ -194,15 +348,31 @@ impl RiskMonitoringService {
                     }
                     Err(e) => {
                         error!("Failed to process wallet {}: {}", wallet_address, e);
+                        self.scheduler.lock().await.retry_later(wallet_address);
                         errors += 1;
                     }
                 }
+                
+                self.rescore_entity_clusters().await;
             }
             
             // Small delay between batches to avoid rate limiting
             tokio::time::sleep(Duration::from_secs(1)).await;
         }
         
+        // Persist per-wallet levels so hysteresis survives restarts
+        if let Err(e) = self.level_stabilizer.lock().await.save() {
+            warn!("Failed to persist risk level state: {}", e);
+        }
+        if let Err(e) = self.usage_tracker.save() {
+            warn!("Failed to persist provider usage: {}", e);
+        }
+        if let Some(path) = &self.config.assessments_path {
+            if let Err(e) = self.assessments.read().await.save(path) {
+                warn!("Failed to persist assessment snapshots: {}", e);
+            }
+        }
+        
         let duration = chrono::Utc::now() - start_time;
         
         Ok(MonitoringCycleSummary {
@@ -217,6 +387,44 @@ impl RiskMonitoringService {
         })
     }
     
+    async fn schedule_next_check(&self, wallet_address: &str, risk_level: &RiskLevel) {
+        let expires_at = self.attestation_context(wallet_address).await
+            .ok()
+            .flatten()
+            .map(|c| c.expires_at);
+        
+        self.scheduler.lock().await.reschedule(wallet_address, risk_level, expires_at);
+    }
+    
+    // Re-scores monitored wallets whose entity cluster was just found to be
+    // high risk, without waiting for their scheduled check
+    async fn rescore_entity_clusters(&self) {
+        let members = self.entity_clusters.lock().await.take_pending_rescore();
+        
+        for wallet_address in members {
+            let Some(attestation_key) = self.wallet_attestations.read().await.get(&wallet_address).cloned() else {
+                continue;
+            };
+            
+            info!("Re-scoring {} after its entity cluster was rated high risk", wallet_address);
+            self.risk_cache.write().await.remove(&wallet_address);
+            
+            match self.process_wallet(&wallet_address, &attestation_key).await {
+                Ok(result) => {
+                    self.schedule_next_check(&wallet_address, &result.risk_profile.risk_level).await;
+                }
+                Err(e) => {
+                    error!("Failed to re-score cluster member {}: {}", wallet_address, e);
+                    self.scheduler.lock().await.schedule_now(&wallet_address);
+                }
+            }
+        }
+    }
+    
+    pub async fn report_anomaly(&self, wallet_address: &str) {
+        self.scheduler.lock().await.record_anomaly(wallet_address);
+    }
+    
     async fn process_wallet(
         &self,
         wallet_address: &str,
@@ -235,18 +443,152 @@ impl RiskMonitoringService {
             }
         }
         
-        // Fetch fresh risk data
-        let risk_profile = self.aggregator.aggregate_wallet_risk(wallet_address).await
-            .map_err(|e| format!("Failed to aggregate risk data: {}", e))?;
-        
-        // Update cache
-        let mut cache = self.risk_cache.write().await;
-        cache.insert(wallet_address.to_string(), (risk_profile.clone(), chrono::Utc::now()));
+        let risk_profile = self.rescore(wallet_address).await?;
         
         // Evaluate policy
         self.evaluate_and_execute(wallet_address, attestation_key, &risk_profile).await
     }
     
+    // Fresh risk profile with fail mode and hysteresis applied, as policies see it
+    async fn rescore(&self, wallet_address: &str) -> Result<WalletRiskProfile, String> {
+        // Fetch fresh risk data, with any exposure inherited from a high-risk cluster
+        let cluster_exposure = self.entity_clusters.lock().await
+            .exposure_for(wallet_address)
+            .map(|attribution| cluster_exposure_indicator(wallet_address, attribution));
+        let risk_profile = self.aggregate(wallet_address, cluster_exposure.into_iter().collect()).await?;
+        self.entity_clusters.lock().await.observe(wallet_address, risk_profile.entity.as_ref());
+        let mut risk_profile = self.apply_fail_mode(wallet_address, risk_profile).await;
+        
+        // Damp level changes around thresholds
+        self.level_stabilizer.lock().await.stabilize(&mut risk_profile);
+        self.usage_tracker.record_level(wallet_address, &risk_profile.risk_level);
+        
+        // Both may have changed the level the aggregator recommended for
+        risk_profile.recommendations = self.aggregator
+            .generate_recommendations(&risk_profile.risk_level, &risk_profile.risk_indicators);
+        
+        // Update cache
+        self.risk_cache.write().await
+            .insert(wallet_address.to_string(), (risk_profile.clone(), chrono::Utc::now()));
+        if risk_profile.fail_mode_applied.is_none() {
+            self.last_complete_profiles.write().await
+                .insert(wallet_address.to_string(), risk_profile.clone());
+        }
+        
+        // Keep what policies saw for labelling cases later
+        let behavior = self.behavior_context(wallet_address).await;
+        let features = FEATURE_NAMES.iter()
+            .zip(build_features(&risk_profile.risk_indicators, &behavior))
+            .filter(|(_, value)| !value.is_nan())
+            .map(|(name, value)| (name.to_string(), value))
+            .collect();
+        self.assessments.write().await.record(AssessmentSnapshot::new(&risk_profile, features));
+        
+        Ok(risk_profile)
+    }
+    
+    // Provider data for the wallet and its linked addresses, plus the rating of
+    // its attested jurisdiction, scored with the wallet's observed behaviour
+    async fn aggregate(
+        &self,
+        wallet_address: &str,
+        mut extra_indicators: Vec<RiskIndicator>,
+    ) -> Result<WalletRiskProfile, String> {
+        let jurisdiction = match self.attestation_context(wallet_address).await {
+            Ok(context) => context.and_then(|c| self.jurisdiction_rules.assess(&c.jurisdiction)),
+            Err(e) => {
+                warn!("Failed to load attestation for {}, skipping jurisdiction rating: {}", wallet_address, e);
+                None
+            }
+        };
+        extra_indicators.extend(jurisdiction.as_ref().and_then(|a| jurisdiction_indicator(wallet_address, a)));
+        
+        let behavior = self.behavior_context(wallet_address).await;
+        let linked = self.linked_addresses_for(wallet_address).await;
+        let suppressed: Vec<RiskCategory> = self.policy_manager.active_overrides(wallet_address)
+            .into_iter()
+            .flat_map(|o| o.suppressed_categories)
+            .collect();
+        let mut risk_profile = self.aggregator
+            .aggregate_wallet_risk_with(wallet_address, extra_indicators, &linked, &behavior, &suppressed)
+            .await
+            .map_err(|e| format!("Failed to aggregate risk data: {}", e))?;
+        risk_profile.jurisdiction = jurisdiction;
+        risk_profile.sybil_cluster = self.sybil_detector.lock().await.cluster_for(wallet_address).cloned();
+        Ok(risk_profile)
+    }
+    
+    async fn behavior_context(&self, wallet_address: &str) -> BehaviorContext {
+        let detector = self.anomaly_detector.lock().await;
+        BehaviorContext {
+            anomalies: detector.recent_anomalies(
+                wallet_address,
+                chrono::Duration::hours(self.config.monitoring.scheduling.anomaly_window_hours as i64),
+            ),
+            profile: detector.get_wallet_behavior_profile(wallet_address),
+        }
+    }
+    
+    // Applies the product's fail mode when a required source (or every source) is down
+    async fn apply_fail_mode(
+        &self,
+        wallet_address: &str,
+        mut risk_profile: WalletRiskProfile,
+    ) -> WalletRiskProfile {
+        let jurisdiction = match self.attestation_context(wallet_address).await {
+            Ok(context) => context.map(|c| c.jurisdiction),
+            Err(e) => {
+                warn!("Failed to load attestation for {}, using default source requirements: {}", wallet_address, e);
+                None
+            }
+        };
+        let requirements = self.policy_manager.source_requirements(jurisdiction.as_deref());
+        
+        let missing_required: Vec<&DataSource> = requirements.required_sources.iter()
+            .filter(|source| !risk_profile.data_sources.contains(source))
+            .collect();
+        let no_data = risk_profile.data_sources.is_empty() && !risk_profile.provider_outages.is_empty();
+        
+        if missing_required.is_empty() && !no_data {
+            return risk_profile;
+        }
+        
+        warn!(
+            "Risk data incomplete for {} (missing {:?}), applying fail mode {:?}",
+            wallet_address, missing_required, requirements.fail_mode
+        );
+        
+        let mut fail_mode = requirements.fail_mode.clone();
+        
+        if fail_mode == FailMode::KeepPrevious {
+            if let Some(previous) = self.last_complete_profiles.read().await.get(wallet_address) {
+                let mut kept = previous.clone();
+                kept.provider_outages = risk_profile.provider_outages;
+                kept.fail_mode_applied = Some(FailMode::KeepPrevious);
+                return kept;
+            }
+            
+            // Nothing to keep yet
+            fail_mode = FailMode::MarkUnknown;
+        }
+        
+        match fail_mode {
+            FailMode::MarkUnknown => {
+                risk_profile.risk_level = RiskLevel::Unknown;
+            }
+            FailMode::TreatAsHigh => {
+                if risk_profile.overall_risk_score < self.config.thresholds.high {
+                    risk_profile.overall_risk_score = self.config.thresholds.high;
+                    risk_profile.risk_level = RiskLevel::High;
+                }
+            }
+            FailMode::KeepPrevious => {}
+        }
+        
+        risk_profile.fail_mode_applied = Some(fail_mode);
+        risk_profile
+    }
+    
     async fn evaluate_cached_profile(
         &self,
         wallet_address: &str,
@@ -263,10 +605,17 @@ impl RiskMonitoringService {
         attestation_key: &Pubkey,
         risk_profile: &WalletRiskProfile,
     ) -> Result<WalletProcessingResult, String> {
+        // Policy bundle is selected by the jurisdiction on the wallet's attestation;
+        // evaluating against the default bundle when it can't be read could apply
+        // the wrong jurisdiction's rules, so the wallet is retried next cycle instead
+        let attestation_context = self.attestation_context(wallet_address).await
+            .map_err(|e| format!("Failed to load attestation for {}: {}", wallet_address, e))?;
+        
         // Evaluate policy
-        let policy_evaluation = self.policy_manager.evaluate_policy(
+        let policy_evaluation = self.policy_manager.evaluate_policy_for_jurisdiction(
             risk_profile,
-            None, // attestation_age_days - would need to fetch from chain
+            attestation_context.as_ref().map(|c| c.jurisdiction.as_str()),
+            attestation_context.as_ref().map(|c| c.age_days()),
             None, // recent_volume - would need to calculate
         );
         
@@ -275,23 +624,39 @@ impl RiskMonitoringService {
         
         // Execute recommended actions
         for action in &policy_evaluation.recommended_actions {
-            if !action.requires_approval {
-                match self.action_executor.execute_action(
-                    action,
-                    wallet_address,
-                    attestation_key,
-                    risk_profile,
-                ).await {
-                    Ok(result) => {
-                        if result.success {
-                            action_taken = true;
-                            executed_actions.push(result);
-                        }
-                    }
-                    Err(e) => {
-                        warn!("Failed to execute action for {}: {}", wallet_address, e);
+            if action.requires_approval {
+                self.queue_for_approval(action, wallet_address, attestation_key, risk_profile, "Policy requires approval")
+                    .await;
+                continue;
+            }
+            
+            let (decision, alert) = self.enforcement_guard.lock().await.check(&action.action_type);
+            
+            if let Some(reason) = alert {
+                self.raise_guard_alert(wallet_address, attestation_key, risk_profile, &reason).await;
+            }
+            
+            if let GuardDecision::Deferred(reason) = decision {
+                self.queue_for_approval(action, wallet_address, attestation_key, risk_profile, &reason)
+                    .await;
+                continue;
+            }
+            
+            match self.action_executor.execute_action(
+                action,
+                wallet_address,
+                attestation_key,
+                risk_profile,
+            ).await {
+                Ok(result) => {
+                    if result.success {
+                        action_taken = true;
+                        executed_actions.push(result);
                     }
                 }
+                Err(e) => {
+                    warn!("Failed to execute action for {}: {}", wallet_address, e);
+                }
             }
         }
         
@@ -305,6 +670,569 @@ impl RiskMonitoringService {
         })
     }
     
+    async fn queue_for_approval(
+        &self,
+        action: &PolicyAction,
+        wallet_address: &str,
+        attestation_key: &Pubkey,
+        risk_profile: &WalletRiskProfile,
+        reason: &str,
+    ) {
+        let mut pending = self.pending_approvals.write().await;
+        
+        // Don't queue the same action twice for a wallet
+        if pending.iter().any(|p| p.wallet_address == wallet_address && p.action.action_type == action.action_type) {
+            return;
+        }
+        
+        info!("Queued {:?} for {} pending approval: {}", action.action_type, wallet_address, reason);
+        pending.push(PendingAction {
+            id: uuid::Uuid::new_v4().to_string(),
+            wallet_address: wallet_address.to_string(),
+            attestation_key: *attestation_key,
+            action: action.clone(),
+            risk_profile: risk_profile.clone(),
+            reason: reason.to_string(),
+            queued_at: chrono::Utc::now(),
+        });
+    }
+    
+    async fn raise_guard_alert(
+        &self,
+        wallet_address: &str,
+        attestation_key: &Pubkey,
+        risk_profile: &WalletRiskProfile,
+        reason: &str,
+    ) {
+        let alert = PolicyAction {
+            action_type: ActionType::NotifyComplianceTeam,
+            parameters: ActionParameters {
+                notification_message: Some(format!(
+                    "Enforcement guard tripped ({}). Automatic actions are paused and queued for approval.",
+                    reason
+                )),
+                ..Default::default()
+            },
+            delay_minutes: None,
+            requires_approval: false,
+        };
+        
+        if let Err(e) = self.action_executor.execute_action(&alert, wallet_address, attestation_key, risk_profile).await {
+            error!("Failed to raise enforcement guard alert: {}", e);
+        }
+    }
+    
+    pub async fn pending_approvals(&self) -> Vec<PendingAction> {
+        self.pending_approvals.read().await.clone()
+    }
+    
+    pub async fn approve_action(&self, id: &str) -> Result<ActionExecutionResult, String> {
+        let pending = {
+            let mut queue = self.pending_approvals.write().await;
+            let index = queue.iter().position(|p| p.id == id)
+                .ok_or_else(|| format!("No pending action {}", id))?;
+            queue.remove(index)
+        };
+        
+        let mut action = pending.action.clone();
+        action.requires_approval = false;
+        action.delay_minutes = None;
+        
+        self.action_executor.execute_action(
+            &action,
+            &pending.wallet_address,
+            &pending.attestation_key,
+            &pending.risk_profile,
+        ).await
+        .map_err(|e| e.to_string())
+    }
+    
+    pub async fn reject_action(&self, id: &str) -> Result<(), String> {
+        let mut queue = self.pending_approvals.write().await;
+        let index = queue.iter().position(|p| p.id == id)
+            .ok_or_else(|| format!("No pending action {}", id))?;
+        queue.remove(index);
+        Ok(())
+    }
+    
+    // Overrides change how the wallet is scored, so its cached profile is dropped
+    pub async fn add_override(&self, analyst_override: AnalystOverride) -> Result<(), String> {
+        let wallet_address = analyst_override.wallet_address.clone();
+        self.policy_manager.add_override(analyst_override)?;
+        self.risk_cache.write().await.remove(&wallet_address);
+        self.persist_overrides()
+    }
+    
+    pub async fn remove_override(&self, override_id: &str) -> Result<bool, String> {
+        let removed = self.policy_manager.remove_override(override_id)?;
+        if let Some(removed) = &removed {
+            self.risk_cache.write().await.remove(&removed.wallet_address);
+        }
+        self.persist_overrides()?;
+        Ok(removed.is_some())
+    }
+    
+    fn persist_overrides(&self) -> Result<(), String> {
+        match &self.config.overrides_path {
+            Some(path) => self.policy_manager.save_overrides(path),
+            None => Ok(()),
+        }
+    }
+    
+    // Records how an analyst closed a case on the wallet's latest full assessment.
+    // Indicators without their own outcome take the case outcome.
+    pub async fn label_case(
+        &self,
+        case_id: &str,
+        wallet_address: &str,
+        analyst: &str,
+        outcome: CaseOutcome,
+        indicator_outcomes: HashMap<String, CaseOutcome>,
+        notes: Option<String>,
+    ) -> Result<AssessmentLabel, String> {
+        let snapshot = self.assessments.read().await
+            .latest(wallet_address)
+            .cloned()
+            .ok_or_else(|| format!("No assessment to label for {}", wallet_address))?;
+        
+        if let Some(unknown) = indicator_outcomes.keys().find(|id| !snapshot.indicators.iter().any(|i| &i.id == *id)) {
+            return Err(format!("Assessment for {} has no indicator {}", wallet_address, unknown));
+        }
+        
+        let label = AssessmentLabel {
+            id: case_id.to_string(),
+            wallet_address: wallet_address.to_string(),
+            analyst: analyst.to_string(),
+            outcome,
+            assessed_at: snapshot.assessed_at,
+            labeled_at: chrono::Utc::now(),
+            overall_score: snapshot.overall_score,
+            risk_level: snapshot.risk_level.clone(),
+            scoring_strategy: snapshot.scoring_strategy.clone(),
+            features: snapshot.features.clone(),
+            indicators: snapshot.indicators.iter()
+                .map(|i| IndicatorLabel::from_indicator(i, *indicator_outcomes.get(&i.id).unwrap_or(&outcome)))
+                .collect(),
+            notes,
+        };
+        
+        let mut labels = self.labels.write().await;
+        labels.add(label.clone());
+        if let Some(path) = &self.config.labels_path {
+            labels.save(path)?;
+        }
+        
+        info!("Case {} on {} closed as {:?} by {}", case_id, wallet_address, outcome, analyst);
+        Ok(label)
+    }
+    
+    pub async fn case_labels(&self, wallet_address: &str) -> Vec<AssessmentLabel> {
+        self.labels.read().await.for_wallet(wallet_address)
+    }
+    
+    pub fn provider_usage(&self) -> Vec<ProviderUsage> {
+        self.usage_tracker.provider_usage()
+    }
+    
+    pub fn wallet_usage_report(&self) -> Vec<UsageReport> {
+        self.usage_tracker.wallet_usage()
+    }
+    
+    pub fn tenant_usage_report(&self) -> Vec<UsageReport> {
+        self.usage_tracker.tenant_usage()
+    }
+    
+    pub fn provider_health(&self) -> Vec<ProviderHealth> {
+        self.aggregator.provider_health()
+    }
+    
+    pub async fn enforcement_guard_status(&self) -> Option<String> {
+        self.enforcement_guard.lock().await.trip_reason().map(|r| r.to_string())
+    }
+    
+    pub async fn reset_enforcement_guard(&self) {
+        self.enforcement_guard.lock().await.reset();
+        info!("Enforcement guard reset");
+    }
+    
+    async fn attestation_context(
+        &self,
+        wallet_address: &str,
+    ) -> Result<Option<AttestationContext>, String> {
+        if let Some((context, fetched_at)) = self.attestation_contexts.read().await.get(wallet_address) {
+            let age = chrono::Utc::now() - *fetched_at;
+            if age.num_minutes() < self.config.monitoring.cache_ttl_minutes as i64 {
+                return Ok(Some(context.clone()));
+            }
+        }
+        
+        let Some(registry_program_id) = &self.attestation_registry_program_id else {
+            return Ok(None);
+        };
+        
+        let wallet = Pubkey::from_str(wallet_address)
+            .map_err(|e| format!("Invalid wallet address: {}", e))?;
+        let (attestation_pda, _) = Pubkey::find_program_address(
+            &[b"attestation", wallet.as_ref()],
+            registry_program_id,
+        );
+        
+        // A missing account means no attestation; RPC failures are surfaced
+        let account = self.rpc_client
+            .get_account_with_commitment(&attestation_pda, self.rpc_client.commitment())
+            .map_err(|e| format!("Failed to fetch attestation {}: {}", attestation_pda, e))?
+            .value;
+        let Some(account) = account else {
+            return Ok(None);
+        };
+        
+        let attestation = Attestation::try_deserialize(&mut account.data.as_slice())
+            .map_err(|e| format!("Failed to decode attestation {}: {}", attestation_pda, e))?;
+        
+        let context = AttestationContext {
+            jurisdiction: attestation.jurisdiction,
+            issued_at: attestation.issued_at,
+            expires_at: attestation.expires_at,
+            revoked: attestation.revoked,
+        };
+        
+        self.attestation_contexts.write().await
+            .insert(wallet_address.to_string(), (context.clone(), chrono::Utc::now()));
+        
+        Ok(Some(context))
+    }
+    
+    async fn suspension_review_loop(&self) {
+        let mut interval = interval(Duration::from_secs(
+            self.config.monitoring.suspension_review_minutes * 60
+        ));
+        
+        while *self.is_running.lock().await {
+            interval.tick().await;
+            
+            match self.review_expired_suspensions().await {
+                Ok(reviewed) if reviewed > 0 => {
+                    info!("Reviewed {} expired suspensions", reviewed);
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    error!("Suspension review failed: {}", e);
+                }
+            }
+        }
+    }
+    
+    async fn review_expired_suspensions(&self) -> Result<usize, String> {
+        let wallets: Vec<(String, Pubkey)> = self.wallet_attestations.read().await
+            .iter()
+            .map(|(wallet, attestation)| (wallet.clone(), *attestation))
+            .collect();
+        
+        let now = chrono::Utc::now().timestamp();
+        let mut reviewed = 0;
+        
+        for chunk in wallets.chunks(100) {
+            let keys: Vec<Pubkey> = chunk.iter().map(|(_, key)| *key).collect();
+            let accounts = self.rpc_client.get_multiple_accounts(&keys)
+                .map_err(|e| format!("Failed to fetch attestations: {}", e))?;
+            
+            for ((wallet_address, attestation_key), account) in chunk.iter().zip(accounts) {
+                let Some(account) = account else { continue };
+                
+                let attestation = match RiskAwareAttestation::try_deserialize(&mut account.data.as_slice()) {
+                    Ok(attestation) => attestation,
+                    Err(e) => {
+                        warn!("Failed to decode attestation {}: {}", attestation_key, e);
+                        continue;
+                    }
+                };
+                
+                if attestation.risk_status != RiskStatus::Suspended {
+                    // Lifted elsewhere, so a later suspension is reviewed afresh
+                    if self.escalated_suspensions.read().await.contains_key(wallet_address) {
+                        self.escalated_suspensions.write().await.remove(wallet_address);
+                    }
+                    continue;
+                }
+                
+                if attestation.suspension_end.map(|end| end > now).unwrap_or(false) {
+                    continue;
+                }
+                
+                // Compliance already has this suspension; wait for a new one
+                if self.escalated_suspensions.read().await.get(wallet_address) == Some(&attestation.suspension_end) {
+                    continue;
+                }
+                
+                match self.review_suspension(wallet_address, attestation_key).await {
+                    Ok(outcome) => {
+                        info!("Suspension review for {}: {:?}", wallet_address, outcome);
+                        let mut escalated = self.escalated_suspensions.write().await;
+                        match outcome {
+                            SuspensionReviewOutcome::Escalated { .. } => {
+                                escalated.insert(wallet_address.clone(), attestation.suspension_end);
+                            }
+                            SuspensionReviewOutcome::Reinstated { .. } => {
+                                escalated.remove(wallet_address);
+                            }
+                            SuspensionReviewOutcome::Deferred { .. } => {}
+                        }
+                        reviewed += 1;
+                    }
+                    Err(e) => {
+                        error!("Suspension review failed for {}: {}", wallet_address, e);
+                    }
+                }
+            }
+        }
+        
+        Ok(reviewed)
+    }
+    
+    // Re-assesses the wallet with fresh provider data, then reinstates it or
+    // escalates. Signed by the risk engine, so it may run before suspension_end.
+    // A wallet is only reinstated on a complete assessment; with a provider down
+    // it stays suspended until the next review.
+    pub async fn review_suspension(
+        &self,
+        wallet_address: &str,
+        attestation_key: &Pubkey,
+    ) -> Result<SuspensionReviewOutcome, String> {
+        let risk_profile = self.rescore(wallet_address).await?;
+        
+        // Outages on the wallet's own address hold the review; a linked address
+        // only does when the failing source is one the wallet's rules require
+        let jurisdiction = match self.attestation_context(wallet_address).await {
+            Ok(context) => context.map(|c| c.jurisdiction),
+            Err(e) => {
+                warn!("Failed to load attestation for {}, using default source requirements: {}", wallet_address, e);
+                None
+            }
+        };
+        let required_sources = self.policy_manager.source_requirements(jurisdiction.as_deref()).required_sources;
+        let unavailable: Vec<&str> = risk_profile.provider_outages.iter()
+            .filter(|outage| !outage.is_linked() || required_sources.contains(&outage.source))
+            .map(|outage| outage.source.as_str())
+            .collect();
+        
+        if risk_profile.risk_level.requires_action() {
+            let result = self.evaluate_and_execute(wallet_address, attestation_key, &risk_profile).await?;
+            
+            let escalation = PolicyAction {
+                action_type: ActionType::EscalateToCompliance,
+                parameters: ActionParameters {
+                    notification_message: Some(
+                        "Suspension of {wallet} ended but risk remains {risk_level} (score {risk_score})".to_string()
+                    ),
+                    escalation_level: result.policy_evaluation.escalation_level,
+                    ..Default::default()
+                },
+                delay_minutes: None,
+                requires_approval: false,
+            };
+            
+            self.action_executor.execute_action(&escalation, wallet_address, attestation_key, &risk_profile).await
+                .map_err(|e| e.to_string())?;
+            
+            Ok(SuspensionReviewOutcome::Escalated {
+                risk_level: risk_profile.risk_level,
+                risk_score: risk_profile.overall_risk_score,
+            })
+        } else if !unavailable.is_empty() || risk_profile.fail_mode_applied.is_some() {
+            Ok(SuspensionReviewOutcome::Deferred {
+                reason: format!(
+                    "risk data incomplete (providers unavailable: {:?}, fail mode: {:?})",
+                    unavailable, risk_profile.fail_mode_applied
+                ),
+            })
+        } else {
+            let result = self.action_executor.reinstate_attestation(wallet_address, attestation_key).await
+                .map_err(|e| e.to_string())?;
+            
+            Ok(SuspensionReviewOutcome::Reinstated {
+                risk_level: risk_profile.risk_level,
+                transaction_signature: result.transaction_signature,
+            })
+        }
+    }
+    
+    async fn transaction_screening_loop(&self) {
+        let mut interval = interval(Duration::from_secs(
+            self.config.monitoring.transaction_screening.poll_seconds
+        ));
+        
+        while *self.is_running.lock().await {
+            interval.tick().await;
+            
+            match self.screen_new_transactions().await {
+                Ok(screened) if screened > 0 => {
+                    info!("Screened {} new transactions", screened);
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    error!("Transaction screening failed: {}", e);
+                }
+            }
+        }
+    }
+    
+    async fn sybil_detection_loop(&self) {
+        let mut interval = interval(Duration::from_secs(
+            self.config.monitoring.sybil_detection.poll_minutes * 60
+        ));
+        
+        while *self.is_running.lock().await {
+            interval.tick().await;
+            
+            match self.refresh_sybil_clusters().await {
+                Ok(changed) if !changed.is_empty() => {
+                    info!("Sybil clusters changed for {} wallets", changed.len());
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    error!("Sybil clustering failed: {}", e);
+                }
+            }
+        }
+    }
+    
+    // Reads each monitored wallet's on-chain activity since the last poll,
+    // rebuilds the clusters and re-scores wallets whose cluster changed
+    async fn refresh_sybil_clusters(&self) -> Result<Vec<String>, String> {
+        let wallets: Vec<String> = self.wallet_attestations.read().await.keys().cloned().collect();
+        let limit = self.config.monitoring.sybil_detection.signatures_per_wallet;
+        let concurrency = self.config.monitoring.sybil_detection.max_concurrent_fetches.max(1);
+        
+        let cursors: Vec<(String, Option<String>)> = {
+            let detector = self.sybil_detector.lock().await;
+            wallets.iter()
+                .map(|wallet| (wallet.clone(), detector.last_signature(wallet)))
+                .collect()
+        };
+        
+        // The RPC client blocks, so each wallet's reads run off the async workers
+        let mut fetches = stream::iter(cursors)
+            .map(|(wallet_address, until)| {
+                let rpc_client = self.rpc_client.clone();
+                tokio::task::spawn_blocking(move || {
+                    let activity = fetch_wallet_activity(&rpc_client, &wallet_address, limit, until.as_deref());
+                    (wallet_address, activity)
+                })
+            })
+            .buffer_unordered(concurrency);
+        
+        while let Some(fetched) = fetches.next().await {
+            match fetched {
+                Ok((wallet_address, Ok(activity))) => {
+                    self.sybil_detector.lock().await.observe(&wallet_address, activity);
+                }
+                Ok((_, Err(e))) => warn!("{}", e),
+                Err(e) => warn!("Sybil activity fetch task failed: {}", e),
+            }
+        }
+        
+        let changed = self.sybil_detector.lock().await.recluster();
+        
+        if let Some(path) = &self.config.sybil_clusters_path {
+            let clusters = self.sybil_clusters().await;
+            let written = serde_json::to_string_pretty(&clusters)
+                .map_err(|e| format!("Failed to serialize sybil clusters: {}", e))
+                .and_then(|contents| std::fs::write(path, contents)
+                    .map_err(|e| format!("Failed to write sybil clusters {}: {}", path, e)));
+            if let Err(e) = written {
+                warn!("{}", e);
+            }
+        }
+        
+        for wallet_address in changed.iter().filter(|w| wallets.contains(w)) {
+            self.risk_cache.write().await.remove(wallet_address);
+            self.scheduler.lock().await.schedule_now(wallet_address);
+        }
+        
+        Ok(changed)
+    }
+    
+    pub async fn sybil_cluster(&self, wallet_address: &str) -> Option<SybilCluster> {
+        self.sybil_detector.lock().await.cluster_for(wallet_address).cloned()
+    }
+    
+    pub async fn sybil_clusters(&self) -> Vec<SybilCluster> {
+        self.sybil_detector.lock().await.clusters().into_iter().cloned().collect()
+    }
+    
+    // Screens transfers made since the last poll and evaluates transaction
+    // policies against the wallet's profile with the transfer's risk attached
+    async fn screen_new_transactions(&self) -> Result<usize, String> {
+        let Some(screener) = &self.transaction_screener else {
+            return Ok(0);
+        };
+        
+        let wallets: Vec<(String, Pubkey)> = self.wallet_attestations.read().await
+            .iter()
+            .map(|(wallet, attestation)| (wallet.clone(), *attestation))
+            .collect();
+        
+        let mut screened = 0;
+        
+        for (wallet_address, attestation_key) in wallets {
+            let statuses = match screener.new_signatures(&wallet_address).await {
+                Ok(statuses) => statuses,
+                Err(e) => {
+                    warn!("{}", e);
+                    continue;
+                }
+            };
+            
+            // Each transaction is committed once handled, so a failed screening
+            // is retried from that transaction on the next poll
+            for status in statuses {
+                let signature = status.signature;
+                if status.err.is_some() {
+                    screener.commit(&wallet_address, &signature).await?;
+                    continue;
+                }
+                
+                let assessment = match screener.screen(&wallet_address, &signature).await {
+                    Ok(assessment) => assessment,
+                    Err(e) => {
+                        warn!("{}; retrying next poll", e);
+                        break;
+                    }
+                };
+                screened += 1;
+                
+                let anomalies = self.anomaly_detector.lock().await
+                    .analyze_transaction(&wallet_address, &assessment);
+                if !anomalies.is_empty() {
+                    info!("Transaction {} by {} raised {} anomalies", signature, wallet_address, anomalies.len());
+                    self.report_anomaly(&wallet_address).await;
+                }
+                
+                let cached_profile = self.risk_cache.read().await
+                    .get(&wallet_address)
+                    .map(|(profile, _)| profile.clone());
+                let combined_score = self.scoring_model
+                    .evaluate_transaction_risk(&assessment, cached_profile.as_ref())
+                    .min(100.0);
+                
+                let mut risk_profile = match cached_profile {
+                    Some(profile) => profile,
+                    None => self.aggregate(&wallet_address, Vec::new()).await?,
+                };
+                risk_profile.risk_indicators.extend(indicators_from_assessment(&assessment));
+                risk_profile.transaction_risk = Some(TransactionRisk { assessment, combined_score });
+                
+                let result = self.evaluate_and_execute(&wallet_address, &attestation_key, &risk_profile).await?;
+                if result.action_taken {
+                    info!("Transaction {} by {} triggered {} actions", signature, wallet_address, result.executed_actions.len());
+                }
+                screener.commit(&wallet_address, &signature).await?;
+            }
+        }
+        
+        Ok(screened)
+    }
+    
     async fn anomaly_detection_loop(&self) {
         let mut interval = interval(Duration::from_secs(300)); // Every 5 minutes
         
@@ -335,12 +1263,24 @@ impl RiskMonitoringService {
         let mut wallet_attestations = self.wallet_attestations.write().await;
         
         active_wallets.insert(wallet_address.clone());
+        self.scheduler.lock().await.schedule_now(&wallet_address);
         wallet_attestations.insert(wallet_address, attestation_key);
         
         info!("Registered wallet for risk monitoring");
         Ok(())
     }
     
+    // Registers the wallet and bills its provider calls to `tenant`
+    pub async fn register_wallet_for_tenant(
+        &self,
+        wallet_address: String,
+        attestation_key: Pubkey,
+        tenant: &str,
+    ) -> Result<(), String> {
+        self.usage_tracker.set_tenant(&wallet_address, tenant);
+        self.register_wallet(wallet_address, attestation_key).await
+    }
+    
     pub async fn unregister_wallet(
         &self,
         wallet_address: &str,
@@ -348,15 +1288,90 @@ impl RiskMonitoringService {
         let mut active_wallets = self.active_wallets.write().await;
         let mut wallet_attestations = self.wallet_attestations.write().await;
         let mut risk_cache = self.risk_cache.write().await;
+        let mut attestation_contexts = self.attestation_contexts.write().await;
         
         active_wallets.remove(wallet_address);
         wallet_attestations.remove(wallet_address);
         risk_cache.remove(wallet_address);
+        self.last_complete_profiles.write().await.remove(wallet_address);
+        attestation_contexts.remove(wallet_address);
+        self.level_stabilizer.lock().await.forget(wallet_address);
+        self.scheduler.lock().await.remove(wallet_address);
+        self.entity_clusters.lock().await.remove(wallet_address);
+        self.usage_tracker.forget(wallet_address);
+        self.aggregator.forget_wallet(wallet_address);
+        self.anomaly_detector.lock().await.forget(wallet_address);
+        self.sybil_detector.lock().await.forget(wallet_address);
+        self.linked_addresses.write().await.remove(wallet_address);
+        self.escalated_suspensions.write().await.remove(wallet_address);
+        self.assessments.write().await.forget(wallet_address);
+        if let Some(screener) = &self.transaction_screener {
+            screener.forget(wallet_address).await;
+        }
         
         info!("Unregistered wallet from risk monitoring");
         Ok(())
     }
     
+    // Screens an address on another chain (e.g. a bridge source) as part of the wallet
+    pub async fn link_address(
+        &self,
+        wallet_address: &str,
+        address: ChainAddress,
+    ) -> Result<(), String> {
+        if !self.wallet_attestations.read().await.contains_key(wallet_address) {
+            return Err(format!("Wallet {} not registered", wallet_address));
+        }
+        if address.chain() == Chain::Solana && address.address() == wallet_address {
+            return Err("A wallet cannot be linked to itself".to_string());
+        }
+        
+        let mut linked_addresses = self.linked_addresses.write().await;
+        let linked = linked_addresses.entry(wallet_address.to_string()).or_default();
+        if !linked.contains(&address) {
+            self.usage_tracker.link_address(address.address(), wallet_address);
+            self.aggregator.link_address(&address, wallet_address);
+            linked.push(address);
+        }
+        drop(linked_addresses);
+        
+        // Next check picks up the new exposure
+        self.risk_cache.write().await.remove(wallet_address);
+        self.scheduler.lock().await.schedule_now(wallet_address);
+        
+        info!("Linked address to monitored wallet");
+        Ok(())
+    }
+    
+    pub async fn unlink_address(
+        &self,
+        wallet_address: &str,
+        address: &ChainAddress,
+    ) -> Result<bool, String> {
+        let mut linked_addresses = self.linked_addresses.write().await;
+        let Some(linked) = linked_addresses.get_mut(wallet_address) else {
+            return Ok(false);
+        };
+        
+        let before = linked.len();
+        linked.retain(|a| a != address);
+        let removed = linked.len() != before;
+        drop(linked_addresses);
+        
+        if removed {
+            self.risk_cache.write().await.remove(wallet_address);
+            self.scheduler.lock().await.schedule_now(wallet_address);
+        }
+        Ok(removed)
+    }
+    
+    async fn linked_addresses_for(&self, wallet_address: &str) -> Vec<ChainAddress> {
+        self.linked_addresses.read().await
+            .get(wallet_address)
+            .cloned()
+            .unwrap_or_default()
+    }
+    
     pub async fn get_wallet_risk(
         &self,
         wallet_address: &str,
@@ -374,8 +1389,16 @@ impl RiskMonitoringService {
         &self,
         wallet_address: &str,
     ) -> Result<WalletRiskProfile, String> {
-        // Clear cache for this wallet
+        // Clear cache for this wallet, including stored provider responses
         self.risk_cache.write().await.remove(wallet_address);
+        if let Some(cache) = &self.provider_cache {
+            cache.invalidate(wallet_address).await
+                .map_err(|e| format!("Failed to invalidate provider cache: {}", e))?;
+            for address in self.linked_addresses_for(wallet_address).await {
+                cache.invalidate(address.address()).await
+                    .map_err(|e| format!("Failed to invalidate provider cache: {}", e))?;
+            }
+        }
         
         // Check if wallet is registered
         let attestation_key = self.wallet_attestations.read().await
@@ -384,8 +1407,11 @@ impl RiskMonitoringService {
             .ok_or_else(|| format!("Wallet {} not registered", wallet_address))?;
         
         // Process wallet
-        self.process_wallet(wallet_address, &attestation_key).await
-            .map(|result| result.risk_profile)
+        let result = self.process_wallet(wallet_address, &attestation_key).await?;
+        self.schedule_next_check(wallet_address, &result.risk_profile.risk_level).await;
+        self.rescore_entity_clusters().await;
+        
+        Ok(result.risk_profile)
     }
 }
  
@@ -416,6 +1442,48 @@ impl std::fmt::Display for MonitoringCycleSummary {
     }
 }
  
+#[derive(Debug, Clone)]
+pub struct PendingAction {
+    pub id: String,
+    pub wallet_address: String,
+    pub attestation_key: Pubkey,
+    pub action: PolicyAction,
+    pub risk_profile: WalletRiskProfile,
+    pub reason: String,
+    pub queued_at: chrono::DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub enum SuspensionReviewOutcome {
+    Reinstated {
+        risk_level: RiskLevel,
+        transaction_signature: Option<String>,
+    },
+    Escalated {
+        risk_level: RiskLevel,
+        risk_score: f64,
+    },
+    // Left suspended because the assessment was incomplete
+    Deferred {
+        reason: String,
+    },
+}
+
+// Attestation registry fields the risk engine needs for policy selection
+#[derive(Debug, Clone)]
+pub struct AttestationContext {
+    pub jurisdiction: String,
+    pub issued_at: i64,
+    pub expires_at: i64,
+    pub revoked: bool,
+}
+
+impl AttestationContext {
+    pub fn age_days(&self) -> u32 {
+        ((chrono::Utc::now().timestamp() - self.issued_at).max(0) / 86_400) as u32
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct WalletProcessingResult {
     pub wallet_address: String,
@@ -432,13 +1500,30 @@ impl Clone for RiskMonitoringService {
             config: self.config.clone(),
             rpc_client: self.rpc_client.clone(),
             aggregator: self.aggregator.clone(),
+            provider_cache: self.provider_cache.clone(),
+            usage_tracker: self.usage_tracker.clone(),
             scoring_model: self.scoring_model.clone(),
             anomaly_detector: self.anomaly_detector.clone(),
             policy_manager: self.policy_manager.clone(),
+            labels: self.labels.clone(),
+            assessments: self.assessments.clone(),
             action_executor: self.action_executor.clone(),
             active_wallets: self.active_wallets.clone(),
             wallet_attestations: self.wallet_attestations.clone(),
+            linked_addresses: self.linked_addresses.clone(),
             risk_cache: self.risk_cache.clone(),
+            last_complete_profiles: self.last_complete_profiles.clone(),
+            attestation_contexts: self.attestation_contexts.clone(),
+            attestation_registry_program_id: self.attestation_registry_program_id,
+            enforcement_guard: self.enforcement_guard.clone(),
+            level_stabilizer: self.level_stabilizer.clone(),
+            scheduler: self.scheduler.clone(),
+            entity_clusters: self.entity_clusters.clone(),
+            jurisdiction_rules: self.jurisdiction_rules.clone(),
+            sybil_detector: self.sybil_detector.clone(),
+            transaction_screener: self.transaction_screener.clone(),
+            pending_approvals: self.pending_approvals.clone(),
+            escalated_suspensions: self.escalated_suspensions.clone(),
             is_running: self.is_running.clone(),
         }
     }
\ No newline at end of file
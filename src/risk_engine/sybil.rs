@@ -0,0 +1,380 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::config::SybilDetectionConfig;
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use solana_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::UiTransactionEncoding;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::str::FromStr;
+use tracing::warn;
+
+// How strongly each signal alone ties two wallets to one operator
+const FUNDING_SOURCE_CONFIDENCE: f64 = 0.6;
+const FEE_PAYER_CONFIDENCE: f64 = 0.8;
+const SYNCHRONIZED_CONFIDENCE: f64 = 0.5;
+
+// A funder or fee payer shared by more wallets than this is taken to be a
+// service (exchange hot wallet, relayer) rather than an operator
+const MAX_SHARED_ACCOUNT_WALLETS: usize = 100;
+
+// What a wallet's recent transactions reveal about who runs it
+#[derive(Debug, Clone, Default)]
+pub struct WalletActivity {
+    // Fee payer of the transfer that took the wallet from an empty balance
+    pub funding_source: Option<String>,
+    // Other accounts that paid for transactions the wallet signed
+    pub fee_payers: HashSet<String>,
+    // Block times in unix seconds
+    pub active_at: Vec<i64>,
+    // Newest signature read, so the next poll only reads past it
+    pub newest_signature: Option<String>,
+}
+
+// Reads the wallet's most recent transactions, stopping at `until` when an
+// earlier poll already read up to it. The funding transfer is only found
+// when it is among them.
+pub fn fetch_wallet_activity(
+    rpc_client: &RpcClient,
+    wallet_address: &str,
+    limit: usize,
+    until: Option<&str>,
+) -> Result<WalletActivity, String> {
+    let pubkey = Pubkey::from_str(wallet_address)
+        .map_err(|e| format!("Invalid wallet address {}: {}", wallet_address, e))?;
+    let until = until
+        .map(|sig| Signature::from_str(sig).map_err(|e| format!("Invalid signature {}: {}", sig, e)))
+        .transpose()?;
+
+    let statuses = rpc_client
+        .get_signatures_for_address_with_config(
+            &pubkey,
+            GetConfirmedSignaturesForAddress2Config {
+                before: None,
+                until,
+                limit: Some(limit),
+                commitment: None,
+            },
+        )
+        .map_err(|e| format!("Failed to fetch signatures for {}: {}", wallet_address, e))?;
+
+    let mut activity = WalletActivity {
+        active_at: statuses.iter().filter_map(|s| s.block_time).collect(),
+        newest_signature: statuses.first().map(|s| s.signature.clone()),
+        ..WalletActivity::default()
+    };
+
+    // Oldest first, so the earliest funding transfer wins
+    for status in statuses.iter().rev().filter(|s| s.err.is_none()) {
+        let signature = Signature::from_str(&status.signature)
+            .map_err(|e| format!("Invalid signature {}: {}", status.signature, e))?;
+        let transaction = match rpc_client.get_transaction_with_config(
+            &signature,
+            RpcTransactionConfig {
+                encoding: Some(UiTransactionEncoding::Base64),
+                commitment: None,
+                max_supported_transaction_version: Some(0),
+            },
+        ) {
+            Ok(transaction) => transaction,
+            Err(e) => {
+                warn!("Failed to fetch transaction {}: {}", status.signature, e);
+                continue;
+            }
+        };
+
+        let Some(decoded) = transaction.transaction.transaction.decode() else {
+            continue;
+        };
+        let keys = decoded.message.static_account_keys();
+        let Some(fee_payer) = keys.first().filter(|payer| **payer != pubkey) else {
+            continue;
+        };
+        let wallet_index = keys.iter().position(|key| *key == pubkey);
+
+        // Whoever paid for the transfer that funded the wallet is its funder;
+        // paying for a transfer *to* the wallet says nothing about its fees
+        if activity.funding_source.is_none() {
+            let funded = transaction.transaction.meta.as_ref()
+                .zip(wallet_index)
+                .map(|(meta, index)| {
+                    meta.pre_balances.get(index) == Some(&0)
+                        && meta.post_balances.get(index).map(|b| *b > 0).unwrap_or(false)
+                })
+                .unwrap_or(false);
+            if funded {
+                activity.funding_source = Some(fee_payer.to_string());
+                continue;
+            }
+        }
+
+        // Someone else paid for a transaction the wallet itself signed
+        let signed = wallet_index
+            .map(|index| index < decoded.message.header().num_required_signatures as usize)
+            .unwrap_or(false);
+        if signed {
+            activity.fee_payers.insert(fee_payer.to_string());
+        }
+    }
+
+    Ok(activity)
+}
+
+// Groups monitored wallets that on-chain heuristics tie to one operator.
+// Pairwise links combine their signals' confidence; links below the
+// configured minimum are dropped and the rest are joined transitively.
+#[derive(Debug, Clone)]
+pub struct SybilDetector {
+    config: SybilDetectionConfig,
+    activity: HashMap<String, WalletActivity>,
+    clusters: HashMap<String, SybilCluster>,
+    wallet_cluster: HashMap<String, String>,
+}
+
+impl SybilDetector {
+    pub fn new(config: SybilDetectionConfig) -> Self {
+        Self {
+            config,
+            activity: HashMap::new(),
+            clusters: HashMap::new(),
+            wallet_cluster: HashMap::new(),
+        }
+    }
+
+    // Merges newly read activity into what earlier polls saw. The first
+    // funding source found is kept and only the most recent
+    // `signatures_per_wallet` block times are.
+    pub fn observe(&mut self, wallet_address: &str, activity: WalletActivity) {
+        let limit = self.config.signatures_per_wallet;
+        let entry = self.activity.entry(wallet_address.to_string()).or_default();
+        if entry.funding_source.is_none() {
+            entry.funding_source = activity.funding_source;
+        }
+        entry.fee_payers.extend(activity.fee_payers);
+        entry.active_at.extend(activity.active_at);
+        entry.active_at.sort_unstable();
+        if entry.active_at.len() > limit {
+            let excess = entry.active_at.len() - limit;
+            entry.active_at.drain(..excess);
+        }
+        if activity.newest_signature.is_some() {
+            entry.newest_signature = activity.newest_signature;
+        }
+    }
+
+    // Newest signature already read for the wallet
+    pub fn last_signature(&self, wallet_address: &str) -> Option<String> {
+        self.activity.get(wallet_address)?.newest_signature.clone()
+    }
+
+    pub fn forget(&mut self, wallet_address: &str) {
+        self.activity.remove(wallet_address);
+    }
+
+    pub fn cluster_for(&self, wallet_address: &str) -> Option<&SybilCluster> {
+        self.clusters.get(self.wallet_cluster.get(wallet_address)?)
+    }
+
+    pub fn clusters(&self) -> Vec<&SybilCluster> {
+        self.clusters.values().collect()
+    }
+
+    // Rebuilds clusters from the observed activity and returns the wallets
+    // whose cluster changed. A rebuilt cluster takes over the id of the old
+    // cluster most of its members were in, so allocations keyed on it hold.
+    pub fn recluster(&mut self) -> Vec<String> {
+        let links: Vec<((String, String), HashSet<ClusterSignal>, f64)> = self.links()
+            .into_iter()
+            .map(|(pair, signals)| {
+                let confidence = combined_confidence(&signals);
+                (pair, signals, confidence)
+            })
+            .filter(|(_, _, confidence)| *confidence >= self.config.min_confidence)
+            .collect();
+
+        // Union-find over the kept links
+        let mut parent: HashMap<String, String> = HashMap::new();
+        for ((a, b), _, _) in &links {
+            let root_a = find(&mut parent, a);
+            let root_b = find(&mut parent, b);
+            if root_a != root_b {
+                parent.insert(root_a, root_b);
+            }
+        }
+
+        let mut components: HashMap<String, BTreeSet<String>> = HashMap::new();
+        let wallets: Vec<String> = parent.keys().cloned().collect();
+        for wallet in wallets {
+            let root = find(&mut parent, &wallet);
+            components.entry(root).or_default().insert(wallet);
+        }
+
+        let mut clusters = HashMap::new();
+        let mut wallet_cluster = HashMap::new();
+        for members in components.into_values() {
+            let component_links: Vec<&(_, HashSet<ClusterSignal>, f64)> = links.iter()
+                .filter(|((a, _), _, _)| members.contains(a))
+                .collect();
+            let confidence = component_links.iter().map(|(_, _, c)| c).sum::<f64>() / component_links.len() as f64;
+            let mut signals: Vec<ClusterSignal> = component_links.iter()
+                .flat_map(|(_, signals, _)| signals.iter().copied())
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect();
+            signals.sort_by_key(|s| s.as_str());
+
+            let previous = self.previous_cluster(&members)
+                .filter(|id| !clusters.contains_key(id));
+            let cluster_id = previous.clone().unwrap_or_else(|| cluster_id_for(&members));
+            let detected_at = previous
+                .and_then(|id| self.clusters.get(&id))
+                .map(|c| c.detected_at)
+                .unwrap_or_else(Utc::now);
+
+            for member in &members {
+                wallet_cluster.insert(member.clone(), cluster_id.clone());
+            }
+            clusters.insert(cluster_id.clone(), SybilCluster {
+                cluster_id,
+                confidence,
+                members: members.into_iter().collect(),
+                signals,
+                detected_at,
+            });
+        }
+
+        let mut changed: Vec<String> = self.wallet_cluster.keys()
+            .chain(wallet_cluster.keys())
+            .filter(|wallet| {
+                let before = self.wallet_cluster.get(*wallet).and_then(|id| self.clusters.get(id));
+                let after = wallet_cluster.get(*wallet).and_then(|id| clusters.get(id));
+                match (before, after) {
+                    (Some(b), Some(a)) => b.cluster_id != a.cluster_id || b.members != a.members,
+                    (None, None) => false,
+                    _ => true,
+                }
+            })
+            .cloned()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        changed.sort();
+
+        self.clusters = clusters;
+        self.wallet_cluster = wallet_cluster;
+        changed
+    }
+
+    // Signals seen between each pair of wallets, keyed with the lower address first
+    fn links(&self) -> HashMap<(String, String), HashSet<ClusterSignal>> {
+        let mut links: HashMap<(String, String), HashSet<ClusterSignal>> = HashMap::new();
+        let mut link = |a: &str, b: &str, signal: ClusterSignal| {
+            if a != b {
+                let pair = if a < b { (a.to_string(), b.to_string()) } else { (b.to_string(), a.to_string()) };
+                links.entry(pair).or_default().insert(signal);
+            }
+        };
+
+        // Wallets funded, or paid for, by the same account; a monitored wallet
+        // funding or paying for another links the two directly
+        let mut by_funder: HashMap<&str, Vec<&str>> = HashMap::new();
+        let mut by_fee_payer: HashMap<&str, Vec<&str>> = HashMap::new();
+        for (wallet, activity) in &self.activity {
+            if let Some(funder) = &activity.funding_source {
+                by_funder.entry(funder).or_default().push(wallet);
+            }
+            for fee_payer in &activity.fee_payers {
+                by_fee_payer.entry(fee_payer).or_default().push(wallet);
+            }
+        }
+
+        for (accounts, signal) in [
+            (&by_funder, ClusterSignal::SharedFundingSource),
+            (&by_fee_payer, ClusterSignal::CommonFeePayer),
+        ] {
+            for (account, wallets) in accounts {
+                if wallets.len() > MAX_SHARED_ACCOUNT_WALLETS {
+                    continue;
+                }
+                if self.activity.contains_key(*account) {
+                    for wallet in wallets {
+                        link(account, wallet, signal);
+                    }
+                }
+                for (i, a) in wallets.iter().enumerate() {
+                    for b in &wallets[i + 1..] {
+                        link(a, b, signal);
+                    }
+                }
+            }
+        }
+
+        // Pairs active within the sync window of each other, counted once per window
+        let window = self.config.sync_window_secs.max(1);
+        let mut events: Vec<(i64, &str)> = self.activity.iter()
+            .flat_map(|(wallet, activity)| activity.active_at.iter().map(move |t| (*t, wallet.as_str())))
+            .collect();
+        events.sort();
+
+        let mut windows: HashMap<(&str, &str), HashSet<i64>> = HashMap::new();
+        for (i, (t, a)) in events.iter().enumerate() {
+            for (u, b) in events[i + 1..].iter().take_while(|(u, _)| u - t <= window) {
+                if a != b {
+                    let pair = if a < b { (*a, *b) } else { (*b, *a) };
+                    windows.entry(pair).or_default().insert(u / window);
+                }
+            }
+        }
+        for ((a, b), shared) in windows {
+            if shared.len() >= self.config.min_synchronized_events {
+                link(a, b, ClusterSignal::SynchronizedActivity);
+            }
+        }
+
+        links
+    }
+
+    // Id of the existing cluster holding most of these members
+    fn previous_cluster(&self, members: &BTreeSet<String>) -> Option<String> {
+        let mut counts: HashMap<&String, usize> = HashMap::new();
+        for member in members {
+            if let Some(id) = self.wallet_cluster.get(member) {
+                *counts.entry(id).or_default() += 1;
+            }
+        }
+        counts.into_iter()
+            .max_by(|(a_id, a), (b_id, b)| a.cmp(b).then_with(|| b_id.cmp(a_id)))
+            .map(|(id, _)| id.clone())
+    }
+}
+
+fn combined_confidence(signals: &HashSet<ClusterSignal>) -> f64 {
+    1.0 - signals.iter()
+        .map(|signal| match signal {
+            ClusterSignal::SharedFundingSource => 1.0 - FUNDING_SOURCE_CONFIDENCE,
+            ClusterSignal::CommonFeePayer => 1.0 - FEE_PAYER_CONFIDENCE,
+            ClusterSignal::SynchronizedActivity => 1.0 - SYNCHRONIZED_CONFIDENCE,
+        })
+        .product::<f64>()
+}
+
+fn find(parent: &mut HashMap<String, String>, wallet: &str) -> String {
+    let mut root = wallet.to_string();
+    while let Some(next) = parent.get(&root).filter(|p| **p != root) {
+        root = next.clone();
+    }
+    parent.entry(wallet.to_string()).or_insert_with(|| root.clone());
+    if wallet != root {
+        parent.insert(wallet.to_string(), root.clone());
+    }
+    root
+}
+
+fn cluster_id_for(members: &BTreeSet<String>) -> String {
+    let mut hasher = Sha256::new();
+    for member in members {
+        hasher.update(member.as_bytes());
+    }
+    format!("sybil_{}", &hex::encode(hasher.finalize())[..16])
+}
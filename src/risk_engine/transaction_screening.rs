@@ -0,0 +1,208 @@
+use crate::risk_engine::types::*;
+use crate::risk_engine::config::TransactionScreeningConfig;
+use crate::risk_engine::providers::{RiskDataProvider, TrmClient, TrmTransactionScreening, UsageTracker};
+use solana_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
+use solana_client::rpc_response::RpcConfirmedTransactionStatusWithSignature;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+// Counterparties below this TRM score are not worth an indicator of their own
+const COUNTERPARTY_RISK_MIN: f64 = 50.0;
+
+// Finds transfers a monitored wallet made since the last poll and screens each one
+#[derive(Debug)]
+pub struct TransactionScreener {
+    trm: Arc<TrmClient>,
+    // Screening calls count against TRM's budget like address lookups
+    usage: Arc<UsageTracker>,
+    rpc_client: Arc<RpcClient>,
+    config: TransactionScreeningConfig,
+    last_seen: RwLock<HashMap<String, Signature>>,
+}
+
+impl TransactionScreener {
+    pub fn new(
+        trm: Arc<TrmClient>,
+        usage: Arc<UsageTracker>,
+        rpc_client: Arc<RpcClient>,
+        config: TransactionScreeningConfig,
+    ) -> Self {
+        Self {
+            trm,
+            usage,
+            rpc_client,
+            config,
+            last_seen: RwLock::new(HashMap::new()),
+        }
+    }
+
+    // Transactions since the last committed signature, oldest first, including
+    // failed ones so they can be committed past. The first call for a wallet only
+    // records a starting point; history is not screened.
+    pub async fn new_signatures(
+        &self,
+        wallet_address: &str,
+    ) -> Result<Vec<RpcConfirmedTransactionStatusWithSignature>, String> {
+        let pubkey = Pubkey::from_str(wallet_address)
+            .map_err(|e| format!("Invalid wallet address {}: {}", wallet_address, e))?;
+
+        let Some(until) = self.last_seen.read().await.get(wallet_address).copied() else {
+            let newest = self.signatures_page(&pubkey, None, None, 1)?;
+            if let Some(newest) = newest.first() {
+                self.commit(wallet_address, &newest.signature).await?;
+            }
+            return Ok(Vec::new());
+        };
+
+        // Pages come back newest first; walk back until the last committed one
+        let page_size = self.config.max_signatures_per_poll.max(1);
+        let mut statuses = Vec::new();
+        let mut before = None;
+        loop {
+            let page = self.signatures_page(&pubkey, before, Some(until), page_size)?;
+            let exhausted = page.len() < page_size;
+            before = match page.last() {
+                Some(oldest) => Some(Signature::from_str(&oldest.signature)
+                    .map_err(|e| format!("Invalid signature {}: {}", oldest.signature, e))?),
+                None => None,
+            };
+            statuses.extend(page);
+            if exhausted || before.is_none() {
+                break;
+            }
+        }
+
+        statuses.reverse();
+        Ok(statuses)
+    }
+
+    // Marks the wallet's transactions up to `signature` as screened
+    pub async fn commit(&self, wallet_address: &str, signature: &str) -> Result<(), String> {
+        let signature = Signature::from_str(signature)
+            .map_err(|e| format!("Invalid signature {}: {}", signature, e))?;
+        self.last_seen.write().await.insert(wallet_address.to_string(), signature);
+        Ok(())
+    }
+
+    fn signatures_page(
+        &self,
+        pubkey: &Pubkey,
+        before: Option<Signature>,
+        until: Option<Signature>,
+        limit: usize,
+    ) -> Result<Vec<RpcConfirmedTransactionStatusWithSignature>, String> {
+        self.rpc_client
+            .get_signatures_for_address_with_config(
+                pubkey,
+                GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    until,
+                    limit: Some(limit),
+                    commitment: None,
+                },
+            )
+            .map_err(|e| format!("Failed to fetch signatures for {}: {}", pubkey, e))
+    }
+
+    pub async fn screen(&self, wallet_address: &str, signature: &str) -> Result<TransactionRiskAssessment, String> {
+        self.usage.reserve(self.trm.name(), wallet_address, 1)
+            .map_err(|e| format!("Transaction screening skipped for {}: {}", signature, e))?;
+        let screening = self.trm.screen_transaction(signature).await
+            .map_err(|e| format!("Transaction screening failed for {}: {}", signature, e))?;
+
+        Ok(assessment_from_trm(wallet_address, screening))
+    }
+
+    pub async fn forget(&self, wallet_address: &str) {
+        self.last_seen.write().await.remove(wallet_address);
+    }
+}
+
+pub fn assessment_from_trm(wallet_address: &str, screening: TrmTransactionScreening) -> TransactionRiskAssessment {
+    let mut risk_indicators: Vec<TransactionIndicator> = screening.indicators.iter()
+        .map(|indicator| TransactionIndicator {
+            category: trm_category(&indicator.category),
+            description: indicator.description.clone(),
+            amount_involved: None,
+            counterparty: None,
+            risk_score: severity_score(&indicator.severity),
+        })
+        .collect();
+
+    // Whichever side of the transfer isn't the monitored wallet is the counterparty
+    for side in [&screening.sender_risk, &screening.receiver_risk].into_iter().flatten() {
+        if side.address != wallet_address && side.risk_score >= COUNTERPARTY_RISK_MIN {
+            risk_indicators.push(TransactionIndicator {
+                category: RiskCategory::CounterpartyRisk,
+                description: format!("Counterparty {} rated {}", side.address, side.risk_level),
+                amount_involved: None,
+                counterparty: Some(side.address.clone()),
+                risk_score: side.risk_score,
+            });
+        }
+    }
+
+    TransactionRiskAssessment {
+        tx_hash: screening.transaction_hash,
+        wallet_address: wallet_address.to_string(),
+        risk_score: screening.risk_score,
+        risk_indicators,
+        timestamp: chrono::Utc::now(),
+        verified: true,
+    }
+}
+
+// Transaction indicators become regular indicators so category policies match them too
+pub fn indicators_from_assessment(assessment: &TransactionRiskAssessment) -> Vec<RiskIndicator> {
+    let now = chrono::Utc::now();
+
+    assessment.risk_indicators.iter()
+        .enumerate()
+        .map(|(i, indicator)| RiskIndicator {
+            id: format!("tx_{}_{}", assessment.tx_hash, i),
+            category: indicator.category.clone(),
+            subcategory: "transaction".to_string(),
+            score: indicator.risk_score,
+            confidence: 0.85,
+            description: indicator.description.clone(),
+            evidence: vec![Evidence {
+                source: DataSource::TrmLabs,
+                raw_data: serde_json::to_value(indicator).unwrap_or_default(),
+                extracted_info: HashMap::from([
+                    ("tx_hash".to_string(), assessment.tx_hash.clone()),
+                ]),
+                timestamp: assessment.timestamp,
+            }],
+            first_seen: now,
+            last_seen: now,
+            transaction_hashes: vec![assessment.tx_hash.clone()],
+            addresses_involved: indicator.counterparty.iter().cloned().collect(),
+            metadata: HashMap::new(),
+        })
+        .collect()
+}
+
+fn trm_category(category: &str) -> RiskCategory {
+    match category {
+        "sanctions" => RiskCategory::Sanctions,
+        "illicit_activity" => RiskCategory::IllicitActivity,
+        "high_risk_service" => RiskCategory::HighRiskService,
+        "behavioral" => RiskCategory::BehavioralAnomaly,
+        "counterparty" => RiskCategory::CounterpartyRisk,
+        "reputation" => RiskCategory::ReputationRisk,
+        _ => RiskCategory::TechnicalRisk,
+    }
+}
+
+fn severity_score(severity: &str) -> f64 {
+    match severity {
+        "critical" => 90.0,
+        "high" => 70.0,
+        "medium" => 50.0,
+        "low" => 30.0,
+        _ => 10.0,
+    }
+}
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
 use solana_sdk::pubkey::Pubkey;
 use std::collections::HashMap;
+use std::str::FromStr;
 use chrono::{DateTime, Utc};
  
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,6 +17,27 @@ pub struct WalletRiskProfile {
     pub data_sources: Vec<DataSource>,
     pub recommendations: Vec<RiskRecommendation>,
     pub metadata: HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub provider_outages: Vec<ProviderOutage>,
+    #[serde(default)]
+    pub fail_mode_applied: Option<FailMode>,
+    #[serde(default)]
+    pub score_overrides: Vec<ScoreOverride>,
+    #[serde(default)]
+    pub provider_disagreements: Vec<ProviderDisagreement>,
+    // Set only while evaluating policies for a single screened transaction
+    #[serde(default)]
+    pub transaction_risk: Option<TransactionRisk>,
+    #[serde(default)]
+    pub entity: Option<EntityAttribution>,
+    // Off-chain addresses screened as part of this wallet
+    #[serde(default)]
+    pub linked_addresses: Vec<ChainAddress>,
+    #[serde(default)]
+    pub jurisdiction: Option<JurisdictionAssessment>,
+    // Wallets on-chain heuristics tie to the same operator
+    #[serde(default)]
+    pub sybil_cluster: Option<SybilCluster>,
 }
  
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -23,6 +47,8 @@ pub enum RiskLevel {
     Medium,
     High,
     Critical,
+    // Required data was unavailable and the fail mode chose not to guess
+    Unknown,
 }
  
 impl RiskLevel {
@@ -33,6 +59,19 @@ impl RiskLevel {
             RiskLevel::Medium => "medium",
             RiskLevel::High => "high",
             RiskLevel::Critical => "critical",
+            RiskLevel::Unknown => "unknown",
+        }
+    }
+    
+    // Ordering used when raising or comparing levels; Unknown sorts lowest
+    pub fn rank(&self) -> u8 {
+        match self {
+            RiskLevel::Unknown => 0,
+            RiskLevel::Safe => 1,
+            RiskLevel::Low => 2,
+            RiskLevel::Medium => 3,
+            RiskLevel::High => 4,
+            RiskLevel::Critical => 5,
         }
     }
     
@@ -61,7 +100,7 @@ pub struct RiskIndicator {
     pub metadata: HashMap<String, serde_json::Value>,
 }
  
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum RiskCategory {
     Sanctions,
     IllicitActivity,
@@ -70,6 +109,7 @@ pub enum RiskCategory {
     CounterpartyRisk,
     ReputationRisk,
     TechnicalRisk,
+    GeographicRisk,
 }
  
 impl RiskCategory {
@@ -82,6 +122,7 @@ impl RiskCategory {
             RiskCategory::CounterpartyRisk => 1.2,
             RiskCategory::ReputationRisk => 1.1,
             RiskCategory::TechnicalRisk => 1.0,
+            RiskCategory::GeographicRisk => 1.2,
         }
     }
     
@@ -94,6 +135,7 @@ impl RiskCategory {
             RiskCategory::CounterpartyRisk => "Counterparty exposure risk",
             RiskCategory::ReputationRisk => "Reputational damage risk",
             RiskCategory::TechnicalRisk => "Technical security risks",
+            RiskCategory::GeographicRisk => "Attested jurisdiction risk",
         }
     }
 }
@@ -106,7 +148,7 @@ pub struct Evidence {
     pub timestamp: DateTime<Utc>,
 }
  
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum DataSource {
     TrmLabs,
     Chainalysis,
@@ -116,6 +158,350 @@ pub enum DataSource {
     Manual,
 }
  
+impl DataSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DataSource::TrmLabs => "trm_labs",
+            DataSource::Chainalysis => "chainalysis",
+            DataSource::Crystal => "crystal",
+            DataSource::OnChain => "on_chain",
+            DataSource::Internal => "internal",
+            DataSource::Manual => "manual",
+        }
+    }
+}
+ 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderOutage {
+    pub source: DataSource,
+    pub error: String,
+    pub detected_at: DateTime<Utc>,
+    // Set when the failed call was for a linked address rather than the wallet
+    #[serde(default)]
+    pub linked_address: Option<ChainAddress>,
+}
+ 
+impl ProviderOutage {
+    pub fn is_linked(&self) -> bool {
+        self.linked_address.is_some()
+    }
+}
+ 
+// Records a post-scoring rule that changed the profile's level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreOverride {
+    pub rule_id: String,
+    pub indicator_id: String,
+    pub previous_level: RiskLevel,
+    pub previous_score: f64,
+    pub new_level: RiskLevel,
+    pub new_score: f64,
+}
+ 
+// Entity or cluster a provider attributes the wallet to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityAttribution {
+    // Cluster id when the provider reports one, otherwise its entity id
+    pub entity_id: String,
+    pub name: Option<String>,
+    pub entity_type: String,
+    pub category: String,
+    pub risk_score: f64,
+    // Other addresses the provider places in the same entity
+    #[serde(default)]
+    pub addresses: Vec<String>,
+    pub source: DataSource,
+}
+ 
+// Risk rating a jurisdiction rule pack gives a country
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JurisdictionRating {
+    Standard,
+    // Under increased monitoring, e.g. the FATF grey list
+    Monitored,
+    // Subject to a call for action, e.g. the FATF black list
+    HighRisk,
+    Prohibited,
+}
+ 
+impl JurisdictionRating {
+    // Indicator score when the rule doesn't set one
+    pub fn default_score(&self) -> f64 {
+        match self {
+            JurisdictionRating::Standard => 0.0,
+            JurisdictionRating::Monitored => 45.0,
+            JurisdictionRating::HighRisk => 80.0,
+            JurisdictionRating::Prohibited => 95.0,
+        }
+    }
+    
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JurisdictionRating::Standard => "standard",
+            JurisdictionRating::Monitored => "monitored",
+            JurisdictionRating::HighRisk => "high_risk",
+            JurisdictionRating::Prohibited => "prohibited",
+        }
+    }
+}
+ 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DueDiligenceLevel {
+    Simplified,
+    Standard,
+    Enhanced,
+}
+ 
+impl DueDiligenceLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DueDiligenceLevel::Simplified => "simplified",
+            DueDiligenceLevel::Standard => "standard",
+            DueDiligenceLevel::Enhanced => "enhanced",
+        }
+    }
+}
+ 
+// Rule pack outcome for the jurisdiction on the wallet's attestation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JurisdictionAssessment {
+    pub country: String,
+    pub rating: JurisdictionRating,
+    pub due_diligence: DueDiligenceLevel,
+    pub score: f64,
+    // Lists behind the rating, e.g. "fatf_grey"
+    pub lists: Vec<String>,
+    pub pack_version: String,
+}
+ 
+// On-chain evidence that two wallets are run by the same operator
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ClusterSignal {
+    // First SOL came from the same address
+    SharedFundingSource,
+    // Repeatedly active within seconds of each other
+    SynchronizedActivity,
+    // Transactions paid for by the same address
+    CommonFeePayer,
+}
+ 
+impl ClusterSignal {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClusterSignal::SharedFundingSource => "shared_funding_source",
+            ClusterSignal::SynchronizedActivity => "synchronized_activity",
+            ClusterSignal::CommonFeePayer => "common_fee_payer",
+        }
+    }
+}
+ 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SybilCluster {
+    pub cluster_id: String,
+    // Certainty the members share an operator, 0.0-1.0
+    pub confidence: f64,
+    pub members: Vec<String>,
+    pub signals: Vec<ClusterSignal>,
+    pub detected_at: DateTime<Utc>,
+}
+ 
+// Providers that both responded but materially disagree about an exposure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderDisagreement {
+    pub key: String,
+    pub category: RiskCategory,
+    pub scores: Vec<(DataSource, f64)>,
+    // Responding sources covering the category that reported nothing
+    pub silent_sources: Vec<DataSource>,
+    pub reason: String,
+}
+ 
+// What to do when a required data source is unavailable
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FailMode {
+    KeepPrevious,
+    MarkUnknown,
+    TreatAsHigh,
+}
+ 
+impl std::str::FromStr for FailMode {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "keep_previous" => Ok(FailMode::KeepPrevious),
+            "mark_unknown" => Ok(FailMode::MarkUnknown),
+            "treat_as_high" => Ok(FailMode::TreatAsHigh),
+            other => Err(format!("Unknown fail mode: {}", other)),
+        }
+    }
+}
+ 
+impl std::str::FromStr for DataSource {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "trm" | "trm_labs" => Ok(DataSource::TrmLabs),
+            "chainalysis" => Ok(DataSource::Chainalysis),
+            "crystal" => Ok(DataSource::Crystal),
+            "on_chain" => Ok(DataSource::OnChain),
+            "internal" => Ok(DataSource::Internal),
+            "manual" => Ok(DataSource::Manual),
+            other => Err(format!("Unknown data source: {}", other)),
+        }
+    }
+}
+ 
+// Chains whose addresses the risk engine can screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Chain {
+    Solana,
+    Ethereum,
+    Tron,
+}
+ 
+impl Chain {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Chain::Solana => "solana",
+            Chain::Ethereum => "ethereum",
+            Chain::Tron => "tron",
+        }
+    }
+    
+    pub fn all() -> Vec<Chain> {
+        vec![Chain::Solana, Chain::Ethereum, Chain::Tron]
+    }
+}
+ 
+impl std::str::FromStr for Chain {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "solana" | "sol" => Ok(Chain::Solana),
+            "ethereum" | "eth" => Ok(Chain::Ethereum),
+            "tron" | "trx" => Ok(Chain::Tron),
+            other => Err(format!("Unknown chain: {}", other)),
+        }
+    }
+}
+ 
+// An address validated for its chain. Serialized as "chain:address".
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ChainAddress {
+    chain: Chain,
+    address: String,
+}
+ 
+impl ChainAddress {
+    pub fn new(chain: Chain, address: &str) -> Result<Self, String> {
+        let address = address.trim();
+        let address = match chain {
+            Chain::Solana => {
+                Pubkey::from_str(address)
+                    .map_err(|e| format!("Invalid Solana address {}: {}", address, e))?;
+                address.to_string()
+            }
+            Chain::Ethereum => Self::validate_ethereum(address)?,
+            Chain::Tron => Self::validate_tron(address)?,
+        };
+        
+        Ok(Self { chain, address })
+    }
+    
+    pub fn solana(address: &str) -> Result<Self, String> {
+        Self::new(Chain::Solana, address)
+    }
+    
+    pub fn chain(&self) -> Chain {
+        self.chain
+    }
+    
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+    
+    // 0x + 40 hex digits; mixed case must carry a valid EIP-55 checksum.
+    // Stored lowercase so lookups don't depend on casing.
+    fn validate_ethereum(address: &str) -> Result<String, String> {
+        let hex_part = address.strip_prefix("0x")
+            .filter(|h| h.len() == 40 && h.chars().all(|c| c.is_ascii_hexdigit()))
+            .ok_or_else(|| format!("Invalid Ethereum address {}", address))?;
+        
+        let lower = hex_part.to_ascii_lowercase();
+        let mixed_case = hex_part != lower && hex_part != hex_part.to_ascii_uppercase();
+        if mixed_case {
+            let hash = Keccak256::digest(lower.as_bytes());
+            let checksum_ok = hex_part.chars().enumerate().all(|(i, c)| {
+                let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+                !c.is_ascii_alphabetic() || c.is_ascii_uppercase() == (nibble >= 8)
+            });
+            if !checksum_ok {
+                return Err(format!("Invalid EIP-55 checksum for Ethereum address {}", address));
+            }
+        }
+        
+        Ok(format!("0x{}", lower))
+    }
+    
+    // Base58Check, 21-byte payload starting with the 0x41 mainnet prefix
+    fn validate_tron(address: &str) -> Result<String, String> {
+        let bytes = bs58::decode(address).into_vec()
+            .map_err(|e| format!("Invalid Tron address {}: {}", address, e))?;
+        if bytes.len() != 25 || bytes[0] != 0x41 {
+            return Err(format!("Invalid Tron address {}", address));
+        }
+        
+        let (payload, checksum) = bytes.split_at(21);
+        let digest = Sha256::digest(Sha256::digest(payload));
+        if &digest[..4] != checksum {
+            return Err(format!("Invalid checksum for Tron address {}", address));
+        }
+        
+        Ok(address.to_string())
+    }
+}
+ 
+impl std::fmt::Display for ChainAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.chain.as_str(), self.address)
+    }
+}
+ 
+impl std::str::FromStr for ChainAddress {
+    type Err = String;
+    
+    // "ethereum:0x..." or a bare Solana address
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            Some((chain, address)) => Self::new(chain.parse()?, address),
+            None => Self::solana(s),
+        }
+    }
+}
+ 
+impl TryFrom<String> for ChainAddress {
+    type Error = String;
+    
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+ 
+impl From<ChainAddress> for String {
+    fn from(address: ChainAddress) -> Self {
+        address.to_string()
+    }
+}
+ 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RiskRecommendation {
     pub action: RecommendedAction,
@@ -152,6 +538,13 @@ pub struct TransactionRiskAssessment {
     pub verified: bool,
 }
  
+// A screened transaction combined with the wallet's history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionRisk {
+    pub assessment: TransactionRiskAssessment,
+    pub combined_score: f64,
+}
+ 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionIndicator {
     pub category: RiskCategory,
@@ -4,6 +4,11 @@ pub struct AttestationVerifier {
     program_id: Pubkey,
     sas_registry: Pubkey,
     cache: AttestationCache,
+    cluster_directory: Option<Arc<dyn ClusterDirectory>>,
+    // Wallet holding each (allocation, cluster) claim. Held in memory only: claims
+    // reset on restart and aren't shared between verifier instances, so a
+    // deployment running several needs its own shared claim store.
+    allocations: Arc<RwLock<HashMap<(String, String), Pubkey>>>,
 }
  
 impl AttestationVerifier {
@@ -19,9 +24,16 @@ impl AttestationVerifier {
             program_id: sas_program_id(), // Your program ID
             sas_registry: sas_registry_address(),
             cache: AttestationCache::new(),
+            cluster_directory: None,
+            allocations: Arc::new(RwLock::new(HashMap::new())),
         }
     }
     
+    pub fn with_cluster_directory(mut self, directory: Arc<dyn ClusterDirectory>) -> Self {
+        self.cluster_directory = Some(directory);
+        self
+    }
+    
     // Core verification function
     pub async fn verify_wallet_access(
         &self,
@@ -49,6 +61,66 @@ impl AttestationVerifier {
         Ok(result)
     }
     
+    // Verifies the wallet and records its claim on the allocation (a mint,
+    // a sale tranche). With one_allocation_per_cluster set, a wallet is
+    // refused when another wallet in its sybil cluster already claimed;
+    // wallets outside any cluster count as their own.
+    pub async fn claim_allocation(
+        &self,
+        wallet: &Pubkey,
+        allocation_id: &str,
+        requirements: &AccessRequirements,
+    ) -> Result<VerificationResult, VerificationError> {
+        let mut result = self.verify_wallet_access(wallet, requirements).await?;
+        if !result.allowed || !requirements.one_allocation_per_cluster {
+            return Ok(result);
+        }
+        
+        let cluster_id = self.cluster_key(wallet).await;
+        let mut allocations = self.allocations.write().await;
+        let key = (allocation_id.to_string(), cluster_id.clone());
+        
+        match allocations.get(&key) {
+            Some(holder) if holder != wallet => {
+                result.allowed = false;
+                result.reason = Some(format!(
+                    "Cluster {} already claimed allocation {} with wallet {}",
+                    cluster_id, allocation_id, holder
+                ));
+                result.missing_requirements.push("one_allocation_per_cluster".to_string());
+            }
+            Some(_) => {}
+            None => {
+                allocations.insert(key, *wallet);
+            }
+        }
+        
+        Ok(result)
+    }
+    
+    // Frees the wallet's claim, e.g. when its mint transaction failed
+    pub async fn release_allocation(&self, wallet: &Pubkey, allocation_id: &str) -> bool {
+        let cluster_id = self.cluster_key(wallet).await;
+        let mut allocations = self.allocations.write().await;
+        let key = (allocation_id.to_string(), cluster_id);
+        
+        if allocations.get(&key) == Some(wallet) {
+            allocations.remove(&key);
+            true
+        } else {
+            false
+        }
+    }
+    
+    async fn cluster_key(&self, wallet: &Pubkey) -> String {
+        match &self.cluster_directory {
+            Some(directory) => directory.cluster_for(wallet).await
+                .map(|m| m.cluster_id)
+                .unwrap_or_else(|| wallet.to_string()),
+            None => wallet.to_string(),
+        }
+    }
+    
     // Check if wallet has ANY valid KYC attestation
     pub async fn has_valid_kyc(
         &self,
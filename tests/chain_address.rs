@@ -0,0 +1,72 @@
+use solana_kyc_compliance_sdk::risk_engine::types::{Chain, ChainAddress};
+
+const SOLANA: &str = "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU";
+// EIP-55 reference vector
+const ETHEREUM: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+// USDT contract on Tron
+const TRON: &str = "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t";
+
+#[test]
+fn solana_addresses_must_be_valid_pubkeys() {
+    let address = ChainAddress::solana(SOLANA).unwrap();
+    assert_eq!(address.chain(), Chain::Solana);
+    assert_eq!(address.address(), SOLANA);
+
+    assert!(ChainAddress::solana(" 7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU ").is_ok());
+    assert!(ChainAddress::solana("not-a-pubkey").is_err());
+    assert!(ChainAddress::solana("").is_err());
+}
+
+#[test]
+fn ethereum_addresses_are_checksummed_and_stored_lowercase() {
+    let address = ChainAddress::new(Chain::Ethereum, ETHEREUM).unwrap();
+    assert_eq!(address.address(), ETHEREUM.to_lowercase());
+
+    // Single-case addresses carry no checksum
+    assert!(ChainAddress::new(Chain::Ethereum, &ETHEREUM.to_lowercase()).is_ok());
+    assert!(ChainAddress::new(Chain::Ethereum, "0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED").is_ok());
+
+    // One letter with the wrong case
+    assert!(ChainAddress::new(Chain::Ethereum, "0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_err());
+    assert!(ChainAddress::new(Chain::Ethereum, "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_err());
+    assert!(ChainAddress::new(Chain::Ethereum, "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA").is_err());
+    assert!(ChainAddress::new(Chain::Ethereum, "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeg").is_err());
+}
+
+#[test]
+fn tron_addresses_need_mainnet_prefix_and_checksum() {
+    let address = ChainAddress::new(Chain::Tron, TRON).unwrap();
+    assert_eq!(address.address(), TRON);
+
+    assert!(ChainAddress::new(Chain::Tron, "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6u").is_err());
+    // A valid Solana address isn't a Tron address
+    assert!(ChainAddress::new(Chain::Tron, SOLANA).is_err());
+    assert!(ChainAddress::new(Chain::Tron, "T0000000000000000000000000000000").is_err());
+}
+
+#[test]
+fn addresses_parse_with_or_without_chain_prefix() {
+    let bare: ChainAddress = SOLANA.parse().unwrap();
+    assert_eq!(bare.chain(), Chain::Solana);
+
+    let ethereum: ChainAddress = format!("eth:{}", ETHEREUM).parse().unwrap();
+    assert_eq!(ethereum.chain(), Chain::Ethereum);
+    assert_eq!(ethereum.to_string(), format!("ethereum:{}", ETHEREUM.to_lowercase()));
+
+    let tron: ChainAddress = format!("TRON:{}", TRON).parse().unwrap();
+    assert_eq!(tron.chain(), Chain::Tron);
+
+    assert!(format!("bitcoin:{}", SOLANA).parse::<ChainAddress>().is_err());
+    assert!(format!("ethereum:{}", SOLANA).parse::<ChainAddress>().is_err());
+}
+
+#[test]
+fn serde_round_trips_through_the_prefixed_form() {
+    let address = ChainAddress::new(Chain::Tron, TRON).unwrap();
+
+    let json = serde_json::to_string(&address).unwrap();
+    assert_eq!(json, format!("\"tron:{}\"", TRON));
+    assert_eq!(serde_json::from_str::<ChainAddress>(&json).unwrap(), address);
+
+    assert!(serde_json::from_str::<ChainAddress>("\"ethereum:0x1234\"").is_err());
+}
@@ -0,0 +1,241 @@
+use solana_kyc_compliance_sdk::risk_engine::config::ChainalysisConfig;
+use solana_kyc_compliance_sdk::risk_engine::types::{Chain, ChainAddress};
+use solana_kyc_compliance_sdk::risk_engine::providers::{
+    ChainalysisClient, ChainalysisError, ChainalysisTransfer, RiskDataProvider, TransferDirection,
+};
+use std::sync::Arc;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const WALLET: &str = "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU";
+const BRIDGE_SOURCE: &str = "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed";
+
+fn wallet() -> ChainAddress {
+    ChainAddress::solana(WALLET).unwrap()
+}
+
+fn client_for(server: &MockServer, kyt_enabled: bool, sanctions_enabled: bool) -> ChainalysisClient {
+    ChainalysisClient::new(Arc::new(ChainalysisConfig {
+        api_key: "test-key".to_string(),
+        api_url: server.uri(),
+        sanctions_api_url: server.uri(),
+        timeout_secs: 2,
+        kyt_enabled,
+        sanctions_enabled,
+    }))
+    .expect("client should build")
+}
+
+#[tokio::test]
+async fn sanctioned_address_is_flagged() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/api/v1/address/{}", WALLET)))
+        .and(header("X-API-Key", "test-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "identifications": [{
+                "category": "sanctions",
+                "name": "SANCTIONS: OFAC SDN Example",
+                "description": "Example designation",
+                "url": "https://home.treasury.gov"
+            }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let screening = client_for(&server, false, true)
+        .get_address_screening(&wallet())
+        .await
+        .unwrap();
+
+    assert!(screening.is_sanctioned);
+    assert_eq!(screening.sanctions_identifications.len(), 1);
+    assert!(screening.category_scores.is_empty());
+}
+
+#[tokio::test]
+async fn kyt_rating_maps_to_category_score() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/api/v1/address/{}", WALLET)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "identifications": [] })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!("/api/kyt/v1/users/{}/withdrawaladdresses", WALLET)))
+        .and(header("Token", "test-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+            "asset": "SOL",
+            "address": WALLET,
+            "cluster": { "name": "Example Mixer", "category": "mixing" },
+            "rating": "highRisk",
+            "customAddress": null
+        }])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let screening = client_for(&server, true, true)
+        .get_address_screening(&wallet())
+        .await
+        .unwrap();
+
+    assert!(!screening.is_sanctioned);
+    assert_eq!(screening.category_scores.len(), 1);
+    assert_eq!(screening.category_scores[0].category, "high_risk_service");
+    assert_eq!(screening.category_scores[0].subcategory.as_deref(), Some("mixing"));
+    assert_eq!(screening.category_scores[0].score, 80.0);
+}
+
+#[tokio::test]
+async fn unrated_address_has_no_category_scores() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/api/kyt/v1/users/{}/withdrawaladdresses", WALLET)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+            "asset": "SOL",
+            "address": WALLET,
+            "cluster": null,
+            "rating": "unknown",
+            "customAddress": null
+        }])))
+        .mount(&server)
+        .await;
+
+    let screening = client_for(&server, true, false)
+        .get_address_screening(&wallet())
+        .await
+        .unwrap();
+
+    assert!(screening.category_scores.is_empty());
+}
+
+#[tokio::test]
+async fn low_risk_rating_produces_no_indicators() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/api/kyt/v1/users/{}/withdrawaladdresses", WALLET)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+            "asset": "SOL",
+            "address": WALLET,
+            "cluster": { "name": "Example Exchange", "category": "exchange" },
+            "rating": "lowRisk",
+            "customAddress": null
+        }])))
+        .mount(&server)
+        .await;
+
+    let screening = client_for(&server, true, false)
+        .get_address_screening(&wallet())
+        .await
+        .unwrap();
+
+    assert!(screening.category_scores.is_empty());
+    assert!(screening.to_indicators(WALLET).is_empty());
+}
+
+#[tokio::test]
+async fn linked_address_is_registered_under_its_wallet() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/api/kyt/v1/users/{}/withdrawaladdresses", WALLET)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+            "asset": "ETH",
+            "address": BRIDGE_SOURCE,
+            "cluster": { "name": "Example Mixer", "category": "mixing" },
+            "rating": "highRisk",
+            "customAddress": null
+        }])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client_for(&server, true, false);
+    let linked = ChainAddress::new(Chain::Ethereum, BRIDGE_SOURCE).unwrap();
+    RiskDataProvider::link_address(&client, &linked, WALLET);
+
+    let screening = client.get_address_screening(&linked).await.unwrap();
+    let indicators = screening.to_indicators(WALLET);
+
+    assert_eq!(indicators.len(), 1);
+    assert_eq!(indicators[0].id, format!("chainalysis_{}_{}_high_risk_service", WALLET, BRIDGE_SOURCE));
+}
+
+#[tokio::test]
+async fn transfer_registration_returns_external_id() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/api/kyt/v2/users/{}/transfers", WALLET)))
+        .respond_with(ResponseTemplate::new(202).set_body_json(serde_json::json!({
+            "updatedAt": null,
+            "asset": "SOL",
+            "network": "Solana",
+            "transferReference": "5sig:counterparty",
+            "direction": "received",
+            "externalId": "2f4b5e0a-0000-0000-0000-000000000000"
+        })))
+        .mount(&server)
+        .await;
+
+    let transfer = ChainalysisTransfer::solana("5sig", "counterparty", TransferDirection::Received);
+    let registration = client_for(&server, true, false)
+        .register_transfer(WALLET, &transfer)
+        .await
+        .unwrap();
+
+    assert_eq!(registration.transfer_reference, "5sig:counterparty");
+    assert_eq!(registration.external_id, "2f4b5e0a-0000-0000-0000-000000000000");
+}
+
+#[tokio::test]
+async fn server_error_is_reported_with_status() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/api/v1/address/{}", WALLET)))
+        .respond_with(ResponseTemplate::new(503).set_body_string("maintenance"))
+        .mount(&server)
+        .await;
+
+    let err = client_for(&server, false, true)
+        .get_address_screening(&wallet())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ChainalysisError::StatusError(503, ref msg) if msg.contains("maintenance")));
+}
+
+#[tokio::test]
+async fn slow_response_times_out() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/api/v1/address/{}", WALLET)))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "identifications": [] }))
+                .set_delay(std::time::Duration::from_secs(5)),
+        )
+        .mount(&server)
+        .await;
+
+    let err = client_for(&server, false, true)
+        .get_address_screening(&wallet())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ChainalysisError::TimeoutError | ChainalysisError::NetworkError(_)));
+}
+
+#[tokio::test]
+async fn empty_api_key_is_rejected() {
+    let result = ChainalysisClient::new(Arc::new(ChainalysisConfig {
+        api_key: String::new(),
+        api_url: "http://127.0.0.1:1".to_string(),
+        sanctions_api_url: "http://127.0.0.1:1".to_string(),
+        timeout_secs: 1,
+        kyt_enabled: true,
+        sanctions_enabled: true,
+    }));
+
+    assert!(matches!(result, Err(ChainalysisError::ConfigError(_))));
+}
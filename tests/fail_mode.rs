@@ -0,0 +1,53 @@
+use solana_kyc_compliance_sdk::risk_engine::types::FailMode;
+use solana_kyc_compliance_sdk::risk_engine::{RiskEngineConfig, RiskMonitoringService};
+use solana_sdk::pubkey::Pubkey;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const WALLET: &str = "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU";
+
+// Chainalysis sanctions screening as the only provider, without retries
+async fn service_for(server: &MockServer) -> RiskMonitoringService {
+    std::env::set_var("TRM_API_KEY", "test-key");
+    std::env::set_var("TRM_ENABLED", "false");
+    std::env::set_var("CHAINALYSIS_API_KEY", "test-key");
+    std::env::set_var("CHAINALYSIS_KYT_ENABLED", "false");
+    std::env::set_var("CHAINALYSIS_SANCTIONS_API_URL", server.uri());
+    std::env::set_var("CHAINALYSIS_TIMEOUT", "2");
+    std::env::set_var("PROVIDER_MAX_RETRIES", "0");
+    std::env::set_var("SOURCE_FAIL_MODE", "keep_previous");
+    std::env::set_var("RISK_ENGINE_WALLET", WALLET);
+
+    let config = RiskEngineConfig::from_env().expect("config should load");
+    let service = RiskMonitoringService::new(config).await.expect("service should start");
+    service.register_wallet(WALLET.to_string(), Pubkey::new_unique()).await.unwrap();
+    service
+}
+
+#[tokio::test]
+async fn forced_check_during_outage_keeps_previous_profile() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/api/v1/address/{}", WALLET)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "identifications": [] })))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/api/v1/address/{}", WALLET)))
+        .respond_with(ResponseTemplate::new(503).set_body_string("maintenance"))
+        .mount(&server)
+        .await;
+
+    let service = service_for(&server).await;
+    let first = service.force_risk_check(WALLET).await.unwrap();
+    assert!(first.fail_mode_applied.is_none());
+
+    // The forced check clears the risk cache before the source goes down
+    let second = service.force_risk_check(WALLET).await.unwrap();
+
+    assert_eq!(second.fail_mode_applied, Some(FailMode::KeepPrevious));
+    assert_eq!(second.risk_level, first.risk_level);
+    assert_eq!(second.provider_outages.len(), 1);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
@@ -0,0 +1,178 @@
+use solana_kyc_compliance_sdk::risk_engine::config::ScoringConfig;
+use solana_kyc_compliance_sdk::risk_engine::scoring::{feature_index, GradientBoostedStrategy, FEATURE_NAMES};
+use serde_json::json;
+
+fn config(objective: &str, base_score: f64) -> ScoringConfig {
+    ScoringConfig {
+        model_path: None,
+        model_objective: objective.to_string(),
+        model_base_score: base_score,
+        weights_path: None,
+        calibration_holdout: 0.2,
+    }
+}
+
+// Feature vector with the named values set and everything else zero
+fn features(values: &[(&str, f64)]) -> Vec<f64> {
+    let mut features = vec![0.0; FEATURE_NAMES.len()];
+    for (name, value) in values {
+        features[feature_index(name).expect("known feature")] = *value;
+    }
+    features
+}
+
+// Two trees: sanctions < 50 (NaN goes right), then indicator_count < 3 (NaN goes left)
+fn xgboost_dump() -> String {
+    json!([
+        {
+            "nodeid": 0, "depth": 0, "split": "sanctions_max_score", "split_condition": 50.0,
+            "yes": 1, "no": 2, "missing": 2,
+            "children": [
+                { "nodeid": 1, "leaf": 10.0 },
+                { "nodeid": 2, "leaf": 60.0 }
+            ]
+        },
+        {
+            "nodeid": 0, "depth": 0, "split": "indicator_count", "split_condition": 3.0,
+            "yes": 2, "no": 1, "missing": 2,
+            "children": [
+                { "nodeid": 2, "leaf": 0.0 },
+                { "nodeid": 1, "leaf": 20.0 }
+            ]
+        }
+    ])
+    .to_string()
+}
+
+// LightGBM indexes features by its own list, not the engine's order
+fn lightgbm_dump(objective: &str) -> serde_json::Value {
+    json!({
+        "name": "tree",
+        "num_class": 1,
+        "objective": objective,
+        "feature_names": ["max_indicator_score", "sanctions_max_score"],
+        "tree_info": [
+            {
+                "tree_index": 0,
+                "tree_structure": {
+                    "split_index": 0, "split_feature": 1, "threshold": 50.0,
+                    "decision_type": "<=", "default_left": false, "missing_type": "NaN",
+                    "left_child": { "leaf_index": 0, "leaf_value": 10.0 },
+                    "right_child": { "leaf_index": 1, "leaf_value": 60.0 }
+                }
+            },
+            {
+                "tree_index": 1,
+                "tree_structure": {
+                    "split_index": 0, "split_feature": 0, "threshold": 10.0,
+                    "decision_type": "<=", "default_left": false, "missing_type": "Zero",
+                    "left_child": { "leaf_index": 0, "leaf_value": 0.0 },
+                    "right_child": { "leaf_index": 1, "leaf_value": 20.0 }
+                }
+            }
+        ]
+    })
+}
+
+#[test]
+fn xgboost_splits_strictly_below_the_threshold() {
+    let model = GradientBoostedStrategy::parse(&xgboost_dump(), &config("reg:squarederror", 0.0)).unwrap();
+
+    assert_eq!(model.evaluate(&features(&[("sanctions_max_score", 49.9), ("indicator_count", 5.0)])), 30.0);
+    assert_eq!(model.evaluate(&features(&[("sanctions_max_score", 50.0), ("indicator_count", 5.0)])), 80.0);
+    assert_eq!(model.evaluate(&features(&[("sanctions_max_score", 50.0), ("indicator_count", 3.0)])), 80.0);
+    assert_eq!(model.evaluate(&features(&[("sanctions_max_score", 50.0), ("indicator_count", 2.0)])), 60.0);
+}
+
+#[test]
+fn xgboost_routes_missing_values_to_the_missing_branch() {
+    let model = GradientBoostedStrategy::parse(&xgboost_dump(), &config("reg:squarederror", 0.0)).unwrap();
+
+    // NaN sanctions follows "missing" to the right; NaN count follows it to the left
+    let nan = model.evaluate(&features(&[("sanctions_max_score", f64::NAN), ("indicator_count", f64::NAN)]));
+    assert_eq!(nan, 60.0);
+}
+
+#[test]
+fn xgboost_logistic_margin_starts_from_base_score() {
+    let dump = json!([{ "nodeid": 0, "leaf": 0.0 }]).to_string();
+
+    let model = GradientBoostedStrategy::parse(&dump, &config("binary:logistic", 0.5)).unwrap();
+    assert!((model.evaluate(&features(&[])) - 50.0).abs() < 1e-9);
+
+    let model = GradientBoostedStrategy::parse(&dump, &config("binary:logistic", 0.2)).unwrap();
+    assert!((model.evaluate(&features(&[])) - 20.0).abs() < 1e-9);
+
+    assert!(GradientBoostedStrategy::parse(&dump, &config("binary:logistic", 1.0)).is_err());
+    assert!(GradientBoostedStrategy::parse(&dump, &config("multi:softmax", 0.5)).is_err());
+}
+
+#[test]
+fn lightgbm_splits_at_or_below_the_threshold() {
+    let dump = lightgbm_dump("regression").to_string();
+    let model = GradientBoostedStrategy::parse(&dump, &config("reg:squarederror", 0.0)).unwrap();
+
+    assert_eq!(model.evaluate(&features(&[("sanctions_max_score", 50.0), ("max_indicator_score", 10.0)])), 10.0);
+    assert_eq!(model.evaluate(&features(&[("sanctions_max_score", 50.1), ("max_indicator_score", 10.1)])), 80.0);
+}
+
+#[test]
+fn lightgbm_routes_missing_values_by_missing_type() {
+    let dump = lightgbm_dump("regression").to_string();
+    let model = GradientBoostedStrategy::parse(&dump, &config("reg:squarederror", 0.0)).unwrap();
+
+    // NaN follows default_left (right); with missing type Zero so does 0.0
+    assert_eq!(model.evaluate(&features(&[("sanctions_max_score", f64::NAN), ("max_indicator_score", 0.0)])), 80.0);
+    assert_eq!(model.evaluate(&features(&[("sanctions_max_score", 0.0), ("max_indicator_score", 5.0)])), 10.0);
+
+    // Without a missing type NaN is compared as 0.0
+    let mut dump = lightgbm_dump("regression");
+    dump["tree_info"][0]["tree_structure"]["missing_type"] = json!("None");
+    let model = GradientBoostedStrategy::parse(&dump.to_string(), &config("reg:squarederror", 0.0)).unwrap();
+    assert_eq!(model.evaluate(&features(&[("sanctions_max_score", f64::NAN), ("max_indicator_score", 5.0)])), 10.0);
+}
+
+#[test]
+fn lightgbm_binary_objective_is_logistic() {
+    let mut dump = lightgbm_dump("binary sigmoid:1");
+    dump["tree_info"] = json!([{ "tree_index": 0, "tree_structure": { "leaf_index": 0, "leaf_value": 0.0 } }]);
+
+    let model = GradientBoostedStrategy::parse(&dump.to_string(), &config("reg:squarederror", 0.0)).unwrap();
+
+    assert!((model.evaluate(&features(&[])) - 50.0).abs() < 1e-9);
+}
+
+#[test]
+fn unknown_feature_names_are_rejected_at_load() {
+    let dump = xgboost_dump().replace("indicator_count", "wallet_age_days");
+    let err = GradientBoostedStrategy::parse(&dump, &config("reg:squarederror", 0.0)).unwrap_err();
+    assert!(err.contains("wallet_age_days"), "{}", err);
+
+    let mut dump = lightgbm_dump("regression");
+    dump["feature_names"] = json!(["max_indicator_score", "Column_1"]);
+    let err = GradientBoostedStrategy::parse(&dump.to_string(), &config("reg:squarederror", 0.0)).unwrap_err();
+    assert!(err.contains("Column_1"), "{}", err);
+}
+
+#[test]
+fn malformed_models_are_rejected_at_load() {
+    let regression = config("reg:squarederror", 0.0);
+
+    // Branch to a node that isn't a child
+    let dump = json!([{
+        "nodeid": 0, "split": "sanctions_max_score", "split_condition": 50.0,
+        "yes": 1, "no": 3, "missing": 1,
+        "children": [{ "nodeid": 1, "leaf": 1.0 }, { "nodeid": 2, "leaf": 2.0 }]
+    }]);
+    assert!(GradientBoostedStrategy::parse(&dump.to_string(), &regression).is_err());
+
+    let mut dump = lightgbm_dump("regression");
+    dump["tree_info"][0]["tree_structure"]["decision_type"] = json!("==");
+    assert!(GradientBoostedStrategy::parse(&dump.to_string(), &regression).is_err());
+
+    let mut dump = lightgbm_dump("multiclass");
+    dump["num_class"] = json!(3);
+    assert!(GradientBoostedStrategy::parse(&dump.to_string(), &regression).is_err());
+
+    assert!(GradientBoostedStrategy::parse("{}", &regression).is_err());
+}
@@ -0,0 +1,176 @@
+use chrono::{Duration, Utc};
+use solana_kyc_compliance_sdk::risk_engine::scoring::IndicatorReconciler;
+use solana_kyc_compliance_sdk::risk_engine::types::{DataSource, Evidence, RiskCategory, RiskIndicator};
+use std::collections::HashMap;
+
+const WALLET: &str = "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU";
+const COUNTERPARTY: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+
+fn indicator(id: &str, source: DataSource, category: RiskCategory, subcategory: &str, score: f64, confidence: f64) -> RiskIndicator {
+    let now = Utc::now();
+    RiskIndicator {
+        id: id.to_string(),
+        category,
+        subcategory: subcategory.to_string(),
+        score,
+        confidence,
+        description: format!("{} exposure", subcategory),
+        evidence: vec![Evidence {
+            source,
+            raw_data: serde_json::Value::Null,
+            extracted_info: HashMap::new(),
+            timestamp: now,
+        }],
+        first_seen: now,
+        last_seen: now,
+        transaction_hashes: Vec::new(),
+        addresses_involved: vec![WALLET.to_string()],
+        metadata: HashMap::new(),
+    }
+}
+
+#[test]
+fn provider_aliases_merge_into_one_indicator() {
+    let indicators = vec![
+        indicator("chainalysis", DataSource::Chainalysis, RiskCategory::Sanctions, "OFAC", 100.0, 0.9),
+        indicator("sdn", DataSource::Internal, RiskCategory::Sanctions, "OFAC SDN", 100.0, 1.0),
+        indicator("trm", DataSource::TrmLabs, RiskCategory::Sanctions, "Sanctioned Entity", 95.0, 0.8),
+    ];
+
+    let (merged, disagreements) = IndicatorReconciler::new().reconcile(indicators, &[]);
+
+    assert_eq!(merged.len(), 1);
+    assert_eq!(merged[0].evidence.len(), 3);
+    assert_eq!(merged[0].metadata["merged_from"], serde_json::json!(["chainalysis", "sdn", "trm"]));
+    assert!(disagreements.is_empty());
+}
+
+#[test]
+fn unrelated_subcategories_stay_separate() {
+    let indicators = vec![
+        indicator("mixer", DataSource::TrmLabs, RiskCategory::IllicitActivity, "Tumbler", 80.0, 0.8),
+        indicator("darknet", DataSource::Chainalysis, RiskCategory::IllicitActivity, "Darknet Market", 80.0, 0.8),
+        indicator("other", DataSource::Chainalysis, RiskCategory::IllicitActivity, "Mixing Service", 80.0, 0.8),
+    ];
+
+    let (merged, _) = IndicatorReconciler::new().reconcile(indicators, &[]);
+
+    // Tumbler and mixing service are the same exposure
+    assert_eq!(merged.len(), 2);
+}
+
+#[test]
+fn same_exposure_through_different_counterparties_merges() {
+    let mut other = indicator("b", DataSource::TrmLabs, RiskCategory::IllicitActivity, "mixer", 80.0, 0.8);
+    other.addresses_involved = vec![COUNTERPARTY.to_string()];
+    let indicators = vec![
+        indicator("a", DataSource::Chainalysis, RiskCategory::IllicitActivity, "mixer", 80.0, 0.8),
+        other,
+    ];
+
+    let (merged, _) = IndicatorReconciler::new().reconcile(indicators, &[]);
+
+    assert_eq!(merged.len(), 1);
+    assert_eq!(merged[0].addresses_involved, vec![WALLET.to_string(), COUNTERPARTY.to_string()]);
+}
+
+#[test]
+fn linked_address_findings_stay_separate() {
+    let mut linked = indicator("b", DataSource::TrmLabs, RiskCategory::IllicitActivity, "mixer", 80.0, 0.8);
+    linked.metadata.insert("linked_address".to_string(), serde_json::json!(format!("solana:{}", COUNTERPARTY)));
+    let indicators = vec![
+        indicator("a", DataSource::Chainalysis, RiskCategory::IllicitActivity, "mixer", 80.0, 0.8),
+        linked,
+    ];
+
+    let (merged, _) = IndicatorReconciler::new().reconcile(indicators, &[]);
+
+    assert_eq!(merged.len(), 2);
+}
+
+#[test]
+fn merged_score_and_confidence_combine_sources() {
+    let mut older = indicator("a", DataSource::Chainalysis, RiskCategory::HighRiskService, "gambling", 60.0, 0.5);
+    older.first_seen = Utc::now() - Duration::days(10);
+    let indicators = vec![
+        older.clone(),
+        indicator("b", DataSource::TrmLabs, RiskCategory::HighRiskService, "Online Gambling", 80.0, 0.5),
+    ];
+
+    let (merged, _) = IndicatorReconciler::new().reconcile(indicators, &[]);
+
+    assert_eq!(merged.len(), 1);
+    // Confidence-weighted score and 1 - (1 - 0.5)(1 - 0.5)
+    assert!((merged[0].score - 70.0).abs() < 1e-9);
+    assert!((merged[0].confidence - 0.75).abs() < 1e-9);
+    assert_eq!(merged[0].first_seen, older.first_seen);
+}
+
+#[test]
+fn repeated_reports_from_one_source_are_not_independent() {
+    let indicators = vec![
+        indicator("a", DataSource::Chainalysis, RiskCategory::HighRiskService, "gambling", 60.0, 0.5),
+        indicator("b", DataSource::Chainalysis, RiskCategory::HighRiskService, "Online Gambling", 60.0, 0.6),
+        indicator("c", DataSource::TrmLabs, RiskCategory::HighRiskService, "gambling", 60.0, 0.5),
+    ];
+
+    let (merged, _) = IndicatorReconciler::new().reconcile(indicators, &[]);
+
+    assert_eq!(merged.len(), 1);
+    // Chainalysis counts once at 0.6: 1 - (1 - 0.6)(1 - 0.5)
+    assert!((merged[0].confidence - 0.8).abs() < 1e-9);
+}
+
+#[test]
+fn diverging_scores_are_a_disagreement() {
+    let indicators = vec![
+        indicator("a", DataSource::Chainalysis, RiskCategory::IllicitActivity, "ransomware", 90.0, 0.9),
+        indicator("b", DataSource::TrmLabs, RiskCategory::IllicitActivity, "Ransom", 40.0, 0.9),
+    ];
+
+    let (merged, disagreements) = IndicatorReconciler::new().reconcile(indicators, &[]);
+
+    assert_eq!(merged.len(), 1);
+    assert_eq!(disagreements.len(), 1);
+    assert_eq!(disagreements[0].category, RiskCategory::IllicitActivity);
+    assert_eq!(disagreements[0].scores.len(), 2);
+}
+
+#[test]
+fn silent_responding_source_is_a_disagreement() {
+    let indicators = vec![
+        indicator("a", DataSource::Chainalysis, RiskCategory::Sanctions, "sanctions", 100.0, 1.0),
+    ];
+    let responding = vec![
+        (DataSource::Chainalysis, Vec::new()),
+        (DataSource::TrmLabs, Vec::new()),
+        // Covers other categories only, so its silence says nothing
+        (DataSource::Crystal, vec![RiskCategory::HighRiskService]),
+    ];
+
+    let (_, disagreements) = IndicatorReconciler::new().reconcile(indicators, &responding);
+
+    assert_eq!(disagreements.len(), 1);
+    assert_eq!(disagreements[0].silent_sources, vec![DataSource::TrmLabs]);
+}
+
+#[test]
+fn immaterial_or_unanswered_exposure_is_not_a_disagreement() {
+    let reconciler = IndicatorReconciler::new();
+
+    // Below the material score
+    let indicators = vec![
+        indicator("a", DataSource::Chainalysis, RiskCategory::HighRiskService, "gambling", 40.0, 1.0),
+    ];
+    let responding = vec![(DataSource::Chainalysis, Vec::new()), (DataSource::TrmLabs, Vec::new())];
+    let (_, disagreements) = reconciler.reconcile(indicators, &responding);
+    assert!(disagreements.is_empty());
+
+    // The other provider didn't respond, which is an outage
+    let indicators = vec![
+        indicator("a", DataSource::Chainalysis, RiskCategory::Sanctions, "sanctions", 100.0, 1.0),
+    ];
+    let responding = vec![(DataSource::Chainalysis, Vec::new())];
+    let (_, disagreements) = reconciler.reconcile(indicators, &responding);
+    assert!(disagreements.is_empty());
+}
@@ -0,0 +1,152 @@
+use solana_kyc_compliance_sdk::risk_engine::config::OfacSdnConfig;
+use solana_kyc_compliance_sdk::risk_engine::providers::{OfacSdnProvider, SdnIndex};
+use solana_kyc_compliance_sdk::risk_engine::types::RiskCategory;
+
+const SDN_XML: &str = r#"<?xml version="1.0" standalone="yes"?>
+<sdnList xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xmlns="https://tempuri.org/sdnList.xsd">
+  <publshInformation>
+    <Publish_Date>06/12/2026</Publish_Date>
+    <Record_Count>2</Record_Count>
+  </publshInformation>
+  <sdnEntry>
+    <uid>30518</uid>
+    <firstName>Ivan</firstName>
+    <lastName>EXAMPLE</lastName>
+    <sdnType>Individual</sdnType>
+    <programList>
+      <program>CYBER2</program>
+      <program>RUSSIA-EO14024</program>
+    </programList>
+    <idList>
+      <id>
+        <uid>1001</uid>
+        <idType>Digital Currency Address - XBT</idType>
+        <idNumber>1BoatSLRHtKNngkdXEeobR76b53LETtpyT</idNumber>
+      </id>
+      <id>
+        <uid>1002</uid>
+        <idType>Digital Currency Address - ETH</idType>
+        <idNumber>0xAbCdEf0123456789aBcDeF0123456789AbCdEf01.</idNumber>
+      </id>
+      <id>
+        <uid>1003</uid>
+        <idType>Passport</idType>
+        <idNumber>AB1234567</idNumber>
+      </id>
+    </idList>
+  </sdnEntry>
+  <sdnEntry>
+    <uid>30600</uid>
+    <lastName>EXAMPLE MIXER</lastName>
+    <sdnType>Entity</sdnType>
+    <programList>
+      <program>CYBER2</program>
+    </programList>
+    <idList>
+      <id>
+        <uid>1004</uid>
+        <idType>Digital Currency Address - SOL</idType>
+        <idNumber>7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU</idNumber>
+      </id>
+    </idList>
+  </sdnEntry>
+</sdnList>
+"#;
+
+const SDN_CSV: &str = concat!(
+    "30518,\"EXAMPLE, Ivan\",\"individual\",\"CYBER2] [RUSSIA-EO14024\",-0-,-0-,-0-,-0-,-0-,-0-,-0-,",
+    "\"DOB 1980; Digital Currency Address - XBT 1BoatSLRHtKNngkdXEeobR76b53LETtpyT; ",
+    "Digital Currency Address - ETH 0xAbCdEf0123456789aBcDeF0123456789AbCdEf01.\"\n",
+    "30700,\"NO ADDRESS CO\",-0-,\"SDGT\",-0-,-0-,-0-,-0-,-0-,-0-,-0-,\"Website example.com\"\n",
+);
+
+#[test]
+fn xml_list_indexes_digital_currency_addresses() {
+    let index = SdnIndex::parse(SDN_XML.as_bytes(), false).unwrap();
+
+    assert_eq!(index.len(), 3);
+    assert_eq!(index.version().publish_date.as_deref(), Some("06/12/2026"));
+    assert_eq!(index.version().record_count, Some(2));
+    assert_eq!(index.version().address_count, 3);
+
+    let hit = index.lookup("1BoatSLRHtKNngkdXEeobR76b53LETtpyT").unwrap();
+    assert_eq!(hit.currency, "XBT");
+    assert_eq!(hit.entry.uid, "30518");
+    assert_eq!(hit.entry.name, "Ivan EXAMPLE");
+    assert_eq!(hit.entry.programs, vec!["CYBER2", "RUSSIA-EO14024"]);
+
+    let entity = index.lookup("7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU").unwrap();
+    assert_eq!(entity.entry.name, "EXAMPLE MIXER");
+    assert_eq!(entity.currency, "SOL");
+
+    // Passport numbers aren't addresses
+    assert!(index.lookup("AB1234567").is_none());
+}
+
+#[test]
+fn hex_addresses_match_case_insensitively() {
+    let index = SdnIndex::parse(SDN_XML.as_bytes(), false).unwrap();
+
+    assert!(index.lookup("0xabcdef0123456789abcdef0123456789abcdef01").is_some());
+    assert!(index.lookup("0XABCDEF0123456789ABCDEF0123456789ABCDEF01").is_some());
+    // Base58 is case-sensitive
+    assert!(index.lookup("1boatslrhtknngkdxeeobr76b53lettpyt").is_none());
+}
+
+#[test]
+fn csv_list_reads_addresses_from_remarks() {
+    let index = SdnIndex::parse(SDN_CSV.as_bytes(), true).unwrap();
+
+    assert_eq!(index.len(), 2);
+    assert_eq!(index.version().publish_date, None);
+
+    let hit = index.lookup("1BoatSLRHtKNngkdXEeobR76b53LETtpyT").unwrap();
+    assert_eq!(hit.currency, "XBT");
+    assert_eq!(hit.entry.uid, "30518");
+    assert_eq!(hit.entry.name, "EXAMPLE, Ivan");
+    assert_eq!(hit.entry.programs, vec!["CYBER2", "RUSSIA-EO14024"]);
+
+    let eth = index.lookup("0xabcdef0123456789abcdef0123456789abcdef01").unwrap();
+    assert_eq!(eth.address, "0xAbCdEf0123456789aBcDeF0123456789AbCdEf01");
+}
+
+#[test]
+fn same_list_hashes_the_same() {
+    let a = SdnIndex::parse(SDN_XML.as_bytes(), false).unwrap();
+    let b = SdnIndex::parse(SDN_XML.as_bytes(), false).unwrap();
+    let csv = SdnIndex::parse(SDN_CSV.as_bytes(), true).unwrap();
+
+    assert_eq!(a.version().sha256, b.version().sha256);
+    assert_ne!(a.version().sha256, csv.version().sha256);
+}
+
+#[test]
+fn malformed_xml_is_rejected() {
+    let truncated = "<sdnList><sdnEntry><uid>1</uid></sdnList>";
+    assert!(SdnIndex::parse(truncated.as_bytes(), false).is_err());
+}
+
+#[test]
+fn provider_screens_against_the_list_file() {
+    let dir = std::env::temp_dir().join(format!("ofac_sdn_test_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("sdn.xml");
+    std::fs::write(&path, SDN_XML).unwrap();
+
+    let config = OfacSdnConfig {
+        list_path: Some(path.to_string_lossy().to_string()),
+        download_url: String::new(),
+    };
+    let provider = OfacSdnProvider::new(&config).unwrap().expect("provider is configured");
+
+    let (indicator, version) = provider.screen("7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU");
+    let indicator = indicator.expect("listed address is flagged");
+    assert_eq!(indicator.category, RiskCategory::Sanctions);
+    assert_eq!(indicator.score, 100.0);
+    assert_eq!(version.address_count, 3);
+
+    let (indicator, _) = provider.screen("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM");
+    assert!(indicator.is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
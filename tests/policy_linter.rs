@@ -0,0 +1,210 @@
+use solana_kyc_compliance_sdk::risk_engine::config::RiskThresholds;
+use solana_kyc_compliance_sdk::risk_engine::jurisdiction::{JurisdictionRulePack, JurisdictionRules};
+use solana_kyc_compliance_sdk::risk_engine::policy::{LintFinding, LintSeverity, PolicyLinter, RiskPolicy};
+use serde_json::{json, Value};
+
+fn linter() -> PolicyLinter {
+    PolicyLinter::new(RiskThresholds {
+        critical: 90.0,
+        high: 75.0,
+        medium: 50.0,
+        low: 25.0,
+    })
+}
+
+// Policy with the given conditions merged over an unconditional default
+fn policy(id: &str, conditions: Value, action: &str) -> RiskPolicy {
+    let mut base = json!({
+        "risk_level": null,
+        "risk_score_min": null,
+        "risk_score_max": null,
+        "categories": [],
+        "age_of_attestation_days": null,
+        "transaction_volume_threshold": null,
+        "required_sources": [],
+    });
+    for (key, value) in conditions.as_object().unwrap() {
+        base[key] = value.clone();
+    }
+    serde_json::from_value(json!({
+        "id": id,
+        "name": id,
+        "conditions": base,
+        "actions": [{
+            "action_type": action,
+            "parameters": {},
+            "delay_minutes": null,
+            "requires_approval": false,
+        }],
+        "priority": "High",
+        "active": true,
+    }))
+    .expect("policy should deserialize")
+}
+
+fn rules(countries: &[(&str, &str)]) -> JurisdictionRules {
+    let pack: JurisdictionRulePack = serde_json::from_value(json!({
+        "version": "2026-06",
+        "effective_from": "2026-01-01T00:00:00Z",
+        "rules": countries.iter()
+            .map(|(country, due_diligence)| json!({
+                "country": country,
+                "rating": "standard",
+                "due_diligence": due_diligence,
+            }))
+            .collect::<Vec<_>>(),
+    }))
+    .unwrap();
+    JurisdictionRules::new(vec![pack]).unwrap()
+}
+
+fn conflicts(findings: &[LintFinding]) -> usize {
+    findings.iter()
+        .filter(|f| matches!(f, LintFinding::ConflictingActions { .. }))
+        .count()
+}
+
+fn gaps(findings: &[LintFinding]) -> Vec<(f64, f64)> {
+    findings.iter()
+        .filter_map(|f| match f {
+            LintFinding::UncoveredRange { range } => Some((range.min, range.max)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn max_at_ceiling_covers_the_top_score() {
+    let policies = vec![
+        policy("low", json!({ "risk_score_max": 50.0 }), "NoAction"),
+        policy("high", json!({ "risk_score_min": 50.0, "risk_score_max": 100.0 }), "FlagAttestation"),
+    ];
+
+    let report = linter().lint("default", &policies);
+
+    assert!(gaps(&report.findings).is_empty(), "{:?}", report.findings);
+    assert!(!report.has_errors());
+}
+
+#[test]
+fn uncovered_scores_are_reported() {
+    let policies = vec![
+        policy("low", json!({ "risk_score_max": 40.0 }), "NoAction"),
+        policy("high", json!({ "risk_score_min": 60.0 }), "FlagAttestation"),
+    ];
+
+    let report = linter().lint("default", &policies);
+
+    assert_eq!(gaps(&report.findings), vec![(40.0, 60.0)]);
+    assert_eq!(report.count(LintSeverity::Info), 1);
+}
+
+#[test]
+fn conditional_policies_do_not_count_as_coverage() {
+    let policies = vec![
+        policy("sanctions", json!({ "categories": ["Sanctions"] }), "RevokeAttestation"),
+        policy("germany", json!({ "jurisdictions": ["DE"] }), "NoAction"),
+        policy("volume", json!({ "transaction_volume_threshold": 10000.0 }), "FlagAttestation"),
+    ];
+
+    let report = linter().lint("default", &policies);
+
+    assert_eq!(gaps(&report.findings).len(), 1);
+    let (min, max) = gaps(&report.findings)[0];
+    assert_eq!(min, 0.0);
+    assert!(max > 100.0);
+}
+
+#[test]
+fn unknown_level_with_score_condition_is_unreachable() {
+    let policies = vec![
+        policy("unknown", json!({ "risk_level": "Unknown", "risk_score_min": 50.0 }), "FlagAttestation"),
+    ];
+
+    let report = linter().lint("default", &policies);
+
+    assert!(report.has_errors());
+    assert!(report.findings.iter().any(|f| matches!(
+        f,
+        LintFinding::UnreachablePolicy { policy_id, .. } if policy_id == "unknown"
+    )));
+}
+
+#[test]
+fn level_outside_score_range_is_unreachable() {
+    let policies = vec![
+        policy("critical", json!({ "risk_level": "Critical", "risk_score_max": 80.0 }), "RevokeAttestation"),
+    ];
+
+    let report = linter().lint("default", &policies);
+
+    assert_eq!(report.count(LintSeverity::Error), 1);
+}
+
+#[test]
+fn overlapping_contradictory_actions_conflict() {
+    let policies = vec![
+        policy("suspend", json!({ "risk_score_min": 70.0 }), "SuspendAttestation"),
+        policy("revoke", json!({ "risk_score_min": 85.0 }), "RevokeAttestation"),
+    ];
+
+    let report = linter().lint("default", &policies);
+
+    assert_eq!(conflicts(&report.findings), 1);
+}
+
+#[test]
+fn policies_that_cannot_match_one_profile_do_not_conflict() {
+    let policies = vec![
+        // Different levels
+        policy("high", json!({ "risk_level": "High" }), "SuspendAttestation"),
+        policy("critical", json!({ "risk_level": "Critical" }), "RevokeAttestation"),
+        // Opposite provider agreement
+        policy("disagree", json!({ "risk_score_min": 50.0, "provider_disagreement": true }), "FlagAttestation"),
+        policy("agree", json!({ "risk_score_min": 50.0, "provider_disagreement": false }), "SuspendAttestation"),
+    ];
+    let report = linter().lint("default", &policies[..2]);
+    assert_eq!(conflicts(&report.findings), 0, "{:?}", report.findings);
+    let report = linter().lint("default", &policies[2..]);
+    assert_eq!(conflicts(&report.findings), 0, "{:?}", report.findings);
+
+    // Disjoint jurisdictions
+    let policies = vec![
+        policy("us", json!({ "risk_score_min": 50.0, "jurisdictions": ["US"] }), "FlagAttestation"),
+        policy("de", json!({ "risk_score_min": 50.0, "jurisdictions": ["de"] }), "SuspendAttestation"),
+    ];
+    let report = linter().lint("default", &policies);
+    assert_eq!(conflicts(&report.findings), 0, "{:?}", report.findings);
+
+    // Transaction policies are evaluated apart from wallet policies
+    let policies = vec![
+        policy("wallet", json!({ "risk_score_min": 50.0 }), "FlagAttestation"),
+        policy("transaction", json!({ "transaction_score_min": 80.0 }), "SuspendAttestation"),
+    ];
+    let report = linter().lint("default", &policies);
+    assert_eq!(conflicts(&report.findings), 0, "{:?}", report.findings);
+}
+
+#[test]
+fn due_diligence_resolves_against_the_rule_pack() {
+    let policies = vec![
+        policy("enhanced", json!({ "risk_score_min": 50.0, "due_diligence_min": "enhanced" }), "SuspendAttestation"),
+        policy("germany", json!({ "risk_score_min": 50.0, "jurisdictions": ["DE"] }), "FlagAttestation"),
+    ];
+
+    // Only Iran needs enhanced due diligence, so the policies never share a wallet
+    let report = linter()
+        .with_jurisdiction_rules(rules(&[("IR", "enhanced"), ("DE", "standard")]))
+        .lint("default", &policies);
+    assert!(!report.has_errors());
+    assert_eq!(conflicts(&report.findings), 0, "{:?}", report.findings);
+
+    // No listed country needs enhanced due diligence
+    let report = linter()
+        .with_jurisdiction_rules(rules(&[("DE", "standard")]))
+        .lint("default", &policies);
+    assert!(report.findings.iter().any(|f| matches!(
+        f,
+        LintFinding::UnreachablePolicy { policy_id, .. } if policy_id == "enhanced"
+    )));
+}
@@ -0,0 +1,126 @@
+use solana_kyc_compliance_sdk::risk_engine::config::{CrystalConfig, ProviderBudgetConfig, ProviderResilienceConfig};
+use solana_kyc_compliance_sdk::risk_engine::providers::{
+    CrystalClient, ProviderError, ResilientProvider, RiskDataProvider, UsageTracker,
+};
+use solana_kyc_compliance_sdk::risk_engine::types::ChainAddress;
+use std::sync::Arc;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const WALLET: &str = "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU";
+
+fn wallet() -> ChainAddress {
+    ChainAddress::solana(WALLET).unwrap()
+}
+
+// Tracker with a daily Crystal budget, written to a per-test budgets file
+fn tracker(name: &str, daily_calls: u64) -> Arc<UsageTracker> {
+    let dir = std::env::temp_dir().join(format!("provider_budget_{}_{}", name, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let budgets = dir.join("budgets.json");
+    std::fs::write(
+        &budgets,
+        serde_json::json!({ "crystal": { "daily_calls": daily_calls, "monthly_calls": null } }).to_string(),
+    )
+    .unwrap();
+
+    Arc::new(UsageTracker::load(&ProviderBudgetConfig {
+        budgets_path: Some(budgets.to_string_lossy().to_string()),
+        usage_path: None,
+    })
+    .unwrap())
+}
+
+async fn crystal_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/monitor/one"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": {
+                "address": WALLET,
+                "currency": "sol",
+                "riskscore": 0.1,
+                "signals": {},
+                "name": null,
+                "entity_type": null
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/cross-chain/exposure"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": { "exposures": [] }
+        })))
+        .mount(&server)
+        .await;
+    server
+}
+
+fn metered_crystal(server: &MockServer, enable_cross_chain: bool, meter: Arc<UsageTracker>) -> ResilientProvider {
+    let client = CrystalClient::new(Arc::new(CrystalConfig {
+        api_key: Some("test-key".to_string()),
+        api_url: server.uri(),
+        timeout_secs: 2,
+        enable_cross_chain,
+    }))
+    .expect("client should build");
+
+    ResilientProvider::new(
+        Arc::new(client),
+        ProviderResilienceConfig {
+            max_retries: 0,
+            base_delay_ms: 1,
+            max_delay_ms: 1,
+            failure_threshold: 5,
+            cool_down_secs: 60,
+        },
+        Some(meter),
+    )
+}
+
+fn crystal_calls_today(tracker: &UsageTracker) -> u64 {
+    tracker.provider_usage().into_iter()
+        .find(|usage| usage.provider == "crystal")
+        .map(|usage| usage.calls_today)
+        .unwrap_or(0)
+}
+
+#[tokio::test]
+async fn cross_chain_fetch_reserves_both_requests() {
+    let server = crystal_server().await;
+    let tracker = tracker("cross_chain", 100);
+    let provider = metered_crystal(&server, true, tracker.clone());
+
+    assert_eq!(provider.requests_per_fetch(), 2);
+    provider.fetch(&wallet()).await.unwrap();
+
+    assert_eq!(crystal_calls_today(&tracker), 2);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn address_only_fetch_reserves_one_request() {
+    let server = crystal_server().await;
+    let tracker = tracker("address_only", 100);
+    let provider = metered_crystal(&server, false, tracker.clone());
+
+    provider.fetch(&wallet()).await.unwrap();
+
+    assert_eq!(crystal_calls_today(&tracker), 1);
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn fetch_is_refused_when_budget_cannot_cover_every_request() {
+    let server = crystal_server().await;
+    let tracker = tracker("refused", 3);
+    let provider = metered_crystal(&server, true, tracker.clone());
+
+    provider.fetch(&wallet()).await.unwrap();
+    let err = provider.fetch(&wallet()).await.unwrap_err();
+
+    assert!(matches!(err, ProviderError::BudgetExhausted(ref name) if name == "crystal"), "{}", err);
+    assert_eq!(crystal_calls_today(&tracker), 2);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
@@ -0,0 +1,171 @@
+use solana_kyc_compliance_sdk::risk_engine::config::SybilDetectionConfig;
+use solana_kyc_compliance_sdk::risk_engine::sybil::{SybilDetector, WalletActivity};
+use solana_kyc_compliance_sdk::risk_engine::types::ClusterSignal;
+
+fn config() -> SybilDetectionConfig {
+    SybilDetectionConfig {
+        enabled: true,
+        poll_minutes: 60,
+        signatures_per_wallet: 100,
+        max_concurrent_fetches: 8,
+        sync_window_secs: 30,
+        min_synchronized_events: 3,
+        min_confidence: 0.6,
+    }
+}
+
+fn activity(funder: Option<&str>, fee_payers: &[&str], active_at: &[i64]) -> WalletActivity {
+    WalletActivity {
+        funding_source: funder.map(str::to_string),
+        fee_payers: fee_payers.iter().map(|p| p.to_string()).collect(),
+        active_at: active_at.to_vec(),
+        newest_signature: None,
+    }
+}
+
+fn members(detector: &SybilDetector, wallet: &str) -> Vec<String> {
+    let mut members = detector.cluster_for(wallet).map(|c| c.members.clone()).unwrap_or_default();
+    members.sort();
+    members
+}
+
+#[test]
+fn shared_funding_source_clusters_wallets() {
+    let mut detector = SybilDetector::new(config());
+    detector.observe("a", activity(Some("funder"), &[], &[]));
+    detector.observe("b", activity(Some("funder"), &[], &[]));
+    detector.observe("c", activity(Some("someone_else"), &[], &[]));
+
+    let changed = detector.recluster();
+
+    assert_eq!(changed, vec!["a", "b"]);
+    assert_eq!(members(&detector, "a"), vec!["a", "b"]);
+    assert!(detector.cluster_for("c").is_none());
+
+    let cluster = detector.cluster_for("b").unwrap();
+    assert_eq!(cluster.signals, vec![ClusterSignal::SharedFundingSource]);
+    assert!((cluster.confidence - 0.6).abs() < 1e-9);
+}
+
+#[test]
+fn weak_signals_alone_do_not_cluster_but_combine() {
+    let times = [1_000, 2_000, 3_000];
+
+    // Synchronized activity alone is below the confidence floor
+    let mut detector = SybilDetector::new(config());
+    detector.observe("a", activity(None, &[], &times));
+    detector.observe("b", activity(None, &[], &times));
+    assert!(detector.recluster().is_empty());
+
+    // Together with a shared funder it is 1 - (1 - 0.6)(1 - 0.5)
+    detector.observe("a", activity(Some("funder"), &[], &[]));
+    detector.observe("b", activity(Some("funder"), &[], &[]));
+    detector.recluster();
+    let cluster = detector.cluster_for("a").unwrap();
+    assert!((cluster.confidence - 0.8).abs() < 1e-9);
+    assert_eq!(
+        cluster.signals,
+        vec![ClusterSignal::SharedFundingSource, ClusterSignal::SynchronizedActivity]
+    );
+}
+
+#[test]
+fn links_join_transitively() {
+    let mut detector = SybilDetector::new(config());
+    detector.observe("a", activity(None, &["payer"], &[]));
+    detector.observe("b", activity(Some("funder"), &["payer"], &[]));
+    detector.observe("c", activity(Some("funder"), &[], &[]));
+    // A monitored wallet paying for another links the two directly
+    detector.observe("d", activity(None, &[], &[]));
+    detector.observe("e", activity(None, &["d"], &[]));
+
+    detector.recluster();
+
+    assert_eq!(members(&detector, "a"), vec!["a", "b", "c"]);
+    assert_eq!(members(&detector, "d"), vec!["d", "e"]);
+    assert_eq!(detector.clusters().len(), 2);
+}
+
+#[test]
+fn accounts_shared_by_many_wallets_are_services() {
+    let mut detector = SybilDetector::new(config());
+    for i in 0..101 {
+        detector.observe(&format!("wallet_{}", i), activity(Some("exchange_hot_wallet"), &[], &[]));
+    }
+
+    assert!(detector.recluster().is_empty());
+    assert!(detector.clusters().is_empty());
+}
+
+#[test]
+fn rebuilt_clusters_keep_their_id() {
+    let mut detector = SybilDetector::new(config());
+    detector.observe("a", activity(Some("funder"), &[], &[]));
+    detector.observe("b", activity(Some("funder"), &[], &[]));
+    detector.recluster();
+    let original = detector.cluster_for("a").unwrap().clone();
+
+    // Nothing changed
+    assert!(detector.recluster().is_empty());
+
+    // A new member joins the existing cluster
+    detector.observe("c", activity(Some("funder"), &[], &[]));
+    let changed = detector.recluster();
+    assert_eq!(changed, vec!["a", "b", "c"]);
+    let grown = detector.cluster_for("c").unwrap();
+    assert_eq!(grown.cluster_id, original.cluster_id);
+    assert_eq!(grown.detected_at, original.detected_at);
+
+    // A forgotten wallet leaves; the rest stay together
+    detector.forget("c");
+    let changed = detector.recluster();
+    assert_eq!(changed, vec!["a", "b", "c"]);
+    assert_eq!(detector.cluster_for("a").unwrap().cluster_id, original.cluster_id);
+
+    detector.forget("b");
+    assert_eq!(detector.recluster(), vec!["a", "b"]);
+    assert!(detector.clusters().is_empty());
+}
+
+#[test]
+fn observed_activity_merges_across_polls() {
+    let mut detector = SybilDetector::new(config());
+    let mut first = activity(Some("funder"), &[], &[]);
+    first.newest_signature = Some("sig_1".to_string());
+    detector.observe("a", first);
+    detector.observe("b", activity(Some("funder"), &[], &[]));
+    assert_eq!(detector.last_signature("a").as_deref(), Some("sig_1"));
+
+    // A later poll doesn't see the funding transfer again, but the wallet
+    // stays funded by it; a poll with nothing new keeps the cursor
+    let mut second = activity(None, &["payer"], &[]);
+    second.newest_signature = Some("sig_2".to_string());
+    detector.observe("a", second);
+    detector.observe("a", activity(None, &[], &[]));
+    detector.observe("c", activity(None, &["payer"], &[]));
+
+    detector.recluster();
+
+    assert_eq!(members(&detector, "a"), vec!["a", "b", "c"]);
+    assert_eq!(detector.last_signature("a").as_deref(), Some("sig_2"));
+    assert_eq!(detector.last_signature("b"), None);
+}
+
+#[test]
+fn only_recent_activity_counts_towards_synchronization() {
+    let mut detector = SybilDetector::new(SybilDetectionConfig {
+        signatures_per_wallet: 3,
+        min_confidence: 0.5,
+        ..config()
+    });
+    detector.observe("a", activity(None, &[], &[1_000, 2_000, 3_000]));
+    detector.observe("b", activity(None, &[], &[1_000, 2_000, 3_000]));
+    detector.recluster();
+    assert_eq!(members(&detector, "a"), vec!["a", "b"]);
+
+    // Newer activity pushes the shared events out of a's window
+    detector.observe("a", activity(None, &[], &[10_000, 20_000]));
+
+    assert_eq!(detector.recluster(), vec!["a", "b"]);
+    assert!(detector.cluster_for("a").is_none());
+}
@@ -0,0 +1,129 @@
+use chrono::Utc;
+use solana_kyc_compliance_sdk::risk_engine::scoring::{
+    calibrate, AssessmentLabel, CaseOutcome, IndicatorLabel, ScoringWeights,
+};
+use solana_kyc_compliance_sdk::risk_engine::types::{DataSource, RiskCategory, RiskLevel};
+use std::collections::HashMap;
+
+const THRESHOLD: f64 = 50.0;
+const HOLDOUT: f64 = 0.25;
+
+fn indicator(category: RiskCategory, source: DataSource, score: f64, confidence: f64, outcome: CaseOutcome) -> IndicatorLabel {
+    IndicatorLabel {
+        indicator_id: format!("{:?}_{}", category, score),
+        category,
+        subcategory: String::new(),
+        source: Some(source),
+        score,
+        confidence,
+        outcome,
+    }
+}
+
+// Every case carries the same high-risk-service noise from TRM; only the
+// Chainalysis sanctions score separates true from false positives
+fn case(i: usize) -> AssessmentLabel {
+    let outcome = if i % 2 == 0 { CaseOutcome::TruePositive } else { CaseOutcome::FalsePositive };
+    let sanctions_score = if outcome.is_positive() { 80.0 } else { 10.0 };
+
+    AssessmentLabel {
+        id: format!("case-{}", i),
+        wallet_address: format!("wallet-{}", i),
+        analyst: "analyst".to_string(),
+        outcome,
+        assessed_at: Utc::now(),
+        labeled_at: Utc::now(),
+        overall_score: 60.0,
+        risk_level: RiskLevel::Medium,
+        scoring_strategy: None,
+        features: HashMap::new(),
+        indicators: vec![
+            indicator(RiskCategory::Sanctions, DataSource::Chainalysis, sanctions_score, 0.5, outcome),
+            indicator(RiskCategory::HighRiskService, DataSource::TrmLabs, 90.0, 1.0, CaseOutcome::FalsePositive),
+        ],
+        notes: None,
+    }
+}
+
+fn cases(count: usize) -> Vec<AssessmentLabel> {
+    (0..count).map(case).collect()
+}
+
+#[test]
+fn calibration_learns_which_categories_and_sources_predict_outcomes() {
+    let weights = calibrate(&cases(200), THRESHOLD, HOLDOUT).unwrap();
+
+    assert!(
+        weights.category_weight(&RiskCategory::Sanctions) > weights.category_weight(&RiskCategory::HighRiskService),
+        "{:?}",
+        weights.category_weights
+    );
+    assert!(
+        weights.confidence_multiplier(&DataSource::Chainalysis) > weights.confidence_multiplier(&DataSource::TrmLabs),
+        "{:?}",
+        weights.confidence_multipliers
+    );
+    // Categories absent from training keep their built-in weight
+    assert_eq!(weights.category_weight(&RiskCategory::TechnicalRisk), RiskCategory::TechnicalRisk.weight());
+}
+
+#[test]
+fn holdout_metrics_compare_built_in_and_calibrated_weights() {
+    let weights = calibrate(&cases(200), THRESHOLD, HOLDOUT).unwrap();
+
+    assert!(weights.before.samples > 0);
+    assert_eq!(weights.before.samples, weights.after.samples);
+    assert_eq!(weights.before.samples + weights.training_labels, 200);
+
+    // Built-in weights flag the false positives on the noise; calibrated ones don't
+    assert!(weights.before.false_positives > 0);
+    assert_eq!(weights.after.false_positives, 0);
+    assert!(weights.after.precision > weights.before.precision);
+    assert_eq!(weights.after.recall, 1.0);
+}
+
+#[test]
+fn holdout_split_is_stable_as_labels_grow() {
+    let first = calibrate(&cases(200), THRESHOLD, HOLDOUT).unwrap();
+    let again = calibrate(&cases(200), THRESHOLD, HOLDOUT).unwrap();
+    let grown = calibrate(&cases(300), THRESHOLD, HOLDOUT).unwrap();
+
+    assert_eq!(first.training_labels, again.training_labels);
+    assert_eq!(first.category_weights, again.category_weights);
+    assert!(grown.training_labels >= first.training_labels);
+    assert!(grown.before.samples >= first.before.samples);
+}
+
+#[test]
+fn unusable_label_sets_are_rejected() {
+    assert!(calibrate(&cases(200), THRESHOLD, 0.0).is_err());
+    assert!(calibrate(&cases(200), THRESHOLD, 1.0).is_err());
+
+    // Too few labels to train on
+    assert!(calibrate(&cases(10), THRESHOLD, HOLDOUT).is_err());
+
+    // Only one outcome
+    let positives: Vec<AssessmentLabel> = (0..100).map(|i| case(i * 2)).collect();
+    assert!(calibrate(&positives, THRESHOLD, HOLDOUT).is_err());
+}
+
+#[test]
+fn weights_file_round_trips_and_rejects_non_positive_weights() {
+    let weights = calibrate(&cases(200), THRESHOLD, HOLDOUT).unwrap();
+    let dir = std::env::temp_dir().join(format!("weight_calibration_test_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("weights.json");
+    let path = path.to_str().unwrap();
+
+    weights.save(path).unwrap();
+    let loaded = ScoringWeights::load(path).unwrap();
+    assert_eq!(loaded.category_weights, weights.category_weights);
+    assert_eq!(loaded.training_labels, weights.training_labels);
+
+    let mut invalid = weights.clone();
+    invalid.category_weights.insert(RiskCategory::Sanctions, 0.0);
+    invalid.save(path).unwrap();
+    assert!(ScoringWeights::load(path).is_err());
+
+    std::fs::remove_dir_all(&dir).ok();
+}